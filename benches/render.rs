@@ -0,0 +1,57 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use gen::project::{Lang, Project, ProjectBuilder};
+use gen::template;
+use handlebars::Handlebars;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+fn collect_templates(dir: &Path, templates: &mut Vec<PathBuf>) {
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            collect_templates(&path, templates);
+        } else if fs::read_to_string(&path).is_ok() {
+            templates.push(path);
+        }
+    }
+}
+
+fn render_uncached(project: &Project, templates: &[PathBuf]) {
+    for path in templates {
+        let name = path.display().to_string();
+        let mut handlebars = Handlebars::new();
+        handlebars.register_escape_fn(handlebars::no_escape);
+        handlebars.register_template_file(&name, path).unwrap();
+        handlebars.render(&name, project).unwrap();
+    }
+}
+
+fn render_cached(project: &Project, templates: &[PathBuf]) {
+    for path in templates {
+        template::render_file(path, project).unwrap();
+    }
+}
+
+fn render(c: &mut Criterion) {
+    let templates_root = Path::new(env!("CARGO_MANIFEST_DIR")).join("templates");
+    let project = ProjectBuilder::new("bench")
+        .lang(Lang::Rust)
+        .templates_root(&templates_root)
+        .build()
+        .unwrap();
+    let mut templates = Vec::new();
+    collect_templates(&templates_root.join("rust"), &mut templates);
+    templates.sort();
+
+    let mut group = c.benchmark_group("render rust templates");
+    group.bench_function("new registry per file", |b| {
+        b.iter(|| render_uncached(&project, &templates))
+    });
+    group.bench_function("shared registry", |b| {
+        b.iter(|| render_cached(&project, &templates))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, render);
+criterion_main!(benches);
@@ -0,0 +1,241 @@
+use crate::component::detect_lang;
+use crate::config::{expand_tilde, Config};
+use crate::languages;
+use crate::manifest::TemplateManifest;
+use crate::project::Lang;
+use crate::template;
+use anyhow::anyhow;
+use clap::{Arg, Command};
+use clap_complete::Shell;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+const BASH: &str = r#"_gen() {
+    local IFS=$'\n'
+    COMPREPLY=($(gen __complete "${COMP_WORDS[@]:1:COMP_CWORD}" 2>/dev/null))
+    if [ ${#COMPREPLY[@]} -eq 0 ]; then
+        compopt -o default
+    fi
+}
+
+complete -F _gen gen
+"#;
+
+const ZSH: &str = r#"#compdef gen
+
+_gen() {
+    local -a candidates
+    candidates=(${(f)"$(gen __complete "${(@)words[2,CURRENT]}" 2>/dev/null)"})
+    if (( ${#candidates} )); then
+        compadd -a candidates
+    else
+        _files
+    fi
+}
+
+if [ "$funcstack[1]" = "_gen" ]; then
+    _gen "$@"
+else
+    compdef _gen gen
+fi
+"#;
+
+const FISH: &str = r#"function __gen_complete
+    set -l tokens (commandline -opc)
+    set -e tokens[1]
+    gen __complete $tokens (commandline -ct) 2>/dev/null
+end
+
+complete -c gen -f -n 'test -n "$(__gen_complete)"' -a '(__gen_complete)'
+complete -c gen -F -n 'test -z "$(__gen_complete)"'
+"#;
+
+#[derive(Default)]
+struct Context {
+    positionals: Vec<String>,
+    template: Option<String>,
+    profile: Option<String>,
+    project: Option<PathBuf>,
+}
+
+pub fn script(shell: Shell) -> anyhow::Result<&'static str> {
+    match shell {
+        Shell::Bash => Ok(BASH),
+        Shell::Zsh => Ok(ZSH),
+        Shell::Fish => Ok(FISH),
+        _ => Err(anyhow!(
+            "Dynamic completions are only available for bash, zsh and fish"
+        )),
+    }
+}
+
+pub fn complete(mut command: Command, words: &[String]) -> anyhow::Result<Vec<String>> {
+    command.build();
+    let (current, previous) = match words.split_last() {
+        Some((current, previous)) => (current.as_str(), previous),
+        None => ("", words),
+    };
+
+    let mut command = &command;
+    let mut context = Context::default();
+    let mut pending: Option<&Arg> = None;
+    for word in previous {
+        if let Some(arg) = pending.take() {
+            context.set(arg, word);
+        } else if let Some(flag) = word.strip_prefix("--") {
+            let (name, value) = match flag.split_once('=') {
+                Some((name, value)) => (name, Some(value)),
+                None => (flag, None),
+            };
+            if let Some(arg) = command
+                .get_arguments()
+                .find(|arg| arg.get_long() == Some(name))
+            {
+                match value {
+                    Some(value) => context.set(arg, value),
+                    None if takes_value(arg) => pending = Some(arg),
+                    None => {}
+                }
+            }
+        } else if let Some(short) = word.strip_prefix('-').and_then(|flag| flag.chars().next()) {
+            if let Some(arg) = command
+                .get_arguments()
+                .find(|arg| arg.get_short() == Some(short))
+            {
+                match &word[2..] {
+                    "" if takes_value(arg) => pending = Some(arg),
+                    "" => {}
+                    value => context.set(arg, value),
+                }
+            }
+        } else if let Some(subcommand) = context
+            .positionals
+            .is_empty()
+            .then(|| command.find_subcommand(word))
+            .flatten()
+        {
+            command = subcommand;
+        } else {
+            context.positionals.push(word.clone());
+        }
+    }
+
+    let mut candidates = match pending {
+        Some(arg) => context.values(command, arg)?,
+        None if current.starts_with('-') => flags(command),
+        None => {
+            let mut candidates = Vec::new();
+            if context.positionals.is_empty() {
+                candidates.extend(
+                    command
+                        .get_subcommands()
+                        .filter(|subcommand| !subcommand.is_hide_set())
+                        .map(|subcommand| subcommand.get_name().to_string()),
+                );
+            }
+            if let Some(arg) = command.get_positionals().nth(context.positionals.len()) {
+                candidates.extend(context.values(command, arg)?);
+            }
+            candidates
+        }
+    };
+    candidates.retain(|candidate| candidate.starts_with(current));
+    Ok(candidates)
+}
+
+impl Context {
+    fn set(&mut self, arg: &Arg, value: &str) {
+        let value = Some(value.to_string());
+        match arg.get_id().as_str() {
+            "template" => self.template = value,
+            "profile" => self.profile = value,
+            "project" => self.project = value.map(expand_tilde),
+            _ => {}
+        }
+    }
+
+    fn values(&self, command: &Command, arg: &Arg) -> anyhow::Result<Vec<String>> {
+        let config = Config::load_profile(self.profile.as_deref())?;
+        let templates_root = config.templates_root();
+        let values = match (command.get_name(), arg.get_id().as_str()) {
+            (_, "lang") => languages::installed(),
+            ("add", "kind") => template::kinds(&templates_root)?,
+            ("add", "component") => self.components(&templates_root),
+            (_, "kind") => self.kinds(&templates_root)?,
+            (_, "template") | ("check" | "test", "name") => template::sets(&templates_root)?,
+            _ => arg
+                .get_possible_values()
+                .iter()
+                .filter(|value| !value.is_hide_set())
+                .map(|value| value.get_name().to_string())
+                .collect(),
+        };
+        Ok(values)
+    }
+
+    fn kinds(&self, templates_root: &Path) -> anyhow::Result<Vec<String>> {
+        let set = match (&self.template, self.positionals.first()) {
+            (Some(set), _) => set.clone(),
+            (None, Some(lang)) => match Lang::from_str(lang) {
+                Ok(lang) => lang.template_subdir(),
+                Err(_) => return Ok(Vec::new()),
+            },
+            (None, None) => return template::kinds(templates_root),
+        };
+        let template_dir = templates_root.join(set);
+        if !template_dir.is_dir() {
+            return Ok(Vec::new());
+        }
+
+        let manifest = TemplateManifest::load(&template_dir)?;
+        let mut kinds = template::set_kinds(&template_dir, &manifest);
+        for (kind, sets) in [
+            ("docs", &["mdbook", "mkdocs"][..]),
+            ("schema", &["schema"]),
+            ("monorepo", &["monorepo"]),
+        ] {
+            if sets.iter().any(|set| templates_root.join(set).is_dir()) {
+                kinds.push(kind.to_string());
+            }
+        }
+        Ok(kinds)
+    }
+
+    fn components(&self, templates_root: &Path) -> Vec<String> {
+        let project_dir = self.project.as_deref().unwrap_or(Path::new("."));
+        let lang = match detect_lang(project_dir) {
+            Some(lang) => lang,
+            None => return Vec::new(),
+        };
+        let components_dir = templates_root
+            .join(lang.template_subdir())
+            .join("components");
+        let mut components: Vec<String> = fs::read_dir(components_dir)
+            .into_iter()
+            .flatten()
+            .flatten()
+            .filter(|entry| entry.path().is_dir())
+            .map(|entry| entry.file_name().to_string_lossy().to_string())
+            .collect();
+        components.sort();
+        components
+    }
+}
+
+fn takes_value(arg: &Arg) -> bool {
+    arg.get_num_args()
+        .is_some_and(|num_args| num_args.min_values() > 0)
+}
+
+fn flags(command: &Command) -> Vec<String> {
+    command
+        .get_arguments()
+        .filter(|arg| !arg.is_positional() && !arg.is_hide_set())
+        .flat_map(|arg| {
+            let long = arg.get_long().map(|long| format!("--{}", long));
+            let short = arg.get_short().map(|short| format!("-{}", short));
+            long.into_iter().chain(short)
+        })
+        .collect()
+}
@@ -0,0 +1,720 @@
+use crate::config::Config;
+use crate::name::validate_component_name;
+use crate::project::{Lang, ProjectKind, Sink};
+use crate::record::ProjectRecord;
+use crate::walk::collect_files;
+use anyhow::anyhow;
+use handlebars::Handlebars;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct ComponentManifest {
+    pub append: Vec<AppendRule>,
+    pub merge: Vec<MergeRule>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AppendRule {
+    pub files: Vec<String>,
+    pub line: String,
+    pub before: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MergeRule {
+    pub file: String,
+    pub content: String,
+    pub require_table: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ComponentContext {
+    pub name: String,
+    pub snake_name: String,
+    pub pascal_name: String,
+    pub camel_name: String,
+    pub upper_name: String,
+    pub lower_name: String,
+    pub lang: Lang,
+    pub project_name: String,
+    pub package: Option<String>,
+    pub package_path: Option<String>,
+    pub module_path: Option<String>,
+    pub go_package: Option<String>,
+    pub project_kind: Option<ProjectKind>,
+}
+
+#[derive(Debug)]
+pub struct Component {
+    kind: String,
+    project_dir: PathBuf,
+    template_dir: PathBuf,
+    context: ComponentContext,
+    sink: Sink,
+}
+
+impl Component {
+    pub fn new(kind: &str, name: &str, project_dir: &Path) -> anyhow::Result<Component> {
+        let lang = match detect_lang(project_dir) {
+            Some(lang) => lang,
+            None => {
+                return Err(anyhow!(
+                    "Could not detect the language of the project in {}",
+                    project_dir.display()
+                ))
+            }
+        };
+
+        // Names passed to with_template_dir come from validated project names, this one from the
+        // command line
+        validate_component_name(name, &lang)?;
+        let template_dir = Config::load()?
+            .templates_root()
+            .join(lang.template_subdir());
+        Component::with_template_dir(kind, name, project_dir, lang, &template_dir)
+    }
+
+    pub fn with_template_dir(
+        kind: &str,
+        name: &str,
+        project_dir: &Path,
+        lang: Lang,
+        template_dir: &Path,
+    ) -> anyhow::Result<Component> {
+        let template_dir = template_dir.join("components").join(kind);
+        if !template_dir.is_dir() {
+            return Err(anyhow!(
+                "No {} component templates for {:?} ({} does not exist)",
+                kind,
+                lang,
+                template_dir.display()
+            ));
+        }
+
+        let context = ComponentContext {
+            name: name.to_string(),
+            snake_name: snake_case(name),
+            pascal_name: pascal_case(name),
+            camel_name: camel_case(name),
+            upper_name: snake_case(name).to_uppercase(),
+            lower_name: snake_case(name).replace('_', ""),
+            lang,
+            project_name: project_name(project_dir, &lang),
+            package: java_package(project_dir),
+            package_path: java_package(project_dir).map(|package| package.replace('.', "/")),
+            module_path: go_module_path(project_dir),
+            go_package: go_package(project_dir),
+            // Without a kind the .lib templates are used, so start from the kind the project was
+            // generated as
+            project_kind: ProjectRecord::load(project_dir)
+                .ok()
+                .map(|record| record.kind),
+        };
+
+        Ok(Component {
+            kind: kind.to_string(),
+            project_dir: project_dir.to_path_buf(),
+            template_dir,
+            context,
+            sink: Sink::Disk,
+        })
+    }
+
+    pub fn kind(&self) -> &String {
+        &self.kind
+    }
+
+    pub fn set_project_kind(&mut self, project_kind: ProjectKind) {
+        self.context.project_kind = Some(project_kind);
+    }
+
+    pub fn set_sink(&mut self, sink: Sink) {
+        self.sink = sink;
+    }
+
+    pub fn context(&self) -> &ComponentContext {
+        &self.context
+    }
+
+    pub fn manifest(&self) -> anyhow::Result<ComponentManifest> {
+        let manifest_path = self.template_dir.join("component.toml");
+        if !manifest_path.is_file() {
+            return Ok(ComponentManifest::default());
+        }
+
+        match toml::from_str(&fs::read_to_string(&manifest_path)?) {
+            Ok(manifest) => Ok(manifest),
+            Err(error) => Err(anyhow!(
+                "Could not parse {}: {}",
+                manifest_path.display(),
+                error
+            )),
+        }
+    }
+
+    // The component's templates and the paths they are written to, before rendering. Templates
+    // ending in .bin or .lib are only written for executable or library projects
+    pub fn templates(&self) -> anyhow::Result<Vec<(PathBuf, String)>> {
+        let mut files = Vec::new();
+        collect_files(&self.template_dir, &[], &mut files)?;
+        files.sort();
+
+        let executable = self
+            .context
+            .project_kind
+            .is_some_and(|kind| kind.is_executable());
+        let mut templates = Vec::new();
+        for from_path in files {
+            let relative_path = from_path.strip_prefix(&self.template_dir)?;
+            if relative_path == Path::new("component.toml") {
+                continue;
+            }
+
+            let relative_path = relative_path.display().to_string();
+            let relative_path = match (relative_path.strip_suffix(".bin"), executable) {
+                (Some(path), true) => path.to_string(),
+                (Some(_), false) => continue,
+                (None, _) => match (relative_path.strip_suffix(".lib"), executable) {
+                    (Some(path), false) => path.to_string(),
+                    (Some(_), true) => continue,
+                    (None, _) => relative_path,
+                },
+            };
+            templates.push((from_path, relative_path));
+        }
+        Ok(templates)
+    }
+
+    pub fn generate(&self) -> anyhow::Result<()> {
+        let mut handlebars = Handlebars::new();
+        handlebars.register_escape_fn(handlebars::no_escape);
+
+        let manifest = self.manifest()?;
+        for rule in &manifest.merge {
+            if let Some(table) = &rule.require_table {
+                let target = self.project_dir.join(&rule.file);
+                let document = self
+                    .sink
+                    .read_to_string(&target)
+                    .unwrap_or_default()
+                    .parse::<toml_edit::Document>()?;
+                let found = table
+                    .split('.')
+                    .try_fold(document.as_item(), |item, key| item.get(key))
+                    .is_some();
+                if !found {
+                    return Err(anyhow!("{} has no [{}] table", target.display(), table));
+                }
+            }
+        }
+
+        let mut rendered = Vec::new();
+        for (from_path, relative_path) in self.templates()? {
+            let to_path = self
+                .project_dir
+                .join(handlebars.render_template(&relative_path, &self.context)?);
+            if self.sink.exists(&to_path) {
+                return Err(anyhow!(
+                    "{} already exists! Refusing to overwrite",
+                    to_path.display()
+                ));
+            }
+
+            let contents =
+                handlebars.render_template(&fs::read_to_string(&from_path)?, &self.context)?;
+            rendered.push((to_path, contents));
+        }
+
+        for (to_path, contents) in rendered {
+            if let Some(parent) = to_path.parent() {
+                self.sink.create_dir_all(parent)?;
+            }
+            self.sink.write(&to_path, contents)?;
+            self.sink.created(&to_path);
+        }
+
+        for rule in manifest.append {
+            let line = handlebars.render_template(&rule.line, &self.context)?;
+            let target = rule
+                .files
+                .iter()
+                .map(|file| self.project_dir.join(file))
+                .find(|path| self.sink.is_file(path));
+
+            match target {
+                Some(target) => {
+                    let mut contents = self.sink.read_to_string(&target)?;
+                    if contents.contains(line.trim()) {
+                        continue;
+                    }
+                    let anchor = rule
+                        .before
+                        .as_ref()
+                        .and_then(|before| contents.find(before.as_str()))
+                        .map(|index| contents[..index].rfind('\n').map_or(0, |line| line + 1));
+                    match anchor {
+                        Some(index) => contents.insert_str(index, &format!("{}\n", line)),
+                        None => {
+                            if !contents.is_empty() && !contents.ends_with('\n') {
+                                contents.push('\n');
+                            }
+                            contents.push_str(&line);
+                            contents.push('\n');
+                        }
+                    }
+                    self.sink.write(&target, contents)?;
+                    self.sink.updated(&target);
+                }
+                None if self.sink.is_memory() => {}
+                None => eprintln!(
+                    "Warning: none of {} exist, add `{}` manually",
+                    rule.files.join(", "),
+                    line
+                ),
+            }
+        }
+
+        for rule in manifest.merge {
+            let target = self.project_dir.join(&rule.file);
+            if !self.sink.is_file(&target) {
+                if !self.sink.is_memory() {
+                    eprintln!(
+                        "Warning: {} does not exist, merge it by hand",
+                        target.display()
+                    );
+                }
+                continue;
+            }
+
+            let content = handlebars.render_template(&rule.content, &self.context)?;
+            let source = content.parse::<toml_edit::Document>()?;
+            self.sink.update(&target, |document| {
+                let mut document = document.parse::<toml_edit::Document>()?;
+                merge_toml(document.as_table_mut(), source.as_table());
+                Ok(document.to_string())
+            })?;
+        }
+        Ok(())
+    }
+}
+
+pub fn merge_toml(target: &mut toml_edit::Table, source: &toml_edit::Table) {
+    let mut next_position = last_position(target) + 1;
+    merge_tables(target, source, &mut next_position);
+}
+
+fn merge_tables(
+    target: &mut toml_edit::Table,
+    source: &toml_edit::Table,
+    next_position: &mut usize,
+) {
+    for (key, item) in source.iter() {
+        match (target.get_mut(key), item) {
+            (None, _) => {
+                let mut item = item.clone();
+                reposition(&mut item, next_position);
+                target.insert(key, item);
+            }
+            (Some(toml_edit::Item::Table(existing)), toml_edit::Item::Table(table)) => {
+                merge_tables(existing, table, next_position)
+            }
+            (
+                Some(toml_edit::Item::ArrayOfTables(existing)),
+                toml_edit::Item::ArrayOfTables(tables),
+            ) => {
+                for table in tables.iter() {
+                    if !existing
+                        .iter()
+                        .any(|other| other.to_string() == table.to_string())
+                    {
+                        let mut table = table.clone();
+                        table.set_position(*next_position);
+                        table.decor_mut().set_prefix("\n");
+                        *next_position += 1;
+                        existing.push(table);
+                    }
+                }
+            }
+            (Some(toml_edit::Item::Value(toml_edit::Value::Array(existing))), item) => {
+                if let Some(values) = item.as_array() {
+                    for value in values.iter() {
+                        let value = value.clone().decorated("", "");
+                        if existing.iter().any(|other| {
+                            other.clone().decorated("", "").to_string() == value.to_string()
+                        }) {
+                            continue;
+                        }
+
+                        let multiline_decor = existing
+                            .iter()
+                            .last()
+                            .map(|last| last.decor().clone())
+                            .filter(|decor| {
+                                decor
+                                    .prefix()
+                                    .and_then(|prefix| prefix.as_str())
+                                    .is_some_and(|prefix| prefix.contains('\n'))
+                            });
+                        match multiline_decor {
+                            Some(mut decor) => {
+                                // A comment after the last value belongs to it, so it stays on
+                                // that value's line instead of following the new one
+                                let trailing =
+                                    existing.trailing().as_str().unwrap_or("").to_string();
+                                if let Some(newline) =
+                                    trailing.rfind('\n').filter(|_| trailing.contains('#'))
+                                {
+                                    let prefix = decor
+                                        .prefix()
+                                        .and_then(|prefix| prefix.as_str())
+                                        .unwrap_or("");
+                                    decor.set_prefix(format!(
+                                        "{}{}",
+                                        trailing[..newline].trim_end(),
+                                        prefix
+                                    ));
+                                    existing.set_trailing(&trailing[newline..]);
+                                }
+                                let mut value = value;
+                                *value.decor_mut() = decor;
+                                existing.push_formatted(value);
+                            }
+                            None => existing.push(value),
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn last_position(table: &toml_edit::Table) -> usize {
+    table
+        .iter()
+        .map(|(_, item)| match item {
+            toml_edit::Item::Table(child) => {
+                child.position().unwrap_or(0).max(last_position(child))
+            }
+            toml_edit::Item::ArrayOfTables(tables) => tables
+                .iter()
+                .map(|child| child.position().unwrap_or(0))
+                .max()
+                .unwrap_or(0),
+            _ => 0,
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+fn reposition(item: &mut toml_edit::Item, next_position: &mut usize) {
+    match item {
+        toml_edit::Item::Table(table) => {
+            table.set_position(*next_position);
+            table.decor_mut().set_prefix("\n");
+            *next_position += 1;
+            for (_, child) in table.iter_mut() {
+                reposition(child, next_position);
+            }
+        }
+        toml_edit::Item::ArrayOfTables(tables) => {
+            for table in tables.iter_mut() {
+                table.set_position(*next_position);
+                table.decor_mut().set_prefix("\n");
+                *next_position += 1;
+            }
+        }
+        _ => {}
+    }
+}
+
+pub fn detect_lang(project_dir: &Path) -> Option<Lang> {
+    if project_dir.join("Cargo.toml").is_file() {
+        return Some(Lang::Rust);
+    }
+    if project_dir.join("go.mod").is_file() {
+        return Some(Lang::Go);
+    }
+    if project_dir.join("pom.xml").is_file() {
+        return Some(Lang::Java);
+    }
+    if project_dir.join("build.sbt").is_file() {
+        return Some(Lang::Scala);
+    }
+    if project_dir.join("composer.json").is_file() {
+        return Some(Lang::Php);
+    }
+    if project_dir.join("Gemfile").is_file() {
+        return Some(Lang::Ruby);
+    }
+    if project_dir.join("mix.exs").is_file() {
+        return Some(Lang::Elixir);
+    }
+    if project_dir.join("pyproject.toml").is_file() {
+        return Some(Lang::Python);
+    }
+    if project_dir.join(".shellcheckrc").is_file() {
+        return Some(Lang::Shell);
+    }
+    if project_dir.join(".latexmkrc").is_file() {
+        return Some(Lang::Latex);
+    }
+    if project_dir.join("versions.tf").is_file() {
+        return Some(Lang::Terraform);
+    }
+    if project_dir.join(".luacheckrc").is_file() {
+        return Some(Lang::Lua);
+    }
+
+    let src_dir = project_dir.join("src");
+    let has_extension = |extension: &str| {
+        fs::read_dir(&src_dir)
+            .map(|entries| {
+                entries.filter_map(Result::ok).any(|entry| {
+                    entry.path().extension().and_then(|ext| ext.to_str()) == Some(extension)
+                })
+            })
+            .unwrap_or(false)
+    };
+    if has_extension("cpp") || has_extension("cc") {
+        return Some(Lang::Cpp);
+    }
+    if has_extension("c") || has_extension("h") {
+        return Some(Lang::C);
+    }
+    if has_extension("asm") {
+        return Some(Lang::Asm);
+    }
+
+    let makefile = fs::read_to_string(project_dir.join("Makefile")).ok()?;
+    if makefile.contains("clang++") || makefile.contains("g++") {
+        Some(Lang::Cpp)
+    } else if makefile.contains("CC=") {
+        Some(Lang::C)
+    } else {
+        None
+    }
+}
+
+fn project_name(project_dir: &Path, lang: &Lang) -> String {
+    let from_manifest = match lang {
+        Lang::Rust => fs::read_to_string(project_dir.join("Cargo.toml"))
+            .ok()
+            .and_then(|manifest| manifest.parse::<toml::Table>().ok())
+            .and_then(|manifest| {
+                manifest
+                    .get("package")?
+                    .get("name")?
+                    .as_str()
+                    .map(String::from)
+            }),
+        Lang::Go => go_module_path(project_dir)
+            .and_then(|module| module.rsplit('/').next().map(String::from)),
+        _ => None,
+    };
+
+    from_manifest.unwrap_or_else(|| {
+        project_dir
+            .canonicalize()
+            .ok()
+            .and_then(|dir| {
+                dir.file_name()
+                    .map(|name| name.to_string_lossy().to_string())
+            })
+            .unwrap_or_default()
+    })
+}
+
+pub(crate) fn go_module_path(project_dir: &Path) -> Option<String> {
+    let go_mod = fs::read_to_string(project_dir.join("go.mod")).ok()?;
+    go_mod
+        .lines()
+        .find_map(|line| line.trim().strip_prefix("module "))
+        .map(|module| module.trim().to_string())
+}
+
+fn go_package(project_dir: &Path) -> Option<String> {
+    fs::read_dir(project_dir)
+        .ok()?
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().and_then(|ext| ext.to_str()) == Some("go"))
+        .filter_map(|path| fs::read_to_string(path).ok())
+        .find_map(|source| {
+            source
+                .lines()
+                .find_map(|line| line.trim().strip_prefix("package "))
+                .map(|package| package.trim().to_string())
+        })
+}
+
+pub(crate) fn java_package(project_dir: &Path) -> Option<String> {
+    let pom = fs::read_to_string(project_dir.join("pom.xml")).ok()?;
+    let start = pom.find("<groupId>")? + "<groupId>".len();
+    let end = start + pom[start..].find("</groupId>")?;
+    Some(pom[start..end].trim().to_string())
+}
+
+pub fn snake_case(name: &str) -> String {
+    let mut snake = String::new();
+    let mut previous_lower = false;
+    for c in name.chars() {
+        if c == '-' || c == ' ' || c == '_' {
+            if !snake.ends_with('_') && !snake.is_empty() {
+                snake.push('_');
+            }
+            previous_lower = false;
+        } else if c.is_uppercase() {
+            if previous_lower && !snake.ends_with('_') {
+                snake.push('_');
+            }
+            snake.extend(c.to_lowercase());
+            previous_lower = false;
+        } else {
+            snake.push(c);
+            previous_lower = c.is_lowercase() || c.is_ascii_digit();
+        }
+    }
+    snake.trim_end_matches('_').to_string()
+}
+
+pub fn pascal_case(name: &str) -> String {
+    snake_case(name)
+        .split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+pub fn php_namespace(domain: &str, name: &str) -> String {
+    let vendor = domain.rsplit(['.', '/']).next().unwrap_or(domain);
+    format!("{}\\{}", pascal_case(vendor), pascal_case(name))
+}
+
+pub fn camel_case(name: &str) -> String {
+    let pascal = pascal_case(name);
+    let mut chars = pascal.chars();
+    match chars.next() {
+        Some(first) => first.to_lowercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{merge_toml, Component};
+    use crate::project::{Lang, ProjectKind};
+    use crate::record::RECORD_FILE;
+    use std::fs;
+    use std::path::Path;
+    use tempfile::TempDir;
+
+    #[test]
+    fn rejects_component_names_that_leave_the_project() {
+        let project_dir = TempDir::new().unwrap();
+        fs::write(project_dir.path().join("Cargo.toml"), "[package]\n").unwrap();
+
+        let error = Component::new("module", "../../escaped", project_dir.path()).unwrap_err();
+        assert!(error.to_string().contains("Invalid component name"));
+    }
+
+    #[test]
+    fn takes_the_project_kind_from_the_record() {
+        let project_dir = TempDir::new().unwrap();
+        let templates_root = Path::new(env!("CARGO_MANIFEST_DIR")).join("templates");
+        fs::write(project_dir.path().join("Cargo.toml"), "[package]\n").unwrap();
+        fs::write(
+            project_dir.path().join(RECORD_FILE),
+            "gen_version = \"0.1.0\"\ntemplate_set = \"rust\"\ntemplate_hash = \"0\"\n\
+             lang = \"Rust\"\nkind = \"Daemon\"\n\n[variables]\n",
+        )
+        .unwrap();
+
+        let component = Component::with_template_dir(
+            "module",
+            "user",
+            project_dir.path(),
+            Lang::Rust,
+            &templates_root.join("rust"),
+        )
+        .unwrap();
+        assert_eq!(component.context().project_kind, Some(ProjectKind::Daemon));
+    }
+
+    #[test]
+    fn merges_toml_keeping_comments() {
+        let mut manifest = r#"# The demo crate
+[package]
+name = "demo" # keep this name
+
+[dependencies]
+# logging
+log = "0.4"
+
+[features]
+default = [
+    "std", # std comment
+]
+
+[[bench]]
+name = "existing"
+harness = false
+"#
+        .parse::<toml_edit::Document>()
+        .unwrap();
+        let source = r#"
+[dependencies]
+log = "0.4.20"
+serde = "1"
+
+[features]
+default = ["std", "serde"]
+
+[[bench]]
+name = "existing"
+harness = false
+
+[[bench]]
+name = "parse"
+harness = false
+"#
+        .parse::<toml_edit::Document>()
+        .unwrap();
+
+        merge_toml(manifest.as_table_mut(), source.as_table());
+        assert_eq!(
+            manifest.to_string(),
+            r#"# The demo crate
+[package]
+name = "demo" # keep this name
+
+[dependencies]
+# logging
+log = "0.4"
+serde = "1"
+
+[features]
+default = [
+    "std", # std comment
+    "serde",
+]
+
+[[bench]]
+name = "existing"
+harness = false
+
+[[bench]]
+name = "parse"
+harness = false
+"#
+        );
+    }
+}
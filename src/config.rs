@@ -0,0 +1,268 @@
+use crate::forge::ForgeConfig;
+use crate::git::GitSettings;
+use crate::project::{HookPolicy, Lang};
+use crate::vcs::VcsKind;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// Keys that decide which templates and commands run, or where forge tokens are sent. A .genrc
+// can come with any checked-out repository, so only config.toml may set them
+const USER_ONLY_KEYS: &[&str] = &["templates", "post_generate", "editor", "forges"];
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    pub projects_root: Option<String>,
+    pub default_lang: Option<String>,
+    pub default_kind: Option<String>,
+    pub domain: Option<String>,
+    pub domains: HashMap<String, String>,
+    pub license: Option<String>,
+    pub license_header: bool,
+    pub templates: Option<String>,
+    pub template: Option<String>,
+    pub ci: Option<String>,
+    pub task_runner: Option<String>,
+    pub packaging: Vec<String>,
+    pub k8s: Option<String>,
+    pub registry: Option<String>,
+    pub errors: Option<String>,
+    pub author: Option<String>,
+    pub format: FormatConfig,
+    pub forge: Option<String>,
+    pub forges: HashMap<String, ForgeConfig>,
+    pub git: GitSettings,
+    pub vcs: Option<VcsKind>,
+    pub post_generate: HookPolicy,
+    pub editor: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct FormatConfig {
+    pub enabled: bool,
+    pub indent_width: usize,
+    pub max_width: usize,
+}
+
+impl Default for FormatConfig {
+    fn default() -> FormatConfig {
+        FormatConfig {
+            enabled: true,
+            indent_width: 4,
+            max_width: 100,
+        }
+    }
+}
+
+impl Config {
+    pub fn load() -> anyhow::Result<Config> {
+        Config::load_profile(None)
+    }
+
+    pub fn load_profile(profile: Option<&str>) -> anyhow::Result<Config> {
+        Config::load_files(
+            &config_dir().join("config.toml"),
+            &discover_genrc(&std::env::current_dir()?),
+            profile,
+        )
+    }
+
+    // config.toml, then the .genrc files from farthest to nearest, then the profile
+    fn load_files(
+        config_path: &Path,
+        genrcs: &[PathBuf],
+        profile: Option<&str>,
+    ) -> anyhow::Result<Config> {
+        let mut settings = read_table(config_path)?;
+        for genrc in genrcs.iter().rev() {
+            let mut overrides = read_table(genrc)?;
+            drop_user_only_keys(&mut overrides, genrc);
+            merge_tables(&mut settings, overrides);
+        }
+
+        let profiles = settings.remove("profile");
+        if let Some(profile) = profile {
+            match profiles.as_ref().and_then(|profiles| profiles.get(profile)) {
+                Some(toml::Value::Table(overrides)) => {
+                    merge_tables(&mut settings, overrides.clone())
+                }
+                _ => return Err(anyhow::anyhow!("Unknown profile {}", profile)),
+            }
+        }
+
+        match toml::Value::Table(settings).try_into() {
+            Ok(config) => Ok(config),
+            Err(error) => Err(anyhow::anyhow!("Invalid configuration: {}", error)),
+        }
+    }
+
+    pub fn forge(&self, name: &str) -> ForgeConfig {
+        self.forges.get(name).cloned().unwrap_or_default()
+    }
+
+    pub fn domain_for(&self, lang: &Lang) -> Option<String> {
+        self.domains
+            .get(lang.name())
+            .or(self.domain.as_ref())
+            .cloned()
+    }
+
+    pub fn projects_root(&self) -> Option<PathBuf> {
+        self.projects_root.as_deref().map(expand_tilde)
+    }
+
+    pub fn templates(&self) -> Option<PathBuf> {
+        self.templates.as_deref().map(expand_tilde)
+    }
+
+    pub fn templates_root(&self) -> PathBuf {
+        self.templates()
+            .unwrap_or_else(|| config_dir().join("templates"))
+    }
+}
+
+pub fn discover_genrc(dir: &Path) -> Vec<PathBuf> {
+    dir.ancestors()
+        .map(|ancestor| ancestor.join(".genrc"))
+        .filter(|genrc| genrc.is_file())
+        .collect()
+}
+
+fn drop_user_only_keys(table: &mut toml::Table, genrc: &Path) {
+    for key in USER_ONLY_KEYS {
+        if table.remove(*key).is_some() {
+            println!(
+                "Warning: ignoring {} in {}, it can only be set in config.toml",
+                key,
+                genrc.display()
+            );
+        }
+    }
+    if let Some(toml::Value::Table(profiles)) = table.get_mut("profile") {
+        for (_, profile) in profiles.iter_mut() {
+            if let toml::Value::Table(profile) = profile {
+                drop_user_only_keys(profile, genrc);
+            }
+        }
+    }
+}
+
+pub(crate) fn read_table(path: &Path) -> anyhow::Result<toml::Table> {
+    if !path.is_file() {
+        return Ok(toml::Table::new());
+    }
+
+    let contents = fs::read_to_string(path)?;
+    match toml::from_str(&contents) {
+        Ok(table) => Ok(table),
+        Err(error) => Err(anyhow::anyhow!(
+            "Could not parse {}: {}",
+            path.display(),
+            error
+        )),
+    }
+}
+
+pub(crate) fn merge_tables(base: &mut toml::Table, overrides: toml::Table) {
+    for (key, value) in overrides {
+        match (base.get_mut(&key), value) {
+            (Some(toml::Value::Table(existing)), toml::Value::Table(table)) => {
+                merge_tables(existing, table)
+            }
+            (_, value) => {
+                base.insert(key, value);
+            }
+        }
+    }
+}
+
+pub fn home_dir() -> PathBuf {
+    PathBuf::from(std::env::var("HOME").expect("Could not find $HOME"))
+}
+
+pub fn config_dir() -> PathBuf {
+    home_dir().join(".config/gen")
+}
+
+pub fn expand_tilde<P: AsRef<Path>>(path: P) -> PathBuf {
+    let path = path.as_ref();
+    match path.strip_prefix("~") {
+        Ok(rest) => home_dir().join(rest),
+        Err(_) => path.to_path_buf(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{drop_user_only_keys, Config};
+    use crate::project::{Lang, ProjectBuilder};
+    use std::fs;
+    use std::path::Path;
+    use tempfile::TempDir;
+
+    #[test]
+    fn genrc_cannot_set_user_only_keys() {
+        let mut table: toml::Table = toml::from_str(
+            r#"
+            domain = "com.acme"
+            templates = "/tmp/templates"
+            post_generate = "allow"
+
+            [forges.github]
+            base_url = "https://example.com"
+
+            [profile.work]
+            license = "MIT"
+            editor = "sh -c"
+            "#,
+        )
+        .unwrap();
+        drop_user_only_keys(&mut table, Path::new(".genrc"));
+
+        let expected: toml::Table = toml::from_str(
+            r#"
+            domain = "com.acme"
+
+            [profile.work]
+            license = "MIT"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(table, expected);
+    }
+
+    #[test]
+    fn profiles_choose_the_template_set() {
+        let dir = TempDir::new().unwrap();
+        let templates_root = Path::new(env!("CARGO_MANIFEST_DIR")).join("templates");
+        let config_path = dir.path().join("config.toml");
+        fs::write(
+            &config_path,
+            format!(
+                "templates = {:?}\n\n[profile.work]\ntemplate = \"monorepo\"\n",
+                templates_root
+            ),
+        )
+        .unwrap();
+        let genrcs = [dir.path().join(".genrc")];
+        fs::write(&genrcs[0], "[profile.oss]\ntemplate = \"rust\"\n").unwrap();
+
+        let expected = [
+            (None, templates_root.join("c")),
+            (Some("work"), templates_root.join("monorepo")),
+            (Some("oss"), templates_root.join("rust")),
+        ];
+        for (profile, template_dir) in expected {
+            let config = Config::load_files(&config_path, &genrcs, profile).unwrap();
+            let project = ProjectBuilder::new("demo")
+                .lang(Lang::C)
+                .config_templates(&config, None)
+                .build()
+                .unwrap();
+            assert_eq!(project.template_dir(), Some(&template_dir));
+        }
+    }
+}
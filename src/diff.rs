@@ -0,0 +1,69 @@
+use crate::project::Project;
+use crate::record::{file_contents, RECORD_FILE};
+use anyhow::anyhow;
+use similar::TextDiff;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug)]
+pub struct FileChange {
+    pub path: PathBuf,
+    pub current: Option<Vec<u8>>,
+    pub rendered: Vec<u8>,
+}
+
+pub fn preview(project: &Project) -> anyhow::Result<()> {
+    let project_dir = match project.project_dir() {
+        Some(project_dir) => project_dir.clone(),
+        None => return Err(anyhow!("Project directory not set")),
+    };
+
+    let changes = changes(&project_dir, project.render_in_memory()?);
+    if changes.is_empty() {
+        println!("{} is up to date", project_dir.display());
+    }
+    for change in &changes {
+        print_diff(change);
+    }
+    Ok(())
+}
+
+pub fn changes(project_dir: &Path, rendered: Vec<(PathBuf, Vec<u8>)>) -> Vec<FileChange> {
+    let mut changes = Vec::new();
+    for (path, rendered) in rendered {
+        if path == Path::new(RECORD_FILE) {
+            continue;
+        }
+
+        let current = file_contents(&project_dir.join(&path)).ok();
+        if current.as_ref() != Some(&rendered) {
+            changes.push(FileChange {
+                path,
+                current,
+                rendered,
+            });
+        }
+    }
+    changes
+}
+
+pub fn print_diff(change: &FileChange) {
+    let current = change.current.clone().unwrap_or_default();
+    match (
+        std::str::from_utf8(&current),
+        std::str::from_utf8(&change.rendered),
+    ) {
+        (Ok(current), Ok(rendered)) => {
+            let old_header = match change.current {
+                Some(_) => format!("a/{}", change.path.display()),
+                None => String::from("/dev/null"),
+            };
+            print!(
+                "{}",
+                TextDiff::from_lines(current, rendered)
+                    .unified_diff()
+                    .header(&old_header, &format!("b/{}", change.path.display()))
+            );
+        }
+        _ => println!("Binary file {} differs", change.path.display()),
+    }
+}
@@ -0,0 +1,311 @@
+use anyhow::anyhow;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+#[derive(Debug, Deserialize, Serialize, Eq, PartialEq, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum ForgeKind {
+    Github,
+    Gitlab,
+    Gitea,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct ForgeConfig {
+    pub kind: Option<ForgeKind>,
+    pub base_url: Option<String>,
+    #[serde(skip_serializing)]
+    pub token: Option<String>,
+    pub owner: Option<String>,
+    pub private: Option<bool>,
+}
+
+#[derive(Debug, Clone)]
+pub struct RepositoryRequest {
+    pub name: String,
+    pub description: Option<String>,
+    pub owner: Option<String>,
+    pub private: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct RemoteRepository {
+    pub full_name: String,
+    pub url: String,
+}
+
+pub trait Forge {
+    fn name(&self) -> &str;
+    fn create_repository(&self, request: &RepositoryRequest) -> anyhow::Result<RemoteRepository>;
+}
+
+pub fn connect(name: &str, config: &ForgeConfig) -> anyhow::Result<Box<dyn Forge>> {
+    let kind = match (config.kind, name) {
+        (Some(kind), _) => kind,
+        (None, "github") => ForgeKind::Github,
+        (None, "gitlab") => ForgeKind::Gitlab,
+        (None, "gitea") | (None, "codeberg") => ForgeKind::Gitea,
+        (None, _) => {
+            return Err(anyhow!(
+                "Unknown forge {}. Set kind = \"github\", \"gitlab\" or \"gitea\" in [forges.{}]",
+                name,
+                name
+            ))
+        }
+    };
+
+    let token_env = match kind {
+        ForgeKind::Github => ["GITHUB_TOKEN", "GH_TOKEN"],
+        ForgeKind::Gitlab => ["GITLAB_TOKEN", "GL_TOKEN"],
+        ForgeKind::Gitea => ["GITEA_TOKEN", "CODEBERG_TOKEN"],
+    };
+    let token = config.token.clone().or_else(|| {
+        token_env
+            .iter()
+            .find_map(|variable| std::env::var(variable).ok())
+    });
+
+    match kind {
+        ForgeKind::Github => Ok(Box::new(GitHub {
+            base_url: config
+                .base_url
+                .clone()
+                .unwrap_or_else(|| String::from("https://api.github.com")),
+            token,
+        })),
+        ForgeKind::Gitlab => Ok(Box::new(GitLab {
+            base_url: config
+                .base_url
+                .clone()
+                .unwrap_or_else(|| String::from("https://gitlab.com")),
+            token: token.ok_or_else(|| anyhow!("No token configured for forge {}", name))?,
+        })),
+        ForgeKind::Gitea => Ok(Box::new(Gitea {
+            base_url: match (&config.base_url, name) {
+                (Some(base_url), _) => base_url.clone(),
+                (None, "codeberg") => String::from("https://codeberg.org"),
+                (None, _) => String::from("https://gitea.com"),
+            },
+            token: token.ok_or_else(|| anyhow!("No token configured for forge {}", name))?,
+        })),
+    }
+}
+
+pub struct GitHub {
+    base_url: String,
+    token: Option<String>,
+}
+
+impl Forge for GitHub {
+    fn name(&self) -> &str {
+        "GitHub"
+    }
+
+    fn create_repository(&self, request: &RepositoryRequest) -> anyhow::Result<RemoteRepository> {
+        let token = match &self.token {
+            Some(token) => token,
+            None => return create_with_gh(request),
+        };
+        let auth = format!("Authorization: Bearer {}", token);
+
+        let user = api_request(&auth, "GET", &format!("{}/user", self.base_url), None)?;
+        let endpoint = match &request.owner {
+            Some(owner) if Some(owner.as_str()) != user["login"].as_str() => {
+                format!("{}/orgs/{}/repos", self.base_url, owner)
+            }
+            _ => format!("{}/user/repos", self.base_url),
+        };
+        let payload = json!({
+            "name": request.name,
+            "description": request.description,
+            "private": request.private,
+        });
+
+        let repository = api_request(&auth, "POST", &endpoint, Some(&payload))?;
+        remote_repository(&repository, "full_name", "ssh_url")
+    }
+}
+
+pub struct GitLab {
+    base_url: String,
+    token: String,
+}
+
+impl Forge for GitLab {
+    fn name(&self) -> &str {
+        "GitLab"
+    }
+
+    fn create_repository(&self, request: &RepositoryRequest) -> anyhow::Result<RemoteRepository> {
+        let auth = format!("PRIVATE-TOKEN: {}", self.token);
+        let mut payload = json!({
+            "name": request.name,
+            "description": request.description,
+            "visibility": if request.private { "private" } else { "public" },
+        });
+
+        if let Some(owner) = &request.owner {
+            let namespace = api_request(
+                &auth,
+                "GET",
+                &format!("{}/api/v4/namespaces/{}", self.base_url, owner),
+                None,
+            )?;
+            match namespace["id"].as_u64() {
+                Some(id) => payload["namespace_id"] = json!(id),
+                None => return Err(anyhow!("Could not find GitLab namespace {}", owner)),
+            }
+        }
+
+        let repository = api_request(
+            &auth,
+            "POST",
+            &format!("{}/api/v4/projects", self.base_url),
+            Some(&payload),
+        )?;
+        remote_repository(&repository, "path_with_namespace", "ssh_url_to_repo")
+    }
+}
+
+pub struct Gitea {
+    base_url: String,
+    token: String,
+}
+
+impl Forge for Gitea {
+    fn name(&self) -> &str {
+        "Gitea"
+    }
+
+    fn create_repository(&self, request: &RepositoryRequest) -> anyhow::Result<RemoteRepository> {
+        let auth = format!("Authorization: token {}", self.token);
+
+        let user = api_request(
+            &auth,
+            "GET",
+            &format!("{}/api/v1/user", self.base_url),
+            None,
+        )?;
+        let endpoint = match &request.owner {
+            Some(owner) if Some(owner.as_str()) != user["login"].as_str() => {
+                format!("{}/api/v1/orgs/{}/repos", self.base_url, owner)
+            }
+            _ => format!("{}/api/v1/user/repos", self.base_url),
+        };
+        let payload = json!({
+            "name": request.name,
+            "description": request.description,
+            "private": request.private,
+        });
+
+        let repository = api_request(&auth, "POST", &endpoint, Some(&payload))?;
+        remote_repository(&repository, "full_name", "ssh_url")
+    }
+}
+
+fn create_with_gh(request: &RepositoryRequest) -> anyhow::Result<RemoteRepository> {
+    let full_name = match &request.owner {
+        Some(owner) => format!("{}/{}", owner, request.name),
+        None => request.name.clone(),
+    };
+
+    let mut command = Command::new("gh");
+    command
+        .arg("repo")
+        .arg("create")
+        .arg(&full_name)
+        .arg(if request.private {
+            "--private"
+        } else {
+            "--public"
+        });
+    if let Some(description) = &request.description {
+        command.arg("--description").arg(description);
+    }
+
+    let output = match command.output() {
+        Ok(output) => output,
+        Err(_) => {
+            return Err(anyhow!(
+                "Publishing to GitHub requires the gh CLI or a token (GITHUB_TOKEN or [forges.github] token in config.toml)"
+            ))
+        }
+    };
+    if !output.status.success() {
+        return Err(anyhow!(
+            "gh repo create failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+
+    let url = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    Ok(RemoteRepository {
+        full_name,
+        url: format!("{}.git", url),
+    })
+}
+
+fn api_request(
+    auth_header: &str,
+    method: &str,
+    url: &str,
+    payload: Option<&Value>,
+) -> anyhow::Result<Value> {
+    let mut command = Command::new("curl");
+    command
+        .arg("-sS")
+        .arg("-X")
+        .arg(method)
+        .arg("-H")
+        .arg("@-")
+        .arg("-H")
+        .arg("Accept: application/json")
+        .arg("-H")
+        .arg("Content-Type: application/json")
+        .arg(url);
+    if let Some(payload) = payload {
+        command.arg("-d").arg(payload.to_string());
+    }
+
+    let mut child = command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+    if let Some(mut stdin) = child.stdin.take() {
+        writeln!(stdin, "{}", auth_header)?;
+    }
+
+    let output = child.wait_with_output()?;
+    if !output.status.success() {
+        return Err(anyhow!(
+            "API request to {} failed: {}",
+            url,
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(serde_json::from_slice(&output.stdout)?)
+}
+
+fn remote_repository(
+    response: &Value,
+    name_field: &str,
+    url_field: &str,
+) -> anyhow::Result<RemoteRepository> {
+    match (response[name_field].as_str(), response[url_field].as_str()) {
+        (Some(full_name), Some(url)) => Ok(RemoteRepository {
+            full_name: full_name.to_string(),
+            url: url.to_string(),
+        }),
+        _ => Err(anyhow!(
+            "Could not create repository: {}",
+            response["message"]
+                .as_str()
+                .map(String::from)
+                .unwrap_or_else(|| response.to_string())
+        )),
+    }
+}
@@ -0,0 +1,235 @@
+use crate::languages;
+use crate::plugin;
+use crate::project::{Lang, Project};
+use anyhow::anyhow;
+use std::collections::HashMap;
+use std::panic;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::thread;
+
+pub trait LanguageGenerator: Send + Sync {
+    fn prepare(&self, project: &Project) -> anyhow::Result<()> {
+        project.create_dir()
+    }
+
+    fn scaffold(&self, project: &Project) -> anyhow::Result<()>;
+
+    // Whether scaffold only adds its own files to the directory created by prepare, so the
+    // files that don't depend on it can be rendered while it runs
+    fn scaffolds_in_place(&self) -> bool {
+        false
+    }
+
+    fn post_process(&self, _project: &Project) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+struct RustGenerator;
+
+impl LanguageGenerator for RustGenerator {
+    fn prepare(&self, _project: &Project) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn scaffold(&self, project: &Project) -> anyhow::Result<()> {
+        project.create_rust_project()
+    }
+}
+
+struct CGenerator;
+
+impl LanguageGenerator for CGenerator {
+    fn scaffold(&self, project: &Project) -> anyhow::Result<()> {
+        concurrently(
+            || project.create_clang_format(),
+            || project.create_c_project(),
+        )
+    }
+}
+
+struct CppGenerator;
+
+impl LanguageGenerator for CppGenerator {
+    fn scaffold(&self, project: &Project) -> anyhow::Result<()> {
+        concurrently(
+            || project.create_clang_format(),
+            || project.create_cpp_project(),
+        )
+    }
+}
+
+struct GoGenerator;
+
+impl LanguageGenerator for GoGenerator {
+    fn scaffold(&self, project: &Project) -> anyhow::Result<()> {
+        project.create_go_project()
+    }
+}
+
+struct JavaGenerator;
+
+impl LanguageGenerator for JavaGenerator {
+    fn scaffold(&self, project: &Project) -> anyhow::Result<()> {
+        project.create_java_project()
+    }
+
+    fn scaffolds_in_place(&self) -> bool {
+        true
+    }
+
+    fn post_process(&self, project: &Project) -> anyhow::Result<()> {
+        project.move_java_archetype()
+    }
+}
+
+struct AsmGenerator;
+
+impl LanguageGenerator for AsmGenerator {
+    fn scaffold(&self, project: &Project) -> anyhow::Result<()> {
+        project.create_asm_project()
+    }
+}
+
+struct ShellGenerator;
+
+impl LanguageGenerator for ShellGenerator {
+    fn scaffold(&self, project: &Project) -> anyhow::Result<()> {
+        project.create_shell_project()
+    }
+}
+
+struct PythonGenerator;
+
+impl LanguageGenerator for PythonGenerator {
+    fn scaffold(&self, project: &Project) -> anyhow::Result<()> {
+        project.create_python_project()
+    }
+}
+
+struct ElixirGenerator;
+
+impl LanguageGenerator for ElixirGenerator {
+    fn prepare(&self, _project: &Project) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn scaffold(&self, project: &Project) -> anyhow::Result<()> {
+        project.create_elixir_project()
+    }
+}
+
+struct RubyGenerator;
+
+impl LanguageGenerator for RubyGenerator {
+    fn scaffold(&self, project: &Project) -> anyhow::Result<()> {
+        project.create_ruby_project()
+    }
+}
+
+struct ScalaGenerator;
+
+impl LanguageGenerator for ScalaGenerator {
+    fn scaffold(&self, project: &Project) -> anyhow::Result<()> {
+        project.create_scala_project()
+    }
+}
+
+struct LuaGenerator;
+
+impl LanguageGenerator for LuaGenerator {
+    fn scaffold(&self, project: &Project) -> anyhow::Result<()> {
+        project.create_lua_project()
+    }
+}
+
+struct PhpGenerator;
+
+impl LanguageGenerator for PhpGenerator {
+    fn scaffold(&self, project: &Project) -> anyhow::Result<()> {
+        project.create_php_project()
+    }
+}
+
+struct TerraformGenerator;
+
+impl LanguageGenerator for TerraformGenerator {
+    fn scaffold(&self, project: &Project) -> anyhow::Result<()> {
+        project.create_terraform_project()
+    }
+}
+
+struct LatexGenerator;
+
+impl LanguageGenerator for LatexGenerator {
+    fn scaffold(&self, project: &Project) -> anyhow::Result<()> {
+        project.create_latex_project()
+    }
+}
+
+type Registry = Mutex<HashMap<String, Arc<dyn LanguageGenerator>>>;
+
+fn registry() -> &'static Registry {
+    static REGISTRY: OnceLock<Registry> = OnceLock::new();
+    REGISTRY.get_or_init(|| {
+        let mut generators: HashMap<String, Arc<dyn LanguageGenerator>> = HashMap::new();
+        generators.insert(String::from("rust"), Arc::new(RustGenerator));
+        generators.insert(String::from("c"), Arc::new(CGenerator));
+        generators.insert(String::from("cpp"), Arc::new(CppGenerator));
+        generators.insert(String::from("go"), Arc::new(GoGenerator));
+        generators.insert(String::from("java"), Arc::new(JavaGenerator));
+        generators.insert(String::from("asm"), Arc::new(AsmGenerator));
+        generators.insert(String::from("shell"), Arc::new(ShellGenerator));
+        generators.insert(String::from("python"), Arc::new(PythonGenerator));
+        generators.insert(String::from("elixir"), Arc::new(ElixirGenerator));
+        generators.insert(String::from("ruby"), Arc::new(RubyGenerator));
+        generators.insert(String::from("scala"), Arc::new(ScalaGenerator));
+        generators.insert(String::from("lua"), Arc::new(LuaGenerator));
+        generators.insert(String::from("php"), Arc::new(PhpGenerator));
+        generators.insert(String::from("terraform"), Arc::new(TerraformGenerator));
+        generators.insert(String::from("latex"), Arc::new(LatexGenerator));
+        Mutex::new(generators)
+    })
+}
+
+pub fn register(name: &str, generator: impl LanguageGenerator + 'static) {
+    if let Ok(mut generators) = registry().lock() {
+        generators.insert(name.to_string(), Arc::new(generator));
+    }
+}
+
+pub fn is_registered(name: &str) -> bool {
+    registry()
+        .lock()
+        .map(|generators| generators.contains_key(name))
+        .unwrap_or(false)
+}
+
+pub fn generator(lang: Lang) -> anyhow::Result<Arc<dyn LanguageGenerator>> {
+    let name = lang.name();
+    if !is_registered(name) && !languages::register(name) && !plugin::register(name) {
+        return Err(anyhow!("No generator registered for {}", name));
+    }
+
+    match registry().lock() {
+        Ok(generators) => match generators.get(name) {
+            Some(generator) => Ok(generator.clone()),
+            None => Err(anyhow!("No generator registered for {}", name)),
+        },
+        Err(_) => Err(anyhow!("Language registry is unavailable")),
+    }
+}
+
+pub fn concurrently(
+    background: impl FnOnce() -> anyhow::Result<()> + Send,
+    foreground: impl FnOnce() -> anyhow::Result<()>,
+) -> anyhow::Result<()> {
+    thread::scope(|scope| {
+        let background = scope.spawn(background);
+        let foreground = foreground();
+        background
+            .join()
+            .unwrap_or_else(|payload| panic::resume_unwind(payload))?;
+        foreground
+    })
+}
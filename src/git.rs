@@ -0,0 +1,129 @@
+use anyhow::anyhow;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::process::Command;
+
+#[derive(Debug, Deserialize, Serialize, Eq, PartialEq, Clone, Copy, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum SignMode {
+    #[default]
+    None,
+    Gpg,
+    Ssh,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct GitSettings {
+    pub default_branch: Option<String>,
+    pub sign: SignMode,
+    pub signing_key: Option<String>,
+}
+
+pub fn config_value(key: &str) -> Option<String> {
+    let output = Command::new("git").arg("config").arg(key).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+
+    let value = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    if value.is_empty() {
+        None
+    } else {
+        Some(value)
+    }
+}
+
+pub fn default_author() -> Option<String> {
+    let name = config_value("user.name")?;
+    match config_value("user.email") {
+        Some(email) => Some(format!("{} <{}>", name, email)),
+        None => Some(name),
+    }
+}
+
+pub fn run(dir: &Path, args: &[&str]) -> anyhow::Result<String> {
+    let output = Command::new("git").args(args).current_dir(dir).output()?;
+    if !output.status.success() {
+        return Err(anyhow!(
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+pub fn init(dir: &Path, settings: &GitSettings) -> anyhow::Result<()> {
+    if !dir.join(".git").is_dir() {
+        run(dir, &["init"])?;
+        println!("Initialized git repository in {}", dir.display());
+    }
+
+    if let Some(branch) = &settings.default_branch {
+        run(
+            dir,
+            &["symbolic-ref", "HEAD", &format!("refs/heads/{}", branch)],
+        )?;
+        println!("Set initial branch to {}", branch);
+    }
+    Ok(())
+}
+
+pub fn commit_all(dir: &Path, message: &str, settings: &GitSettings) -> anyhow::Result<()> {
+    run(dir, &["add", "-A"])?;
+
+    let sign_flag = match &settings.signing_key {
+        Some(key) => format!("-S{}", key),
+        None => String::from("-S"),
+    };
+    let mut args = Vec::new();
+    match settings.sign {
+        SignMode::None => {}
+        SignMode::Gpg => args.extend(["-c", "gpg.format=openpgp"]),
+        SignMode::Ssh => args.extend(["-c", "gpg.format=ssh"]),
+    }
+    args.extend(["commit", "--no-verify", "-m", message]);
+    if settings.sign != SignMode::None {
+        args.push(&sign_flag);
+    }
+
+    run(dir, &args)?;
+    println!("Created commit \"{}\"", message);
+    Ok(())
+}
+
+pub fn add_remote(dir: &Path, name: &str, url: &str) -> anyhow::Result<()> {
+    run(dir, &["remote", "add", name, url])?;
+    println!("Added remote {} {}", name, url);
+    Ok(())
+}
+
+pub fn push(dir: &Path, remote: &str) -> anyhow::Result<()> {
+    run(dir, &["push", "-u", remote, "HEAD"])?;
+    println!("Pushed to {}", remote);
+    Ok(())
+}
+
+pub fn current_branch(dir: &Path) -> anyhow::Result<String> {
+    run(dir, &["symbolic-ref", "--short", "HEAD"])
+}
+
+pub fn set_upstream(dir: &Path, remote: &str) -> anyhow::Result<()> {
+    let branch = current_branch(dir)?;
+    run(
+        dir,
+        &["config", &format!("branch.{}.remote", branch), remote],
+    )?;
+    run(
+        dir,
+        &[
+            "config",
+            &format!("branch.{}.merge", branch),
+            &format!("refs/heads/{}", branch),
+        ],
+    )?;
+    println!("Set upstream of {} to {}/{}", branch, remote, branch);
+    Ok(())
+}
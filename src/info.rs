@@ -0,0 +1,34 @@
+use crate::record::{template_hash, ProjectRecord};
+use std::path::Path;
+
+pub fn info(project_dir: &Path, templates_root: &Path) -> anyhow::Result<()> {
+    let record = ProjectRecord::load(project_dir)?;
+    let template_dir = record.template_dir(templates_root);
+
+    println!("Language:     {:?}", record.lang);
+    println!("Kind:         {:?}", record.kind);
+    println!(
+        "Template set: {} ({})",
+        record.template_set,
+        template_dir.display()
+    );
+    match &record.template_commit {
+        Some(commit) => println!("Templates:    {} (commit {})", record.template_hash, commit),
+        None => println!("Templates:    {}", record.template_hash),
+    }
+    println!("Generated by: gen {}", record.gen_version);
+
+    if !template_dir.is_dir() {
+        println!("Status:       template set no longer exists");
+    } else if template_hash(&template_dir)? == record.template_hash {
+        println!("Status:       up to date");
+    } else {
+        println!("Status:       newer templates available, run gen upgrade to see the changes");
+    }
+
+    println!("Variables:");
+    for (name, value) in &record.variables {
+        println!("  {} = {}", name, value);
+    }
+    Ok(())
+}
@@ -0,0 +1,117 @@
+use crate::component::{detect_lang, go_module_path, java_package};
+use crate::project::{Lang, ProjectKind};
+use crate::record::{ProjectRecord, RECORD_FILE};
+use anyhow::anyhow;
+use std::fs;
+use std::path::Path;
+
+#[derive(Debug, Clone)]
+pub struct Inspection {
+    pub lang: Lang,
+    pub kind: ProjectKind,
+    pub domain: Option<String>,
+}
+
+pub fn inspect(project_dir: &Path) -> anyhow::Result<Inspection> {
+    if project_dir.join(RECORD_FILE).is_file() {
+        let record = ProjectRecord::load(project_dir)?;
+        return Ok(Inspection {
+            lang: record.lang,
+            kind: record.kind,
+            domain: record
+                .variables
+                .get("domain")
+                .and_then(|domain| domain.as_str())
+                .map(String::from),
+        });
+    }
+
+    let lang = match detect_lang(project_dir) {
+        Some(lang) => lang,
+        None => {
+            return Err(anyhow!(
+                "Could not detect the language of the project in {}",
+                project_dir.display()
+            ))
+        }
+    };
+
+    let executable = match lang {
+        Lang::Rust => project_dir.join("src").join("main.rs").is_file(),
+        Lang::C => project_dir.join("src").join("main.c").is_file(),
+        Lang::Cpp => project_dir.join("src").join("main.cpp").is_file(),
+        Lang::Go => fs::read_to_string(project_dir.join("main.go"))
+            .map(|source| source.contains("package main"))
+            .unwrap_or(false),
+        Lang::Java => fs::read_to_string(project_dir.join("manifest.txt"))
+            .map(|manifest| manifest.contains("Main-Class"))
+            .unwrap_or(false),
+        Lang::Asm => project_dir.join("src").join("main.asm").is_file(),
+        Lang::Shell => project_dir.join("bin").is_dir(),
+        Lang::Python => fs::read_dir(project_dir.join("src"))
+            .map(|entries| {
+                entries
+                    .filter_map(Result::ok)
+                    .any(|entry| entry.path().join("__main__.py").is_file())
+            })
+            .unwrap_or(false),
+        Lang::Elixir => fs::read_to_string(project_dir.join("mix.exs"))
+            .map(|mix| mix.contains("escript:"))
+            .unwrap_or(false),
+        Lang::Ruby => project_dir.join("exe").is_dir(),
+        Lang::Scala => fs::read_to_string(project_dir.join("build.sbt"))
+            .map(|build| build.contains("mainClass"))
+            .unwrap_or(false),
+        Lang::Lua | Lang::Php => project_dir.join("bin").is_dir(),
+        Lang::Latex => true,
+        Lang::Terraform | Lang::Custom(_) => false,
+    };
+
+    let domain = match lang {
+        Lang::Go => go_module_path(project_dir).and_then(|module| {
+            module
+                .rsplit_once('/')
+                .map(|(domain, _)| domain.to_string())
+        }),
+        Lang::Java => java_package(project_dir).and_then(|package| {
+            package
+                .rsplit_once('.')
+                .map(|(domain, _)| domain.to_string())
+        }),
+        Lang::Scala => sbt_organization(project_dir),
+        Lang::Php => composer_vendor(project_dir),
+        _ => None,
+    };
+
+    Ok(Inspection {
+        lang,
+        kind: if lang == Lang::Lua && project_dir.join("conf.lua").is_file() {
+            ProjectKind::Love
+        } else if lang == Lang::Rust && project_dir.join("cbindgen.toml").is_file() {
+            ProjectKind::Ffi
+        } else if executable {
+            ProjectKind::Executable
+        } else {
+            ProjectKind::Library
+        },
+        domain,
+    })
+}
+
+fn sbt_organization(project_dir: &Path) -> Option<String> {
+    let build = fs::read_to_string(project_dir.join("build.sbt")).ok()?;
+    build.lines().find_map(|line| {
+        line.trim()
+            .strip_prefix("ThisBuild / organization :=")
+            .map(|value| value.trim().trim_matches('"').to_string())
+    })
+}
+
+fn composer_vendor(project_dir: &Path) -> Option<String> {
+    let composer = fs::read_to_string(project_dir.join("composer.json")).ok()?;
+    let composer: serde_json::Value = serde_json::from_str(&composer).ok()?;
+    composer["name"]
+        .as_str()?
+        .split_once('/')
+        .map(|(vendor, _)| vendor.to_string())
+}
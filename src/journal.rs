@@ -0,0 +1,122 @@
+use crate::config::config_dir;
+use anyhow::anyhow;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::{self, BufRead, IsTerminal, Write};
+use std::path::{Path, PathBuf};
+
+const JOURNAL_FILE: &str = "journal.toml";
+const MAX_RUNS: usize = 20;
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct Journal {
+    pub runs: Vec<Run>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct Run {
+    pub created: Vec<PathBuf>,
+    pub side_effects: Vec<String>,
+}
+
+impl Journal {
+    pub fn path() -> PathBuf {
+        config_dir().join(JOURNAL_FILE)
+    }
+
+    pub fn load() -> anyhow::Result<Journal> {
+        let journal_path = Journal::path();
+        if !journal_path.is_file() {
+            return Ok(Journal::default());
+        }
+
+        match toml::from_str(&fs::read_to_string(&journal_path)?) {
+            Ok(journal) => Ok(journal),
+            Err(error) => Err(anyhow!(
+                "Could not parse {}: {}",
+                journal_path.display(),
+                error
+            )),
+        }
+    }
+
+    pub fn save(&self) -> anyhow::Result<()> {
+        let journal_path = Journal::path();
+        if let Some(parent) = journal_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(journal_path, toml::to_string(self)?)?;
+        Ok(())
+    }
+}
+
+pub fn begin_run(project_dir: &Path) -> anyhow::Result<()> {
+    let project_dir = if project_dir.is_absolute() {
+        project_dir.to_path_buf()
+    } else {
+        std::env::current_dir()?.join(project_dir)
+    };
+
+    let mut journal = Journal::load()?;
+    journal.runs.push(Run {
+        created: vec![project_dir],
+        side_effects: Vec::new(),
+    });
+    if journal.runs.len() > MAX_RUNS {
+        journal.runs.remove(0);
+    }
+    journal.save()
+}
+
+pub fn record_side_effect(side_effect: &str) -> anyhow::Result<()> {
+    let mut journal = Journal::load()?;
+    if let Some(run) = journal.runs.last_mut() {
+        run.side_effects.push(side_effect.to_string());
+    }
+    journal.save()
+}
+
+pub fn undo(yes: bool) -> anyhow::Result<()> {
+    let mut journal = Journal::load()?;
+    let run = match journal.runs.last() {
+        Some(run) => run.clone(),
+        None => return Err(anyhow!("Nothing to undo")),
+    };
+
+    let existing: Vec<&PathBuf> = run.created.iter().filter(|path| path.exists()).collect();
+    for path in &existing {
+        println!("Will remove {}", path.display());
+    }
+
+    if !existing.is_empty() && !yes {
+        if !io::stdin().is_terminal() {
+            return Err(anyhow!("Refusing to remove files without --yes"));
+        }
+
+        print!("Remove these paths? [y/N] ");
+        io::stdout().flush()?;
+        let mut answer = String::new();
+        io::stdin().lock().read_line(&mut answer)?;
+        if !matches!(answer.trim(), "y" | "Y" | "yes") {
+            println!("Aborted");
+            return Ok(());
+        }
+    }
+
+    for path in existing {
+        if path.is_dir() {
+            fs::remove_dir_all(path)?;
+        } else {
+            fs::remove_file(path)?;
+        }
+        println!("Removed {}", path.display());
+    }
+    for side_effect in &run.side_effects {
+        println!("Cannot undo automatically: {}", side_effect);
+    }
+
+    journal.runs.pop();
+    journal.save()
+}
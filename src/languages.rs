@@ -0,0 +1,164 @@
+use crate::config::{config_dir, merge_tables, read_table};
+use crate::generator::{self, LanguageGenerator};
+use crate::plugin;
+use crate::project::{Lang, Project};
+use anyhow::anyhow;
+use handlebars::Handlebars;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::Path;
+use std::process::Command;
+use std::sync::OnceLock;
+
+const SHIPPED_LANGUAGES: &str = include_str!("../templates/languages.toml");
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LanguageDefinition {
+    pub aliases: Vec<String>,
+    pub template_dir: Option<String>,
+    pub tools: Vec<String>,
+    pub init: Vec<String>,
+    pub dirs: Vec<String>,
+    pub files: Vec<String>,
+    pub comment: Option<String>,
+    pub extensions: Vec<String>,
+}
+
+pub fn definitions() -> &'static HashMap<String, LanguageDefinition> {
+    static DEFINITIONS: OnceLock<HashMap<String, LanguageDefinition>> = OnceLock::new();
+    DEFINITIONS.get_or_init(|| match load() {
+        Ok(definitions) => definitions,
+        Err(error) => {
+            println!("{}", error);
+            toml::from_str(SHIPPED_LANGUAGES).unwrap_or_default()
+        }
+    })
+}
+
+fn load() -> anyhow::Result<HashMap<String, LanguageDefinition>> {
+    let mut languages: toml::Table = toml::from_str(SHIPPED_LANGUAGES)?;
+    for path in [
+        config_dir().join("templates").join("languages.toml"),
+        config_dir().join("languages.toml"),
+    ] {
+        merge_tables(&mut languages, read_table(&path)?);
+    }
+
+    match toml::Value::Table(languages).try_into() {
+        Ok(definitions) => Ok(definitions),
+        Err(error) => Err(anyhow!("Invalid language definition: {}", error)),
+    }
+}
+
+pub fn definition(name: &str) -> Option<&'static LanguageDefinition> {
+    definitions().get(name)
+}
+
+pub fn resolve(name: &str) -> Option<&'static str> {
+    definitions()
+        .iter()
+        .find(|(language, definition)| {
+            language.as_str() == name || definition.aliases.iter().any(|alias| alias == name)
+        })
+        .map(|(language, _)| language.as_str())
+}
+
+pub fn installed() -> Vec<String> {
+    let mut languages: Vec<String> = definitions()
+        .iter()
+        .flat_map(|(name, definition)| std::iter::once(name).chain(&definition.aliases))
+        .cloned()
+        .chain(plugin::installed())
+        .collect();
+    languages.sort();
+    languages.dedup();
+    languages
+}
+
+pub fn register(name: &str) -> bool {
+    match definition(name) {
+        Some(definition) => {
+            generator::register(name, DefinedGenerator { definition });
+            true
+        }
+        None => false,
+    }
+}
+
+// Tools in provided were given to the project by path, so they need not be on PATH
+pub fn check_tools(lang: Lang, provided: &[&str]) -> anyhow::Result<()> {
+    let tools = match definition(lang.name()) {
+        Some(definition) => &definition.tools,
+        None => return Ok(()),
+    };
+    let path = std::env::var_os("PATH").unwrap_or_default();
+    for tool in tools
+        .iter()
+        .filter(|tool| !provided.contains(&tool.as_str()))
+    {
+        if !std::env::split_paths(&path).any(|dir| dir.join(tool).is_file()) {
+            return Err(anyhow!(
+                "{} is required to generate {} projects but was not found on PATH",
+                tool,
+                lang.name()
+            ));
+        }
+    }
+    Ok(())
+}
+
+struct DefinedGenerator {
+    definition: &'static LanguageDefinition,
+}
+
+impl LanguageGenerator for DefinedGenerator {
+    fn prepare(&self, project: &Project) -> anyhow::Result<()> {
+        let project_dir = match project.project_dir() {
+            Some(project_dir) => project_dir,
+            None => return Err(anyhow!("Project directory not set")),
+        };
+        project.sink().create_dir_all(project_dir)?;
+        project.sink().created_dir(project_dir);
+        for dir in &self.definition.dirs {
+            project.sink().create_dir_all(&project_dir.join(dir))?;
+            project.sink().created_dir(&project_dir.join(dir));
+        }
+        Ok(())
+    }
+
+    fn scaffold(&self, project: &Project) -> anyhow::Result<()> {
+        let project_dir = match project.project_dir() {
+            Some(project_dir) => project_dir,
+            None => return Err(anyhow!("Project directory not set")),
+        };
+
+        let init = self
+            .definition
+            .init
+            .split_first()
+            .filter(|_| !project.sink().is_memory());
+        if let Some((program, args)) = init {
+            let mut handlebars = Handlebars::new();
+            handlebars.register_escape_fn(handlebars::no_escape);
+            let args = args
+                .iter()
+                .map(|arg| handlebars.render_template(arg, project))
+                .collect::<Result<Vec<String>, _>>()?;
+            let status = Command::new(program)
+                .args(&args)
+                .current_dir(project_dir)
+                .status()?;
+            if !status.success() {
+                return Err(anyhow!("`{} {}` failed", program, args.join(" ")));
+            }
+        }
+
+        for file in &self.definition.files {
+            if project.template_source(Path::new(file)).is_some() {
+                project.render_template_file(Path::new(file), Path::new(file))?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -1,3 +1,24 @@
+pub mod complete;
+pub mod component;
+pub mod config;
+pub mod diff;
+pub mod forge;
+pub mod generator;
+pub mod git;
+pub mod info;
+pub mod inspect;
+pub mod journal;
+pub mod languages;
+pub mod manifest;
+pub mod name;
+pub mod plugin;
 pub mod project;
+pub mod record;
+pub mod template;
+pub mod upgrade;
+pub mod vcs;
+pub mod walk;
 
+pub use config::*;
+pub use name::*;
 pub use project::*;
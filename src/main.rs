@@ -1,35 +1,709 @@
-use clap::Parser;
-use gen::project::{Lang, Project, ProjectKind};
+use anyhow::anyhow;
+use clap::{Args, CommandFactory, Parser, Subcommand};
+use clap_complete::Shell;
+use gen::complete;
+use gen::component::Component;
+use gen::config::{expand_tilde, Config};
+use gen::diff;
+use gen::forge;
+use gen::git;
+use gen::info::info;
+use gen::inspect::inspect;
+use gen::journal;
+use gen::languages;
+use gen::manifest::TemplateManifest;
+use gen::project::{
+    AsyncRuntime, CiProvider, Database, ErrorStyle, HookPolicy, HooksKind, K8s, Lang, Member,
+    Packaging, Project, ProjectBuilder, ProjectKind, PublishOptions, TaskRunner,
+};
+use gen::record::ProjectRecord;
+use gen::template;
+use gen::upgrade::upgrade;
+use gen::vcs::VcsKind;
+use serde::Deserialize;
+use std::fs;
+use std::io::{self, BufRead, IsTerminal, Write};
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
 #[derive(Parser)]
-struct Args {
-    #[arg(index = 1)]
-    lang: String,
+#[command(
+    name = "gen",
+    version,
+    about = "Generate project directory structure and boilerplate"
+)]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Commands>,
+    #[command(flatten)]
+    generate: GenerateArgs,
+}
+
+#[derive(Subcommand)]
+enum Commands {
+    Add {
+        component: String,
+        name: String,
+        kind: Option<String>,
+        #[arg(short = 'C', long, default_value = ".")]
+        project: PathBuf,
+    },
+    Batch {
+        spec: PathBuf,
+    },
+    Diff {
+        #[arg(short = 'C', long, default_value = ".")]
+        project: PathBuf,
+    },
+    Template {
+        #[command(subcommand)]
+        command: TemplateCommands,
+    },
+    Completions {
+        shell: Shell,
+        #[arg(long)]
+        dynamic: bool,
+    },
+    #[command(name = "__complete", hide = true)]
+    Complete {
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        words: Vec<String>,
+    },
+    Info {
+        #[arg(default_value = ".")]
+        project: PathBuf,
+    },
+    List {
+        #[command(subcommand)]
+        command: ListCommands,
+    },
+    Manpage {
+        #[arg(long, default_value = ".")]
+        dir: PathBuf,
+    },
+    Openapi {
+        #[arg(short = 'C', long, default_value = ".")]
+        project: PathBuf,
+    },
+    Undo {
+        #[arg(short, long)]
+        yes: bool,
+    },
+    Upgrade {
+        #[arg(short = 'C', long, default_value = ".")]
+        project: PathBuf,
+        #[arg(long)]
+        apply: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum TemplateCommands {
+    New {
+        name: String,
+        #[arg(long)]
+        ext: Option<String>,
+    },
+    Check {
+        name: String,
+    },
+    Test {
+        name: String,
+    },
+    FromDir {
+        dir: PathBuf,
+        set: String,
+        #[arg(long)]
+        name: Option<String>,
+        #[arg(short, long)]
+        domain: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+enum ListCommands {
+    Kinds {
+        lang: String,
+        #[arg(long)]
+        task_runner: Option<String>,
+    },
+}
+
+#[derive(Args, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct GenerateArgs {
+    #[arg(index = 1, required = true)]
+    lang: Option<String>,
     #[arg(index = 2)]
-    name: String,
+    name: Option<String>,
     #[arg(index = 3)]
     kind: Option<String>,
+    #[arg(long)]
+    like: Option<PathBuf>,
+    #[arg(long)]
+    profile: Option<String>,
+    #[arg(long)]
+    template: Option<String>,
     #[arg(short, long)]
     domain: Option<String>,
+    #[arg(long)]
+    no_input: bool,
+    #[arg(long)]
+    module: Option<String>,
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+    #[arg(long)]
+    dir: Option<PathBuf>,
+    #[arg(long = "member")]
+    members: Vec<String>,
+    #[arg(long)]
+    package: Option<String>,
+    #[arg(long)]
+    bin_name: Option<String>,
+    #[arg(long)]
+    description: Option<String>,
+    #[arg(long)]
+    author: Option<String>,
+    #[arg(long)]
+    repository: Option<String>,
+    #[arg(long)]
+    license: Option<String>,
+    #[arg(long)]
+    license_header: bool,
+    #[arg(long)]
+    ci: Option<String>,
+    #[arg(long)]
+    task_runner: Option<String>,
+    #[arg(long)]
+    docker: bool,
+    #[arg(long)]
+    compose: bool,
+    #[arg(long, value_delimiter = ',')]
+    with: Vec<String>,
+    #[arg(long)]
+    packaging: Vec<String>,
+    #[arg(long, num_args = 0..=1, default_missing_value = "manifests")]
+    k8s: Option<String>,
+    #[arg(long)]
+    registry: Option<String>,
+    #[arg(long)]
+    target: Option<String>,
+    #[arg(long)]
+    devcontainer: bool,
+    #[arg(long)]
+    nix: bool,
+    #[arg(long)]
+    hooks: Option<String>,
+    #[arg(long)]
+    community: bool,
+    #[arg(long)]
+    changelog: bool,
+    #[arg(long)]
+    benches: bool,
+    #[arg(long)]
+    fuzz: bool,
+    #[arg(long)]
+    proptest: bool,
+    #[arg(long)]
+    edition: Option<String>,
+    #[arg(long)]
+    msrv: Option<String>,
+    #[arg(long, value_delimiter = ',')]
+    features: Vec<String>,
+    #[arg(long, value_delimiter = ',')]
+    deps: Vec<String>,
+    #[arg(long = "async")]
+    #[serde(rename = "async")]
+    async_runtime: Option<String>,
+    #[arg(long)]
+    errors: Option<String>,
+    #[arg(long)]
+    logging: bool,
+    #[arg(long)]
+    config_loader: bool,
+    #[arg(long)]
+    db: Option<String>,
+    #[arg(long)]
+    spec: Option<PathBuf>,
+    #[arg(long, conflicts_with = "vcs")]
+    git: bool,
+    #[arg(long)]
+    vcs: Option<String>,
+    #[arg(long)]
+    verify: bool,
+    #[arg(long)]
+    diff: bool,
+    #[arg(long)]
+    post_generate: Option<String>,
+    #[arg(long)]
+    open: bool,
+    #[arg(long, conflicts_with = "remote")]
+    publish: bool,
+    #[arg(long)]
+    remote: Option<String>,
+    #[arg(long, requires = "remote")]
+    push: bool,
+    #[arg(long)]
+    forge: Option<String>,
+    #[arg(long)]
+    owner: Option<String>,
+    #[arg(long, conflicts_with = "private")]
+    public: bool,
+    #[arg(long)]
+    private: bool,
 }
 
 fn main() -> anyhow::Result<()> {
-    let args = Args::parse();
-    let name = Box::leak(args.name.into_boxed_str());
-    let kind = match args.kind {
-        Some(kind) => ProjectKind::from_str(&kind)?,
-        None => ProjectKind::Executable,
+    let cli = Cli::parse();
+    match cli.command {
+        Some(Commands::Add {
+            component,
+            name,
+            kind,
+            project,
+        }) => {
+            let mut component = Component::new(&component, &name, &expand_tilde(project))?;
+            if let Some(kind) = kind {
+                component.set_project_kind(ProjectKind::from_str(&kind)?);
+            }
+            component.generate()
+        }
+        Some(Commands::Batch { spec }) => batch(&expand_tilde(spec), &Config::load()?),
+        Some(Commands::Diff { project }) => upgrade(
+            &expand_tilde(project),
+            &Config::load()?.templates_root(),
+            false,
+        ),
+        Some(Commands::Template { command }) => match command {
+            TemplateCommands::New { name, ext } => {
+                let config = Config::load()?;
+                let ext = ext.as_deref().unwrap_or(&name);
+                let template_dir = template::new_template(&config.templates_root(), &name, ext)?;
+                println!("Created template set {}", template_dir.display());
+                Ok(())
+            }
+            TemplateCommands::Check { name } => {
+                let template_dir = Config::load()?.templates_root().join(&name);
+                let problems = template::check(&template_dir)?;
+                for problem in &problems {
+                    println!("{}", problem);
+                }
+                if problems.is_empty() {
+                    println!("No problems found in {}", template_dir.display());
+                    Ok(())
+                } else {
+                    Err(anyhow!(
+                        "Found {} problem(s) in {}",
+                        problems.len(),
+                        template_dir.display()
+                    ))
+                }
+            }
+            TemplateCommands::Test { name } => {
+                let template_dir = Config::load()?.templates_root().join(&name);
+                match template::test(&template_dir)? {
+                    0 => Ok(()),
+                    failed => Err(anyhow!("{} fixture(s) failed", failed)),
+                }
+            }
+            TemplateCommands::FromDir {
+                dir,
+                set,
+                name,
+                domain,
+            } => {
+                let template_dir = Config::load()?.templates_root().join(&set);
+                template::from_dir(
+                    &expand_tilde(dir),
+                    &template_dir,
+                    name.as_deref(),
+                    domain.as_deref(),
+                )?;
+                println!("Created template set {}", template_dir.display());
+                Ok(())
+            }
+        },
+        Some(Commands::Completions { shell, dynamic }) if dynamic => {
+            print!("{}", complete::script(shell)?);
+            Ok(())
+        }
+        Some(Commands::Completions { shell, .. }) => completions(shell, &Config::load()?),
+        Some(Commands::Complete { words }) => {
+            for candidate in complete::complete(Cli::command(), &words)? {
+                println!("{}", candidate);
+            }
+            Ok(())
+        }
+        Some(Commands::Info { project }) => {
+            info(&expand_tilde(project), &Config::load()?.templates_root())
+        }
+        Some(Commands::List { command }) => match command {
+            ListCommands::Kinds { lang, task_runner } => {
+                list_kinds(&lang, task_runner.as_deref(), &Config::load()?)
+            }
+        },
+        Some(Commands::Manpage { dir }) => manpage(&expand_tilde(dir)),
+        Some(Commands::Openapi { project }) => {
+            let project_dir = expand_tilde(project);
+            let record = ProjectRecord::load(&project_dir)?;
+            Project::from_record(&record, &Config::load()?.templates_root(), project_dir)?
+                .update_openapi_stubs()
+        }
+        Some(Commands::Undo { yes }) => journal::undo(yes),
+        Some(Commands::Upgrade { project, apply }) => upgrade(
+            &expand_tilde(project),
+            &Config::load()?.templates_root(),
+            apply,
+        ),
+        None => {
+            let config = Config::load_profile(cli.generate.profile.as_deref())?;
+            generate(cli.generate, &config)
+        }
+    }
+}
+
+fn completions(shell: Shell, config: &Config) -> anyhow::Result<()> {
+    let templates_root = config.templates_root();
+    let values = |values: Vec<String>| -> Vec<&'static str> {
+        values
+            .into_iter()
+            .map(|value| &*Box::leak(value.into_boxed_str()))
+            .collect()
+    };
+    let languages = values(languages::installed());
+    let kinds = values(template::kinds(&templates_root)?);
+    let sets = values(template::sets(&templates_root)?);
+
+    let mut command = Cli::command()
+        .mut_arg("lang", |arg| arg.value_parser(languages))
+        .mut_arg("kind", |arg| arg.value_parser(kinds))
+        .mut_arg("template", |arg| arg.value_parser(sets));
+    clap_complete::generate(shell, &mut command, "gen", &mut std::io::stdout());
+    Ok(())
+}
+
+fn prompt_domain(default: Option<String>) -> anyhow::Result<Option<String>> {
+    match &default {
+        Some(default) => print!("Domain [{}]: ", default),
+        None => print!("Domain: "),
+    }
+    io::stdout().flush()?;
+    let mut answer = String::new();
+    io::stdin().lock().read_line(&mut answer)?;
+    match answer.trim() {
+        "" => Ok(default),
+        answer => Ok(Some(answer.to_string())),
+    }
+}
+
+fn list_kinds(lang: &str, task_runner: Option<&str>, config: &Config) -> anyhow::Result<()> {
+    let template_dir = config
+        .templates_root()
+        .join(Lang::from_str(lang)?.template_subdir());
+    if !template_dir.is_dir() {
+        return Err(anyhow!(
+            "Template directory {} does not exist!",
+            template_dir.display()
+        ));
+    }
+
+    let task_runner = match task_runner.or(config.task_runner.as_deref()) {
+        Some(task_runner) => TaskRunner::from_str(task_runner)?,
+        None => TaskRunner::default(),
+    };
+    let manifest = TemplateManifest::load(&template_dir)?;
+    for kind in template::set_kinds(&template_dir, &manifest) {
+        let project_kind = ProjectKind::from_str(&kind)?;
+        println!("{} ({:?})", kind, project_kind);
+        for path in template::kind_tree(&template_dir, project_kind, task_runner)? {
+            println!("  {}", path.display());
+        }
+    }
+    Ok(())
+}
+
+fn manpage(dir: &Path) -> anyhow::Result<()> {
+    fs::create_dir_all(dir)?;
+    let mut command = Cli::command().disable_help_subcommand(true);
+    command.build();
+    write_manpages(command, dir)
+}
+
+fn write_manpages(command: clap::Command, dir: &Path) -> anyhow::Result<()> {
+    for subcommand in command.get_subcommands().cloned() {
+        write_manpages(subcommand, dir)?;
+    }
+    let path = clap_mangen::Man::new(command).generate_to(dir)?;
+    println!("Created file {}", path.display());
+    Ok(())
+}
+
+fn generate(mut args: GenerateArgs, config: &Config) -> anyhow::Result<()> {
+    let like = match &args.like {
+        Some(like) => Some(inspect(&expand_tilde(like))?),
+        None => None,
+    };
+    if args.name.is_none() && (like.is_some() || config.default_lang.is_some()) {
+        args.name = args.lang.take();
+    }
+
+    let name: &'static str = match args.name {
+        Some(name) => Box::leak(name.into_boxed_str()),
+        None => {
+            return Err(anyhow!(
+                "Missing project name! Use gen <LANG> <NAME>, or set default_lang in config.toml"
+            ))
+        }
+    };
+    let lang = match (args.lang, &like, &config.default_lang) {
+        (Some(lang), _, _) => Lang::from_str(&lang)?,
+        (None, Some(like), _) => like.lang,
+        (None, None, Some(lang)) => Lang::from_str(lang)?,
+        (None, None, None) => return Err(anyhow!("Missing project language!")),
     };
 
-    let lang = Lang::from_str(&args.lang)?;
-    if lang == Lang::Java && args.domain.is_none() {
-        println!("Java project requires domain name! Use --domain option.");
-        std::process::exit(1);
+    let kind = match (args.kind, &like, &config.default_kind) {
+        (Some(kind), _, _) => ProjectKind::from_str(&kind)?,
+        (None, Some(like), _) => like.kind,
+        (None, None, Some(kind)) => ProjectKind::from_str(kind)?,
+        (None, None, None) if lang == Lang::Terraform => ProjectKind::Library,
+        (None, None, None) => ProjectKind::Executable,
+    };
+    if args.domain.is_none() {
+        args.domain = like
+            .and_then(|like| like.domain)
+            .or_else(|| config.domain_for(&lang));
+        let prompt = match lang {
+            Lang::Java => true,
+            Lang::Go | Lang::Scala | Lang::Php => args.module.is_none(),
+            _ => false,
+        };
+        if prompt && !args.no_input && io::stdin().is_terminal() {
+            args.domain = prompt_domain(args.domain)?;
+        }
     }
+    let package_name = args.package.as_deref().unwrap_or(name);
 
-    let project = Project::new(name, lang, kind, args.domain);
+    let mut builder = ProjectBuilder::new(name)
+        .package_name(package_name)
+        .lang(lang)
+        .kind(kind);
+    if let Some(domain) = &args.domain {
+        builder = builder.domain(domain);
+    }
+    if let Some(module) = &args.module {
+        builder = builder.module(module);
+    }
+    builder = builder.config_templates(config, args.template.as_deref());
+    let mut project = builder.build()?;
+    for member in &args.members {
+        project.add_member(Member::parse(member, lang)?);
+    }
+    if let Some(bin_name) = &args.bin_name {
+        project.set_bin_name(bin_name);
+    }
+    if let Some(description) = &args.description {
+        project.set_description(description);
+    }
+    if let Some(author) = args
+        .author
+        .or_else(|| config.author.clone())
+        .or_else(git::default_author)
+    {
+        project.set_author(&author);
+    }
+    if let Some(repository) = &args.repository {
+        project.set_repository(repository);
+    }
+    if let Some(license) = args.license.as_ref().or(config.license.as_ref()) {
+        project.set_license(license);
+    }
+    project.set_license_header(args.license_header || config.license_header);
+    if let Some(ci) = args.ci.as_ref().or(config.ci.as_ref()) {
+        project.set_ci(CiProvider::from_str(ci)?);
+    }
+    if let Some(task_runner) = args.task_runner.as_ref().or(config.task_runner.as_ref()) {
+        project.set_task_runner(TaskRunner::from_str(task_runner)?);
+    }
+    if let Some(hooks) = &args.hooks {
+        project.set_hooks(HooksKind::from_str(hooks)?);
+    }
+    let compose = args.compose || !args.with.is_empty() || args.db.is_some();
+    project.set_docker(args.docker || compose);
+    project.set_compose(compose);
+    for service in &args.with {
+        project.add_compose_service(service)?;
+    }
+    if let Some(db) = &args.db {
+        let db = Database::from_str(db)?;
+        project.add_compose_service(db.compose_service())?;
+        project.set_db(db);
+    }
+    if let Some(spec) = &args.spec {
+        project.set_spec(expand_tilde(spec));
+    }
+    let packaging = if args.packaging.is_empty() {
+        &config.packaging
+    } else {
+        &args.packaging
+    };
+    for packaging in packaging {
+        project.add_packaging(Packaging::from_str(packaging)?);
+    }
+    if let Some(k8s) = args.k8s.as_ref().or(config.k8s.as_ref()) {
+        project.set_k8s(K8s::from_str(k8s)?);
+    }
+    if let Some(registry) = args.registry.as_ref().or(config.registry.as_ref()) {
+        project.set_registry(registry);
+    }
+    if let Some(target) = &args.target {
+        project.set_target(target);
+    }
+    project.set_devcontainer(args.devcontainer);
+    project.set_nix(args.nix);
+    project.set_community(args.community);
+    project.set_changelog(args.changelog);
+    project.set_benches(args.benches);
+    project.set_fuzz(args.fuzz);
+    project.set_proptest(args.proptest);
+    if let Some(edition) = &args.edition {
+        project.set_edition(edition);
+    }
+    if let Some(msrv) = &args.msrv {
+        project.set_msrv(msrv);
+    }
+    for feature in &args.features {
+        project.add_feature(feature);
+    }
+    for dep in &args.deps {
+        project.add_dep(dep);
+    }
+    if let Some(runtime) = &args.async_runtime {
+        project.set_async_runtime(AsyncRuntime::from_str(runtime)?);
+    }
+    project.set_logging(args.logging);
+    project.set_config_loader(args.config_loader);
+    if let Some(errors) = &args.errors {
+        project.set_error_style(ErrorStyle::from_str(errors)?);
+    } else if let Some(errors) = config
+        .errors
+        .as_ref()
+        .filter(|_| project.supports_error_style())
+    {
+        project.set_error_style(ErrorStyle::from_str(errors)?);
+    }
+    project.set_verify(args.verify);
+    if args.open {
+        match config
+            .editor
+            .clone()
+            .or_else(|| std::env::var("VISUAL").ok())
+            .or_else(|| std::env::var("EDITOR").ok())
+        {
+            Some(editor) => project.set_editor(&editor),
+            None => {
+                return Err(anyhow!(
+                    "--open requires $VISUAL, $EDITOR or editor in config.toml"
+                ))
+            }
+        }
+    }
+    project.set_hook_policy(match &args.post_generate {
+        Some(policy) => HookPolicy::from_str(policy)?,
+        None => config.post_generate,
+    });
+    match (args.git, &args.vcs, config.vcs) {
+        (true, _, _) => project.set_vcs(VcsKind::Git),
+        (false, Some(vcs), _) => project.set_vcs(VcsKind::from_str(vcs)?),
+        (false, None, Some(vcs)) => project.set_vcs(vcs),
+        (false, None, None) => {}
+    }
+    project.set_git_settings(config.git.clone());
+    if let Some(remote) = &args.remote {
+        project.set_remote(remote, args.push);
+    }
+    if args.publish {
+        let forge = args
+            .forge
+            .or_else(|| config.forge.clone())
+            .unwrap_or_else(|| String::from("github"));
+        let settings = config.forge(&forge);
+        forge::connect(&forge, &settings)?;
+        project.set_publish_options(PublishOptions {
+            owner: args.owner.or_else(|| settings.owner.clone()),
+            private: match (args.public, args.private) {
+                (true, _) => false,
+                (_, true) => true,
+                _ => settings.private.unwrap_or(true),
+            },
+            forge,
+            settings,
+        });
+    }
+    project.set_format(config.format.clone());
+    match (args.dir, args.output.or_else(|| config.projects_root())) {
+        (Some(dir), _) => project.set_project_dir(expand_tilde(dir)),
+        (None, Some(output)) => project.set_project_dir(expand_tilde(output).join(name)),
+        (None, None) => {}
+    }
+    if args.diff {
+        return diff::preview(&project);
+    }
     project.generate()?;
 
     Ok(())
 }
+
+#[derive(Deserialize)]
+struct BatchSpec {
+    #[serde(default)]
+    project: Vec<GenerateArgs>,
+}
+
+fn batch(spec_path: &Path, config: &Config) -> anyhow::Result<()> {
+    let spec: BatchSpec = match toml::from_str(&fs::read_to_string(spec_path)?) {
+        Ok(spec) => spec,
+        Err(error) => {
+            return Err(anyhow!(
+                "Could not parse {}: {}",
+                spec_path.display(),
+                error
+            ))
+        }
+    };
+    let spec_dir = spec_path.parent().unwrap_or(Path::new("."));
+
+    let mut failed = Vec::new();
+    let total = spec.project.len();
+    for mut args in spec.project {
+        let name = args.name.clone().unwrap_or_default();
+        args.dir = args.dir.map(|dir| spec_dir.join(expand_tilde(dir)));
+        args.output = match args.output {
+            Some(output) => Some(spec_dir.join(expand_tilde(output))),
+            None => Some(spec_dir.to_path_buf()),
+        };
+
+        println!("Generating {}", name);
+        let result = match &args.profile {
+            Some(profile) => {
+                Config::load_profile(Some(profile)).and_then(|config| generate(args, &config))
+            }
+            None => generate(args, config),
+        };
+        match result {
+            Ok(()) => println!("Generated {}", name),
+            Err(error) => {
+                println!("Failed to generate {}: {}", name, error);
+                failed.push(name);
+            }
+        }
+    }
+
+    println!("Generated {} of {} projects", total - failed.len(), total);
+    if failed.is_empty() {
+        Ok(())
+    } else {
+        Err(anyhow!("Failed to generate {}", failed.join(", ")))
+    }
+}
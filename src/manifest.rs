@@ -0,0 +1,119 @@
+use anyhow::anyhow;
+use glob::Pattern;
+use serde::Deserialize;
+use std::fs;
+use std::path::Path;
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct TemplateManifest {
+    pub kinds: Vec<String>,
+    pub variables: Vec<String>,
+    pub render_tree: bool,
+    pub snapshot: bool,
+    pub tree_entries: Vec<String>,
+    pub executable: Vec<String>,
+    pub symlinks: SymlinkPolicy,
+    pub raw: Vec<String>,
+    pub delimiters: Vec<DelimiterRule>,
+    pub post_generate: Vec<PostGenerateHook>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct DelimiterRule {
+    pub files: Vec<String>,
+    pub open: String,
+    pub close: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Syntax {
+    Handlebars,
+    Raw,
+    Delimiters { open: String, close: String },
+}
+
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone, Copy, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum SymlinkPolicy {
+    #[default]
+    Follow,
+    Preserve,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct PostGenerateHook {
+    pub run: String,
+    #[serde(default)]
+    pub working_dir: Option<String>,
+}
+
+impl TemplateManifest {
+    pub fn load(template_dir: &Path) -> anyhow::Result<TemplateManifest> {
+        let manifest_path = template_dir.join("template.toml");
+        if !manifest_path.is_file() {
+            return Ok(TemplateManifest::default());
+        }
+
+        let contents = fs::read_to_string(&manifest_path)?;
+        let manifest: TemplateManifest = match toml::from_str(&contents) {
+            Ok(manifest) => manifest,
+            Err(error) => {
+                return Err(anyhow!(
+                    "Could not parse {}: {}",
+                    manifest_path.display(),
+                    error
+                ))
+            }
+        };
+
+        let patterns = manifest
+            .raw
+            .iter()
+            .chain(manifest.delimiters.iter().flat_map(|rule| &rule.files));
+        for pattern in patterns {
+            if let Err(error) = Pattern::new(pattern) {
+                return Err(anyhow!(
+                    "Invalid file pattern `{}` in {}: {}",
+                    pattern,
+                    manifest_path.display(),
+                    error
+                ));
+            }
+        }
+        for rule in &manifest.delimiters {
+            if rule.open.is_empty() || rule.close.is_empty() {
+                return Err(anyhow!(
+                    "Empty delimiter in {}, set both open and close",
+                    manifest_path.display()
+                ));
+            }
+        }
+        Ok(manifest)
+    }
+
+    pub fn syntax(&self, relative_path: &Path) -> Syntax {
+        if matches_any(&self.raw, relative_path) {
+            return Syntax::Raw;
+        }
+        match self
+            .delimiters
+            .iter()
+            .find(|rule| matches_any(&rule.files, relative_path))
+        {
+            Some(rule) => Syntax::Delimiters {
+                open: rule.open.clone(),
+                close: rule.close.clone(),
+            },
+            None => Syntax::Handlebars,
+        }
+    }
+}
+
+fn matches_any(patterns: &[String], path: &Path) -> bool {
+    patterns.iter().any(|pattern| {
+        Pattern::new(pattern)
+            .map(|pattern| pattern.matches_path(path))
+            .unwrap_or(false)
+    })
+}
@@ -0,0 +1,634 @@
+use crate::project::Lang;
+use anyhow::anyhow;
+
+const UNSAFE_CHARS: &[char] = &['/', '\\', ':', '*', '?', '"', '<', '>', '|', '\0'];
+
+const WINDOWS_RESERVED: &[&str] = &[
+    "con", "prn", "aux", "nul", "com1", "com2", "com3", "com4", "com5", "com6", "com7", "com8",
+    "com9", "lpt1", "lpt2", "lpt3", "lpt4", "lpt5", "lpt6", "lpt7", "lpt8", "lpt9",
+];
+
+const RUST_RESERVED: &[&str] = &[
+    "as",
+    "async",
+    "await",
+    "break",
+    "const",
+    "continue",
+    "crate",
+    "dyn",
+    "else",
+    "enum",
+    "extern",
+    "false",
+    "fn",
+    "for",
+    "if",
+    "impl",
+    "in",
+    "let",
+    "loop",
+    "match",
+    "mod",
+    "move",
+    "mut",
+    "pub",
+    "ref",
+    "return",
+    "self",
+    "Self",
+    "static",
+    "struct",
+    "super",
+    "trait",
+    "true",
+    "type",
+    "unsafe",
+    "use",
+    "where",
+    "while",
+    "abstract",
+    "become",
+    "box",
+    "do",
+    "final",
+    "macro",
+    "override",
+    "priv",
+    "try",
+    "typeof",
+    "unsized",
+    "virtual",
+    "yield",
+    "test",
+    "core",
+    "std",
+    "alloc",
+    "proc_macro",
+];
+
+const JAVA_RESERVED: &[&str] = &[
+    "abstract",
+    "assert",
+    "boolean",
+    "break",
+    "byte",
+    "case",
+    "catch",
+    "char",
+    "class",
+    "const",
+    "continue",
+    "default",
+    "do",
+    "double",
+    "else",
+    "enum",
+    "extends",
+    "final",
+    "finally",
+    "float",
+    "for",
+    "goto",
+    "if",
+    "implements",
+    "import",
+    "instanceof",
+    "int",
+    "interface",
+    "long",
+    "native",
+    "new",
+    "package",
+    "private",
+    "protected",
+    "public",
+    "return",
+    "short",
+    "static",
+    "strictfp",
+    "super",
+    "switch",
+    "synchronized",
+    "this",
+    "throw",
+    "throws",
+    "transient",
+    "try",
+    "void",
+    "volatile",
+    "while",
+    "true",
+    "false",
+    "null",
+    "_",
+];
+
+const LUA_RESERVED: &[&str] = &[
+    "and", "break", "do", "else", "elseif", "end", "false", "for", "function", "goto", "if", "in",
+    "local", "nil", "not", "or", "repeat", "return", "then", "true", "until", "while",
+];
+
+const PYTHON_RESERVED: &[&str] = &[
+    "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class", "continue",
+    "def", "del", "elif", "else", "except", "finally", "for", "from", "global", "if", "import",
+    "in", "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return", "try", "while",
+    "with", "yield",
+];
+
+pub fn validate_name(name: &str, lang: &Lang) -> anyhow::Result<()> {
+    match check_name(name, lang) {
+        Some(problem) => Err(anyhow!(
+            "Invalid project name {}: {}. Try {} instead",
+            name,
+            problem,
+            sanitize_name(name, lang)
+        )),
+        None => Ok(()),
+    }
+}
+
+pub fn validate_dir_name(name: &str) -> anyhow::Result<()> {
+    match check_filesystem(name) {
+        Some(problem) => Err(anyhow!(
+            "Invalid directory name {}: {}. Try {} instead",
+            name,
+            problem,
+            sanitize_name(name, &Lang::Shell)
+        )),
+        None => Ok(()),
+    }
+}
+
+pub fn validate_module(module: &str, lang: &Lang) -> anyhow::Result<()> {
+    use Start::*;
+
+    let problem = check_filesystem(module).or_else(|| match lang {
+        Lang::Python => check_identifier(
+            module,
+            "Python module names",
+            NotDigit,
+            is_identifier_char,
+            PYTHON_RESERVED,
+        ),
+        Lang::Lua => check_identifier(
+            module,
+            "Lua module names",
+            NotDigit,
+            is_identifier_char,
+            LUA_RESERVED,
+        ),
+        _ => check_name(module, lang),
+    });
+
+    match problem {
+        Some(problem) => Err(anyhow!("Invalid module name {}: {}", module, problem)),
+        None => Ok(()),
+    }
+}
+
+// Component names become file, module and class names inside the project, so they must be
+// identifiers and can never name a path
+pub fn validate_component_name(name: &str, lang: &Lang) -> anyhow::Result<()> {
+    let reserved = match lang {
+        Lang::Rust => RUST_RESERVED,
+        Lang::Java => JAVA_RESERVED,
+        Lang::Python => PYTHON_RESERVED,
+        Lang::Lua => LUA_RESERVED,
+        _ => &[],
+    };
+    let problem = check_filesystem(name).or_else(|| {
+        check_identifier(
+            name,
+            "component names",
+            Start::Letter,
+            is_name_char,
+            reserved,
+        )
+    });
+
+    match problem {
+        Some(problem) => Err(anyhow!("Invalid component name {}: {}", name, problem)),
+        None => Ok(()),
+    }
+}
+
+pub fn validate_module_path(module: &str) -> anyhow::Result<()> {
+    let problem = module.split('/').find_map(|element| {
+        if element.is_empty() {
+            Some(String::from("module path elements cannot be empty"))
+        } else if element.starts_with('.') {
+            Some(String::from("module path elements cannot start with '.'"))
+        } else {
+            check_go(element)
+        }
+    });
+
+    match problem {
+        Some(problem) => Err(anyhow!("Invalid module path {}: {}", module, problem)),
+        None => Ok(()),
+    }
+}
+
+pub fn validate_package_path(package: &str) -> anyhow::Result<()> {
+    let problem = package.split('.').find_map(|element| {
+        if element.is_empty() {
+            Some(String::from("package path elements cannot be empty"))
+        } else if let Some(c) = element
+            .chars()
+            .find(|c| !(c.is_ascii_alphanumeric() || *c == '_'))
+        {
+            Some(format!("'{}' is not allowed in package path elements", c))
+        } else if element.starts_with(|c: char| c.is_ascii_digit()) {
+            Some(String::from(
+                "package path elements cannot start with a digit",
+            ))
+        } else {
+            None
+        }
+    });
+
+    match problem {
+        Some(problem) => Err(anyhow!("Invalid package path {}: {}", package, problem)),
+        None => Ok(()),
+    }
+}
+
+pub fn validate_namespace(namespace: &str) -> anyhow::Result<()> {
+    let problem = namespace.split('\\').find_map(|element| {
+        if element.is_empty() {
+            Some(String::from("namespace elements cannot be empty"))
+        } else if let Some(c) = element
+            .chars()
+            .find(|c| !(c.is_ascii_alphanumeric() || *c == '_'))
+        {
+            Some(format!("'{}' is not allowed in namespace elements", c))
+        } else if element.starts_with(|c: char| c.is_ascii_digit()) {
+            Some(String::from("namespace elements cannot start with a digit"))
+        } else {
+            None
+        }
+    });
+
+    match problem {
+        Some(problem) => Err(anyhow!("Invalid namespace {}: {}", namespace, problem)),
+        None => Ok(()),
+    }
+}
+
+pub fn dns_label(name: &str) -> String {
+    let label: String = name
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+        .collect();
+    label.trim_matches('-').to_string()
+}
+
+pub fn sanitize_name(name: &str, lang: &Lang) -> String {
+    let separator = match lang {
+        Lang::Java => '_',
+        _ => '-',
+    };
+
+    let mut sanitized = String::new();
+    for c in name.trim().chars() {
+        let valid = match lang {
+            Lang::Rust
+            | Lang::Python
+            | Lang::Elixir
+            | Lang::Ruby
+            | Lang::Scala
+            | Lang::Lua
+            | Lang::Php
+            | Lang::Terraform => is_name_char(c),
+            Lang::Go => c.is_ascii_alphanumeric() || "-._~".contains(c),
+            Lang::Java => is_java_char(c),
+            Lang::C | Lang::Cpp | Lang::Asm | Lang::Shell | Lang::Latex | Lang::Custom(_) => {
+                !c.is_whitespace() && !UNSAFE_CHARS.contains(&c)
+            }
+        };
+
+        if valid {
+            sanitized.push(c);
+        } else if !sanitized.ends_with(separator) {
+            sanitized.push(separator);
+        }
+    }
+
+    let mut sanitized = sanitized
+        .trim_matches(|c| c == separator || c == '.')
+        .to_string();
+    if *lang == Lang::Go {
+        sanitized = sanitized.to_lowercase();
+    }
+
+    if sanitized.is_empty() {
+        return String::from("project");
+    }
+
+    if check_name(&sanitized, lang).is_some() {
+        sanitized = format!("my{}{}", separator, sanitized);
+    }
+
+    sanitized
+}
+
+enum Start {
+    NotDigit,
+    Letter,
+}
+
+fn check_name(name: &str, lang: &Lang) -> Option<String> {
+    use Start::*;
+
+    check_filesystem(name).or_else(|| match lang {
+        Lang::Rust => check_identifier(name, "crate names", NotDigit, is_name_char, RUST_RESERVED),
+        Lang::Go => check_go(name),
+        Lang::Java => check_identifier(
+            name,
+            "Java identifiers",
+            NotDigit,
+            is_java_char,
+            JAVA_RESERVED,
+        ),
+        Lang::Python => check_identifier(
+            name,
+            "Python distribution names",
+            NotDigit,
+            is_name_char,
+            PYTHON_RESERVED,
+        ),
+        Lang::Lua => check_identifier(
+            name,
+            "LuaRocks package names",
+            NotDigit,
+            is_name_char,
+            LUA_RESERVED,
+        ),
+        Lang::Elixir => {
+            check_identifier(name, "Elixir application names", Letter, is_name_char, &[])
+        }
+        Lang::Ruby => check_identifier(name, "gem names", Letter, is_name_char, &[]),
+        Lang::Scala => check_identifier(name, "Scala package names", Letter, is_name_char, &[]),
+        Lang::Php => check_identifier(name, "Composer package names", Letter, is_name_char, &[]),
+        Lang::Terraform => {
+            check_identifier(name, "Terraform module names", Letter, is_name_char, &[])
+        }
+        Lang::C | Lang::Cpp | Lang::Asm | Lang::Shell | Lang::Latex | Lang::Custom(_) => None,
+    })
+}
+
+fn is_name_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '-' || c == '_'
+}
+
+fn is_identifier_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+fn is_java_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_' || c == '$'
+}
+
+fn check_filesystem(name: &str) -> Option<String> {
+    if name.trim().is_empty() {
+        return Some(String::from("name is empty"));
+    }
+    if name == "." || name == ".." {
+        return Some(String::from("name refers to a directory"));
+    }
+    if name.starts_with('.') {
+        return Some(String::from("name starts with '.'"));
+    }
+    if let Some(c) = name
+        .chars()
+        .find(|c| UNSAFE_CHARS.contains(c) || c.is_control())
+    {
+        return Some(format!(
+            "'{}' is not allowed in file names",
+            c.escape_default()
+        ));
+    }
+    if name.chars().any(char::is_whitespace) {
+        return Some(String::from("name contains whitespace"));
+    }
+    if WINDOWS_RESERVED.contains(&name.to_lowercase().as_str()) {
+        return Some(String::from("name is reserved on Windows"));
+    }
+    None
+}
+
+fn check_go(name: &str) -> Option<String> {
+    if let Some(c) = name
+        .chars()
+        .find(|c| !(c.is_ascii_alphanumeric() || "-._~".contains(*c)))
+    {
+        return Some(format!("'{}' is not allowed in Go module paths", c));
+    }
+    if name.ends_with('.') {
+        return Some(String::from("module path elements cannot end with '.'"));
+    }
+    if name.chars().any(|c| c.is_ascii_uppercase()) {
+        return Some(String::from("module paths should be lowercase"));
+    }
+    None
+}
+
+fn check_identifier(
+    name: &str,
+    kind: &str,
+    start: Start,
+    allowed: fn(char) -> bool,
+    reserved: &[&str],
+) -> Option<String> {
+    if let Some(c) = name.chars().find(|c| !allowed(*c)) {
+        return Some(format!("'{}' is not allowed in {}", c, kind));
+    }
+    match start {
+        Start::NotDigit if name.starts_with(|c: char| c.is_ascii_digit()) => {
+            return Some(format!("{} cannot start with a digit", kind));
+        }
+        Start::Letter if !name.starts_with(|c: char| c.is_ascii_alphabetic()) => {
+            return Some(format!("{} must start with a letter", kind));
+        }
+        _ => {}
+    }
+    if reserved.contains(&name.replace('-', "_").as_str()) {
+        return Some(format!("{} is a reserved word", name));
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        check_identifier, is_name_char, sanitize_name, validate_component_name, validate_dir_name,
+        validate_module, validate_module_path, validate_name, validate_namespace,
+        validate_package_path, Start,
+    };
+    use crate::project::Lang;
+
+    #[test]
+    fn replaces_invalid_characters() {
+        assert_eq!(sanitize_name("my cool app", &Lang::Rust), "my-cool-app");
+        assert_eq!(sanitize_name("my cool-app", &Lang::Java), "my_cool_app");
+        assert_eq!(sanitize_name("a/b: c", &Lang::C), "a-b-c");
+    }
+
+    #[test]
+    fn collapses_and_trims_separators() {
+        assert_eq!(sanitize_name("  --my!!app--  ", &Lang::Rust), "my-app");
+        assert_eq!(sanitize_name(".hidden.", &Lang::Shell), "hidden");
+    }
+
+    #[test]
+    fn lowercases_go_names() {
+        assert_eq!(sanitize_name("My App", &Lang::Go), "my-app");
+    }
+
+    #[test]
+    fn prefixes_reserved_names() {
+        assert_eq!(sanitize_name("fn", &Lang::Rust), "my-fn");
+        assert_eq!(sanitize_name("class", &Lang::Java), "my_class");
+    }
+
+    #[test]
+    fn falls_back_when_nothing_is_left() {
+        assert_eq!(sanitize_name("!!!", &Lang::Rust), "project");
+    }
+
+    #[test]
+    fn accepts_valid_names() {
+        assert!(validate_name("my-app", &Lang::Rust).is_ok());
+        assert!(validate_name("my-app.v2", &Lang::Go).is_ok());
+        assert!(validate_name("My$App", &Lang::Java).is_ok());
+        assert!(validate_name("my_app", &Lang::Python).is_ok());
+        assert!(validate_name("my_app", &Lang::Lua).is_ok());
+        assert!(validate_name("my_app", &Lang::Elixir).is_ok());
+        assert!(validate_name("my-gem", &Lang::Ruby).is_ok());
+        assert!(validate_name("myapp", &Lang::Scala).is_ok());
+        assert!(validate_name("my-package", &Lang::Php).is_ok());
+        assert!(validate_name("my-module", &Lang::Terraform).is_ok());
+        assert!(validate_name("my+app", &Lang::C).is_ok());
+    }
+
+    #[test]
+    fn rejects_reserved_words() {
+        assert!(validate_name("fn", &Lang::Rust).is_err());
+        assert!(validate_name("proc-macro", &Lang::Rust).is_err());
+        assert!(validate_name("class", &Lang::Java).is_err());
+        assert!(validate_name("lambda", &Lang::Python).is_err());
+        assert!(validate_name("local", &Lang::Lua).is_err());
+        assert!(validate_name("con", &Lang::C).is_err());
+        assert!(validate_name("LPT1", &Lang::Shell).is_err());
+    }
+
+    #[test]
+    fn rejects_leading_digits() {
+        assert!(validate_name("1app", &Lang::Rust).is_err());
+        assert!(validate_name("1app", &Lang::Java).is_err());
+        assert!(validate_name("1app", &Lang::Python).is_err());
+        assert!(validate_name("_app", &Lang::Ruby).is_err());
+        assert!(validate_name("1app", &Lang::Elixir).is_err());
+        assert!(validate_name("1app", &Lang::Go).is_ok());
+    }
+
+    #[test]
+    fn rejects_invalid_characters() {
+        assert!(validate_name("my.app", &Lang::Rust).is_err());
+        assert!(validate_name("my-app", &Lang::Java).is_err());
+        assert!(validate_name("My_App", &Lang::Go).is_err());
+        assert!(validate_name("my@app", &Lang::Go).is_err());
+        assert!(validate_name("my/app", &Lang::C).is_err());
+        assert!(validate_name("my app", &Lang::Shell).is_err());
+        assert!(validate_name(".app", &Lang::Shell).is_err());
+        assert!(validate_name("", &Lang::Rust).is_err());
+    }
+
+    #[test]
+    fn error_suggests_a_sanitized_name() {
+        let err = validate_name("my app", &Lang::Rust).unwrap_err();
+        assert!(err.to_string().contains("Try my-app instead"));
+    }
+
+    #[test]
+    fn validates_directory_names() {
+        assert!(validate_dir_name("my-app").is_ok());
+        assert!(validate_dir_name("../my-app").is_err());
+        assert!(validate_dir_name("..").is_err());
+        assert!(validate_dir_name("a/b").is_err());
+    }
+
+    #[test]
+    fn validates_module_names() {
+        assert!(validate_module("my_app", &Lang::Python).is_ok());
+        assert!(validate_module("my-app", &Lang::Python).is_err());
+        assert!(validate_module("1app", &Lang::Python).is_err());
+        assert!(validate_module("lambda", &Lang::Python).is_err());
+        assert!(validate_module("my_app", &Lang::Lua).is_ok());
+        assert!(validate_module("my-app", &Lang::Lua).is_err());
+        assert!(validate_module("my_app", &Lang::Elixir).is_ok());
+    }
+
+    #[test]
+    fn validates_component_names() {
+        assert!(validate_component_name("user_service", &Lang::Rust).is_ok());
+        assert!(validate_component_name("UserService", &Lang::Java).is_ok());
+        assert!(validate_component_name("../../escaped", &Lang::Rust).is_err());
+        assert!(validate_component_name("src/escaped", &Lang::Go).is_err());
+        assert!(validate_component_name("..", &Lang::C).is_err());
+        assert!(validate_component_name("", &Lang::Python).is_err());
+        assert!(validate_component_name("fn", &Lang::Rust).is_err());
+        assert!(validate_component_name("1thing", &Lang::Go).is_err());
+    }
+
+    #[test]
+    fn validates_module_paths() {
+        assert!(validate_module_path("github.com/user/my-app").is_ok());
+        assert!(validate_module_path("github.com//my-app").is_err());
+        assert!(validate_module_path("github.com/.hidden").is_err());
+        assert!(validate_module_path("github.com/user/app.").is_err());
+        assert!(validate_module_path("github.com/User/app").is_err());
+        assert!(validate_module_path("github.com/user/my app").is_err());
+    }
+
+    #[test]
+    fn validates_package_paths() {
+        assert!(validate_package_path("com.example.my_app").is_ok());
+        assert!(validate_package_path("com..example").is_err());
+        assert!(validate_package_path("com.example.my-app").is_err());
+        assert!(validate_package_path("com.1example").is_err());
+    }
+
+    #[test]
+    fn validates_namespaces() {
+        assert!(validate_namespace("Acme\\MyApp").is_ok());
+        assert!(validate_namespace("Acme\\\\MyApp").is_err());
+        assert!(validate_namespace("Acme\\My-App").is_err());
+        assert!(validate_namespace("Acme\\1App").is_err());
+    }
+
+    #[test]
+    fn check_identifier_reports_the_first_problem() {
+        let check = |name, start| check_identifier(name, "names", start, is_name_char, &["end"]);
+
+        assert_eq!(check("ok_name", Start::NotDigit), None);
+        assert_eq!(
+            check("a.b", Start::NotDigit).as_deref(),
+            Some("'.' is not allowed in names")
+        );
+        assert_eq!(
+            check("9lives", Start::NotDigit).as_deref(),
+            Some("names cannot start with a digit")
+        );
+        assert_eq!(
+            check("-name", Start::Letter).as_deref(),
+            Some("names must start with a letter")
+        );
+        assert_eq!(
+            check("end", Start::Letter).as_deref(),
+            Some("end is a reserved word")
+        );
+    }
+}
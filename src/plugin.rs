@@ -0,0 +1,146 @@
+use crate::config::config_dir;
+use crate::generator::{self, LanguageGenerator};
+use crate::project::{Project, Sink};
+use anyhow::anyhow;
+use serde::Deserialize;
+use std::fs;
+use std::io::Write;
+use std::path::{Component, Path, PathBuf};
+use std::process::{Command, Stdio};
+
+#[derive(Debug, Deserialize)]
+pub struct PluginManifest {
+    pub files: Vec<PluginFile>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PluginFile {
+    pub path: PathBuf,
+    pub contents: String,
+    #[serde(default)]
+    pub executable: bool,
+}
+
+pub struct PluginGenerator {
+    program: PathBuf,
+}
+
+pub fn find(lang: &str) -> Option<PathBuf> {
+    let program = format!("gen-{}", lang);
+    let plugins_dir = config_dir().join("plugins");
+    std::iter::once(plugins_dir)
+        .chain(
+            std::env::var_os("PATH")
+                .iter()
+                .flat_map(std::env::split_paths),
+        )
+        .map(|dir| dir.join(&program))
+        .find(|path| path.is_file())
+}
+
+pub fn installed() -> Vec<String> {
+    let plugins_dir = config_dir().join("plugins");
+    let mut plugins: Vec<String> = std::iter::once(plugins_dir)
+        .chain(
+            std::env::var_os("PATH")
+                .iter()
+                .flat_map(std::env::split_paths),
+        )
+        .filter_map(|dir| fs::read_dir(dir).ok())
+        .flatten()
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let file_name = entry.file_name().to_string_lossy().to_string();
+            file_name.strip_prefix("gen-").map(String::from)
+        })
+        .collect();
+    plugins.sort();
+    plugins.dedup();
+    plugins
+}
+
+pub fn register(lang: &str) -> bool {
+    match find(lang) {
+        Some(program) => {
+            generator::register(lang, PluginGenerator { program });
+            true
+        }
+        None => false,
+    }
+}
+
+impl LanguageGenerator for PluginGenerator {
+    fn prepare(&self, project: &Project) -> anyhow::Result<()> {
+        match project.project_dir() {
+            Some(project_dir) => {
+                project.sink().create_dir_all(project_dir)?;
+                project.sink().created_dir(project_dir);
+                Ok(())
+            }
+            None => Err(anyhow!("Project directory not set")),
+        }
+    }
+
+    fn scaffold(&self, project: &Project) -> anyhow::Result<()> {
+        let project_dir = match project.project_dir() {
+            Some(project_dir) => project_dir,
+            None => return Err(anyhow!("Project directory not set")),
+        };
+
+        let mut child = Command::new(&self.program)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin.write_all(serde_json::to_string(project)?.as_bytes())?;
+        }
+        let output = child.wait_with_output()?;
+        if !output.status.success() {
+            return Err(anyhow!(
+                "{} failed: {}",
+                self.program.display(),
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+
+        let manifest: PluginManifest = match serde_json::from_slice(&output.stdout) {
+            Ok(manifest) => manifest,
+            Err(error) => {
+                return Err(anyhow!(
+                    "{} returned an invalid manifest: {}",
+                    self.program.display(),
+                    error
+                ))
+            }
+        };
+        for file in manifest.files {
+            write_file(project.sink(), project_dir, &file)?;
+        }
+        Ok(())
+    }
+}
+
+fn write_file(sink: &Sink, project_dir: &Path, file: &PluginFile) -> anyhow::Result<()> {
+    let inside_project = file
+        .path
+        .components()
+        .all(|component| matches!(component, Component::Normal(_) | Component::CurDir));
+    if !inside_project {
+        return Err(anyhow!(
+            "Plugin file {} is outside the project directory",
+            file.path.display()
+        ));
+    }
+
+    let to_path = project_dir.join(&file.path);
+    if let Some(parent) = to_path.parent() {
+        sink.create_dir_all(parent)?;
+    }
+    sink.write(&to_path, &file.contents)?;
+    if file.executable {
+        sink.make_executable(&to_path)?;
+    }
+    sink.created(&to_path);
+    Ok(())
+}
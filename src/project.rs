@@ -1,15 +1,186 @@
+mod builder;
+mod changelog;
+mod ci;
+mod community;
+mod components;
+mod compose;
+mod config_loader;
+mod cross;
+mod db;
+mod deps;
+mod devcontainer;
+mod docker;
+mod docs;
+mod embedded;
+mod errors;
+mod features;
+mod ffi;
+mod format;
+mod grpc;
+mod hooks;
+mod k8s;
+mod latex;
+mod license_header;
+mod logging;
+mod metadata;
+mod monorepo;
+mod nix;
+mod open;
+mod openapi;
+mod packaging;
+mod post_generate;
+mod publish;
+mod record;
+mod render;
+mod runtime;
+mod schema;
+mod sink;
+mod systemd;
+mod task_runner;
+mod toolchain;
+mod tree;
+mod verify;
+
+pub use builder::ProjectBuilder;
+pub use ci::CiProvider;
+pub use db::Database;
+pub use errors::ErrorStyle;
+pub(crate) use grpc::grpc_component;
+pub(crate) use hooks::copy_executable_bits;
+pub use hooks::HooksKind;
+pub use k8s::K8s;
+pub use monorepo::Member;
+pub(crate) use openapi::openapi_files;
+pub use packaging::Packaging;
+pub use post_generate::HookPolicy;
+pub use publish::PublishOptions;
+pub use runtime::AsyncRuntime;
+pub use sink::Sink;
+pub(crate) use systemd::UNIT_FILES;
+pub use task_runner::TaskRunner;
+
+use crate::component::{pascal_case, php_namespace, snake_case};
+use crate::config::FormatConfig;
+use crate::generator;
+use crate::git::GitSettings;
+use crate::journal;
+use crate::languages;
+use crate::manifest::Syntax;
+use crate::name::validate_dir_name;
+use crate::plugin;
+use crate::template;
+use crate::vcs::VcsKind;
 use anyhow::anyhow;
-use handlebars::Handlebars;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use std::fs::{self, File};
 use std::path::{Path, PathBuf};
 use std::process::Command;
 use std::str::FromStr;
 
-#[derive(Debug, Serialize, Eq, PartialEq, Clone, Copy)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Copy)]
 pub enum ProjectKind {
     Library,
     Executable,
+    Daemon,
+    Grpc,
+    Openapi,
+    Sdl,
+    Qt,
+    Embedded,
+    Baremetal,
+    Kmod,
+    Wasm,
+    Datasci,
+    Love,
+    Ffi,
+    Schema,
+    Docs,
+    Monorepo,
+}
+
+// Every kind, in the order they are listed: its name on the command line, its name in messages,
+// the overlay directories its templates come from (most specific first), and whether it runs as a
+// program and has an entry point
+pub struct KindInfo {
+    pub kind: ProjectKind,
+    pub name: &'static str,
+    pub label: &'static str,
+    pub overlay_dirs: &'static [&'static str],
+    pub executable: bool,
+    pub entry_point: bool,
+}
+
+const fn kind_info(
+    kind: ProjectKind,
+    name: &'static str,
+    label: &'static str,
+    overlay_dirs: &'static [&'static str],
+    executable: bool,
+    entry_point: bool,
+) -> KindInfo {
+    KindInfo {
+        kind,
+        name,
+        label,
+        overlay_dirs,
+        executable,
+        entry_point,
+    }
+}
+
+#[rustfmt::skip]
+pub const KINDS: &[KindInfo] = &[
+    kind_info(ProjectKind::Executable, "bin", "Executable", &["bin"], true, true),
+    kind_info(ProjectKind::Library, "lib", "Library", &["lib"], false, false),
+    kind_info(ProjectKind::Daemon, "daemon", "Daemon", &["daemon", "bin"], true, true),
+    kind_info(ProjectKind::Grpc, "grpc", "gRPC", &["grpc", "bin"], true, true),
+    kind_info(ProjectKind::Openapi, "openapi", "OpenAPI", &["openapi", "bin"], true, true),
+    kind_info(ProjectKind::Sdl, "sdl", "SDL", &["sdl", "bin"], true, true),
+    kind_info(ProjectKind::Qt, "qt", "Qt", &["qt", "bin"], true, true),
+    kind_info(ProjectKind::Embedded, "embedded", "Embedded", &["embedded"], false, true),
+    kind_info(ProjectKind::Baremetal, "baremetal", "Bare-metal", &["baremetal"], false, true),
+    kind_info(ProjectKind::Kmod, "kmod", "Kernel module", &["kmod"], false, false),
+    kind_info(ProjectKind::Wasm, "wasm", "WebAssembly", &["wasm"], false, true),
+    kind_info(ProjectKind::Datasci, "datasci", "Data science", &["datasci"], false, false),
+    kind_info(ProjectKind::Love, "love", "LÖVE", &["love"], false, false),
+    kind_info(ProjectKind::Ffi, "ffi", "FFI", &["ffi"], false, false),
+    kind_info(ProjectKind::Schema, "schema", "Schema", &[], false, false),
+    kind_info(ProjectKind::Docs, "docs", "Docs", &[], false, false),
+    kind_info(ProjectKind::Monorepo, "monorepo", "Monorepo", &[], false, false),
+];
+
+impl ProjectKind {
+    pub fn info(&self) -> &'static KindInfo {
+        KINDS
+            .iter()
+            .find(|info| info.kind == *self)
+            .expect("every kind is listed in KINDS")
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.info().name
+    }
+
+    pub fn overlay_dirs(&self) -> &'static [&'static str] {
+        self.info().overlay_dirs
+    }
+
+    // The overlay a template set needs to support the kind. Every set supports executables and
+    // libraries, with or without bin and lib
+    pub fn required_overlay(&self) -> Option<&'static str> {
+        match self {
+            ProjectKind::Executable | ProjectKind::Library => None,
+            _ => self.overlay_dirs().first().copied(),
+        }
+    }
+
+    pub fn is_executable(&self) -> bool {
+        self.info().executable
+    }
+
+    pub fn has_entry_point(&self) -> bool {
+        self.info().entry_point
+    }
 }
 
 impl FromStr for ProjectKind {
@@ -19,98 +190,653 @@ impl FromStr for ProjectKind {
         match s {
             "bin" | "binary" | "exe" | "executable" => Ok(ProjectKind::Executable),
             "lib" | "library" => Ok(ProjectKind::Library),
+            "daemon" | "service" => Ok(ProjectKind::Daemon),
+            "grpc" => Ok(ProjectKind::Grpc),
+            "openapi" | "rest" => Ok(ProjectKind::Openapi),
+            "sdl" | "sdl2" | "game" => Ok(ProjectKind::Sdl),
+            "qt" | "gui" => Ok(ProjectKind::Qt),
+            "embedded" | "no_std" => Ok(ProjectKind::Embedded),
+            "baremetal" | "bare-metal" => Ok(ProjectKind::Baremetal),
+            "kmod" | "kernel-module" => Ok(ProjectKind::Kmod),
+            "wasm" | "emscripten" => Ok(ProjectKind::Wasm),
+            "datasci" | "data-science" | "notebook" => Ok(ProjectKind::Datasci),
+            "love" | "love2d" => Ok(ProjectKind::Love),
+            "ffi" | "cdylib" => Ok(ProjectKind::Ffi),
+            "schema" | "proto" | "buf" => Ok(ProjectKind::Schema),
+            "docs" | "book" | "mdbook" | "mkdocs" => Ok(ProjectKind::Docs),
+            "monorepo" | "mono" => Ok(ProjectKind::Monorepo),
             _ => Ok(ProjectKind::Executable),
         }
     }
 }
 
-#[derive(Debug, Hash, Eq, PartialEq, Clone, Copy, Serialize)]
+#[derive(Debug, Hash, Eq, PartialEq, Clone, Copy)]
 pub enum Lang {
     Rust,
     C,
     Cpp,
     Go,
     Java,
+    Asm,
+    Shell,
+    Python,
+    Elixir,
+    Ruby,
+    Scala,
+    Lua,
+    Php,
+    Terraform,
+    Latex,
+    Custom(&'static str),
+}
+
+impl Lang {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Lang::Rust => "rust",
+            Lang::C => "c",
+            Lang::Cpp => "cpp",
+            Lang::Go => "go",
+            Lang::Java => "java",
+            Lang::Asm => "asm",
+            Lang::Shell => "shell",
+            Lang::Python => "python",
+            Lang::Elixir => "elixir",
+            Lang::Ruby => "ruby",
+            Lang::Scala => "scala",
+            Lang::Lua => "lua",
+            Lang::Php => "php",
+            Lang::Terraform => "terraform",
+            Lang::Latex => "latex",
+            Lang::Custom(name) => name,
+        }
+    }
+
+    pub fn template_subdir(&self) -> String {
+        languages::definition(self.name())
+            .and_then(|definition| definition.template_dir.clone())
+            .unwrap_or_else(|| self.name().to_string())
+    }
+}
+
+impl Serialize for Lang {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Lang::Custom(name) => serializer.serialize_str(name),
+            _ => serializer.serialize_str(&format!("{:?}", self)),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Lang {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Lang, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        Ok(match name.as_str() {
+            "Rust" => Lang::Rust,
+            "C" => Lang::C,
+            "Cpp" => Lang::Cpp,
+            "Go" => Lang::Go,
+            "Java" => Lang::Java,
+            "Asm" => Lang::Asm,
+            "Shell" => Lang::Shell,
+            "Python" => Lang::Python,
+            "Elixir" => Lang::Elixir,
+            "Ruby" => Lang::Ruby,
+            "Scala" => Lang::Scala,
+            "Lua" => Lang::Lua,
+            "Php" => Lang::Php,
+            "Terraform" => Lang::Terraform,
+            "Latex" => Lang::Latex,
+            _ => Lang::Custom(Box::leak(name.into_boxed_str())),
+        })
+    }
 }
 
 impl FromStr for Lang {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "rust" | "rs" => Ok(Lang::Rust),
-            "c" => Ok(Lang::C),
-            "cpp" | "c++" | "cc" => Ok(Lang::Cpp),
-            "java" => Ok(Lang::Java),
-            "go" => Ok(Lang::Go),
-            _ => Err(anyhow!("Unknown language {}", s)),
+        match languages::resolve(s) {
+            Some("rust") => Ok(Lang::Rust),
+            Some("c") => Ok(Lang::C),
+            Some("cpp") => Ok(Lang::Cpp),
+            Some("java") => Ok(Lang::Java),
+            Some("go") => Ok(Lang::Go),
+            Some("asm") => Ok(Lang::Asm),
+            Some("shell") => Ok(Lang::Shell),
+            Some("python") => Ok(Lang::Python),
+            Some("elixir") => Ok(Lang::Elixir),
+            Some("ruby") => Ok(Lang::Ruby),
+            Some("scala") => Ok(Lang::Scala),
+            Some("lua") => Ok(Lang::Lua),
+            Some("php") => Ok(Lang::Php),
+            Some("terraform") => Ok(Lang::Terraform),
+            Some("latex") => Ok(Lang::Latex),
+            Some(name) => Ok(Lang::Custom(name)),
+            None if generator::is_registered(s) || plugin::register(s) => {
+                Ok(Lang::Custom(Box::leak(s.into())))
+            }
+            None => Err(anyhow!("Unknown language {}", s)),
         }
     }
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Project {
     name: String,
+    package_name: String,
+    bin_name: String,
     lang: Lang,
     kind: ProjectKind,
     project_dir: Option<PathBuf>,
     template_dir: Option<PathBuf>,
     domain: Option<String>,
+    module: Option<String>,
+    description: Option<String>,
+    author: Option<String>,
+    repository: Option<String>,
+    license: Option<String>,
+    license_header: bool,
+    task_runner: TaskRunner,
+    ci: Option<CiProvider>,
+    docker: bool,
+    compose: bool,
+    compose_services: Vec<String>,
+    packaging: Vec<Packaging>,
+    k8s: Option<K8s>,
+    registry: Option<String>,
+    target: Option<String>,
+    devcontainer: bool,
+    nix: bool,
+    format: FormatConfig,
+    hooks: Option<HooksKind>,
+    community: bool,
+    changelog: bool,
+    benches: bool,
+    fuzz: bool,
+    proptest: bool,
+    edition: Option<String>,
+    msrv: Option<String>,
+    features: Vec<String>,
+    deps: Vec<String>,
+    async_runtime: Option<AsyncRuntime>,
+    error_style: Option<ErrorStyle>,
+    logging: bool,
+    config_loader: bool,
+    db: Option<Database>,
+    spec: Option<String>,
+    spec_path: Option<PathBuf>,
+    vcs: Option<VcsKind>,
+    git_settings: GitSettings,
+    publish: Option<PublishOptions>,
+    remote: Option<String>,
+    push: bool,
+    verify: bool,
+    hook_policy: HookPolicy,
+    editor: Option<String>,
+    members: Vec<Member>,
+    #[serde(skip)]
+    clang_format: Option<PathBuf>,
+    #[serde(skip)]
+    sink: Sink,
 }
 
 impl Project {
-    pub fn new(
-        name: &'static str,
-        lang: Lang,
-        kind: ProjectKind,
-        domain: Option<String>,
-    ) -> Project {
-        let mut project = Project {
+    fn with_defaults(name: &str, lang: Lang, kind: ProjectKind, domain: Option<String>) -> Project {
+        Project {
             name: String::from(name),
+            package_name: String::from(name),
+            bin_name: String::from(name),
             lang,
             kind,
             project_dir: None,
             template_dir: None,
+            module: match lang {
+                Lang::Python | Lang::Elixir | Lang::Ruby | Lang::Lua => Some(snake_case(name)),
+                Lang::Scala => domain
+                    .as_ref()
+                    .map(|domain| format!("{}.{}", domain, snake_case(name))),
+                Lang::Php => domain.as_deref().map(|domain| php_namespace(domain, name)),
+                _ => None,
+            },
             domain,
-        };
+            description: None,
+            author: None,
+            repository: None,
+            license: None,
+            license_header: false,
+            task_runner: TaskRunner::default(),
+            ci: None,
+            docker: false,
+            compose: false,
+            compose_services: Vec::new(),
+            packaging: Vec::new(),
+            k8s: None,
+            registry: None,
+            target: (kind == ProjectKind::Embedded).then(|| String::from(embedded::DEFAULT_TARGET)),
+            devcontainer: false,
+            nix: false,
+            format: FormatConfig::default(),
+            hooks: None,
+            community: false,
+            changelog: false,
+            benches: false,
+            fuzz: false,
+            proptest: false,
+            edition: None,
+            msrv: None,
+            features: Vec::new(),
+            deps: Vec::new(),
+            async_runtime: None,
+            error_style: None,
+            logging: false,
+            config_loader: false,
+            db: None,
+            spec: None,
+            spec_path: None,
+            vcs: None,
+            git_settings: GitSettings::default(),
+            publish: None,
+            remote: None,
+            push: false,
+            verify: false,
+            hook_policy: HookPolicy::default(),
+            editor: None,
+            members: Vec::new(),
+            clang_format: None,
+            sink: Sink::Disk,
+        }
+    }
 
-        let project_dir = Path::new(name).to_path_buf();
-        if project_dir.is_dir() {
-            println!(
-                "Directory {} already exists! Refusing to overwrite",
-                project.name
-            );
-            std::process::exit(1);
+    pub fn variable_names() -> Vec<String> {
+        let project = Project::with_defaults("name", Lang::Rust, ProjectKind::Executable, None);
+        match serde_json::to_value(project) {
+            Ok(serde_json::Value::Object(variables)) => variables
+                .keys()
+                .filter(|key| !matches!(key.as_str(), "project_dir" | "template_dir" | "spec_path"))
+                .cloned()
+                .collect(),
+            _ => Vec::new(),
         }
+    }
 
-        let gen_config_dir = Path::new(&std::env::var("HOME").expect("Could not find $HOME"))
-            .join(".config/gen/templates")
-            .display()
-            .to_string();
+    pub fn name(&self) -> &String {
+        &self.name
+    }
 
-        let template_dir = match project.lang {
-            Lang::Rust => Path::new(&gen_config_dir).join("rust"),
-            Lang::C => Path::new(&gen_config_dir).join("c"),
-            Lang::Cpp => Path::new(&gen_config_dir).join("cpp"),
-            Lang::Go => Path::new(&gen_config_dir).join("go"),
-            Lang::Java => Path::new(&gen_config_dir).join("java"),
-        };
+    pub fn package_name(&self) -> &String {
+        &self.package_name
+    }
+
+    pub fn set_package_name(&mut self, package_name: &str) {
+        self.package_name = String::from(package_name);
+    }
+
+    pub fn bin_name(&self) -> &String {
+        &self.bin_name
+    }
+
+    pub fn set_bin_name(&mut self, bin_name: &str) {
+        self.bin_name = String::from(bin_name);
+    }
 
+    pub fn project_dir(&self) -> Option<&PathBuf> {
+        self.project_dir.as_ref()
+    }
+
+    pub fn template_dir(&self) -> Option<&PathBuf> {
+        self.template_dir.as_ref()
+    }
+
+    pub fn set_project_dir(&mut self, project_dir: PathBuf) {
+        self.project_dir = Some(project_dir);
+    }
+
+    fn template_set(&self) -> String {
+        match self.kind {
+            ProjectKind::Docs if self.lang == Lang::Python => String::from("mkdocs"),
+            ProjectKind::Docs => String::from("mdbook"),
+            ProjectKind::Schema => String::from("schema"),
+            ProjectKind::Monorepo => String::from("monorepo"),
+            _ => self.lang.template_subdir(),
+        }
+    }
+
+    pub fn set_templates_root(&mut self, templates_root: &Path) -> anyhow::Result<()> {
+        self.set_template_set(templates_root, &self.template_set())
+    }
+
+    pub fn set_template_set(
+        &mut self,
+        templates_root: &Path,
+        template_set: &str,
+    ) -> anyhow::Result<()> {
+        // Set names can come from a .genrc, so they must stay inside the templates directory
+        validate_dir_name(template_set)?;
+        let template_dir = templates_root.join(template_set);
         if !template_dir.is_dir() {
-            println!(
+            return Err(anyhow!(
                 "Template directory {} does not exist!",
                 template_dir.display()
-            );
-            std::process::exit(1);
+            ));
         }
 
-        project.template_dir = Some(template_dir);
-        project.project_dir = Some(project_dir);
-        project
+        self.template_dir = Some(template_dir);
+        Ok(())
     }
 
-    pub fn name(&self) -> &String {
-        &self.name
+    pub fn description(&self) -> Option<&String> {
+        self.description.as_ref()
+    }
+
+    pub fn set_description(&mut self, description: &str) {
+        self.description = Some(String::from(description));
+    }
+
+    pub fn author(&self) -> Option<&String> {
+        self.author.as_ref()
+    }
+
+    pub fn set_author(&mut self, author: &str) {
+        self.author = Some(String::from(author));
+    }
+
+    pub fn repository(&self) -> Option<&String> {
+        self.repository.as_ref()
+    }
+
+    pub fn set_repository(&mut self, repository: &str) {
+        self.repository = Some(String::from(repository));
+    }
+
+    pub fn module(&self) -> Option<&String> {
+        self.module.as_ref()
+    }
+
+    pub fn set_module(&mut self, module: &str) {
+        self.module = Some(String::from(module));
+    }
+
+    pub fn license(&self) -> Option<&String> {
+        self.license.as_ref()
+    }
+
+    pub fn set_license(&mut self, license: &str) {
+        self.license = Some(String::from(license));
+    }
+
+    pub fn set_license_header(&mut self, license_header: bool) {
+        self.license_header = license_header;
+    }
+
+    pub fn set_task_runner(&mut self, task_runner: TaskRunner) {
+        self.task_runner = task_runner;
+    }
+
+    pub fn ci(&self) -> Option<&CiProvider> {
+        self.ci.as_ref()
+    }
+
+    pub fn set_ci(&mut self, ci: CiProvider) {
+        self.ci = Some(ci);
+    }
+
+    pub fn docker(&self) -> bool {
+        self.docker
+    }
+
+    pub fn set_docker(&mut self, docker: bool) {
+        self.docker = docker;
+    }
+
+    pub fn set_compose(&mut self, compose: bool) {
+        self.compose = compose;
+    }
+
+    pub fn set_k8s(&mut self, k8s: K8s) {
+        self.k8s = Some(k8s);
+    }
+
+    pub fn registry(&self) -> Option<&String> {
+        self.registry.as_ref()
+    }
+
+    pub fn set_registry(&mut self, registry: &str) {
+        self.registry = Some(String::from(registry));
+    }
+
+    pub fn target(&self) -> Option<&String> {
+        self.target.as_ref()
+    }
+
+    pub fn set_target(&mut self, target: &str) {
+        self.target = Some(String::from(target));
+    }
+
+    pub fn add_packaging(&mut self, packaging: Packaging) {
+        if !self.packaging.contains(&packaging) {
+            self.packaging.push(packaging);
+        }
+    }
+
+    pub fn devcontainer(&self) -> bool {
+        self.devcontainer
+    }
+
+    pub fn set_devcontainer(&mut self, devcontainer: bool) {
+        self.devcontainer = devcontainer;
+    }
+
+    pub fn nix(&self) -> bool {
+        self.nix
+    }
+
+    pub fn set_nix(&mut self, nix: bool) {
+        self.nix = nix;
+    }
+
+    pub fn format(&self) -> &FormatConfig {
+        &self.format
+    }
+
+    pub fn set_format(&mut self, format: FormatConfig) {
+        self.format = format;
+    }
+
+    // The clang-format binary to run, when it is not the one on PATH
+    pub fn set_clang_format(&mut self, clang_format: impl Into<PathBuf>) {
+        self.clang_format = Some(clang_format.into());
+    }
+
+    pub fn hooks(&self) -> Option<&HooksKind> {
+        self.hooks.as_ref()
+    }
+
+    pub fn set_hooks(&mut self, hooks: HooksKind) {
+        self.hooks = Some(hooks);
+    }
+
+    pub fn community(&self) -> bool {
+        self.community
+    }
+
+    pub fn set_community(&mut self, community: bool) {
+        self.community = community;
+    }
+
+    pub fn changelog(&self) -> bool {
+        self.changelog
+    }
+
+    pub fn set_changelog(&mut self, changelog: bool) {
+        self.changelog = changelog;
+    }
+
+    pub fn benches(&self) -> bool {
+        self.benches
+    }
+
+    pub fn set_benches(&mut self, benches: bool) {
+        self.benches = benches;
+    }
+
+    pub fn fuzz(&self) -> bool {
+        self.fuzz
+    }
+
+    pub fn set_fuzz(&mut self, fuzz: bool) {
+        self.fuzz = fuzz;
+    }
+
+    pub fn proptest(&self) -> bool {
+        self.proptest
+    }
+
+    pub fn set_proptest(&mut self, proptest: bool) {
+        self.proptest = proptest;
+    }
+
+    pub fn edition(&self) -> Option<&String> {
+        self.edition.as_ref()
+    }
+
+    pub fn set_edition(&mut self, edition: &str) {
+        self.edition = Some(String::from(edition));
+    }
+
+    pub fn msrv(&self) -> Option<&String> {
+        self.msrv.as_ref()
+    }
+
+    pub fn set_msrv(&mut self, msrv: &str) {
+        self.msrv = Some(String::from(msrv));
+    }
+
+    pub fn features(&self) -> &[String] {
+        &self.features
+    }
+
+    pub fn add_feature(&mut self, feature: &str) {
+        if !self.features.iter().any(|existing| existing == feature) {
+            self.features.push(String::from(feature));
+        }
+    }
+
+    pub fn deps(&self) -> &[String] {
+        &self.deps
+    }
+
+    pub fn add_dep(&mut self, dep: &str) {
+        if !self.deps.iter().any(|existing| existing == dep) {
+            self.deps.push(String::from(dep));
+        }
+    }
+
+    pub fn async_runtime(&self) -> Option<&AsyncRuntime> {
+        self.async_runtime.as_ref()
+    }
+
+    pub fn set_async_runtime(&mut self, async_runtime: AsyncRuntime) {
+        self.async_runtime = match async_runtime {
+            AsyncRuntime::None => None,
+            runtime => Some(runtime),
+        };
+    }
+
+    pub fn error_style(&self) -> Option<&ErrorStyle> {
+        self.error_style.as_ref()
+    }
+
+    pub fn set_error_style(&mut self, error_style: ErrorStyle) {
+        self.error_style = Some(error_style);
+    }
+
+    pub fn logging(&self) -> bool {
+        self.logging
+    }
+
+    pub fn set_logging(&mut self, logging: bool) {
+        self.logging = logging;
+    }
+
+    pub fn config_loader(&self) -> bool {
+        self.config_loader
+    }
+
+    pub fn set_config_loader(&mut self, config_loader: bool) {
+        self.config_loader = config_loader;
+    }
+
+    pub fn db(&self) -> Option<&Database> {
+        self.db.as_ref()
+    }
+
+    pub fn set_db(&mut self, db: Database) {
+        self.db = Some(db);
+    }
+
+    pub fn spec(&self) -> Option<&String> {
+        self.spec.as_ref()
+    }
+
+    pub fn set_spec(&mut self, spec_path: PathBuf) {
+        self.spec = spec_path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string());
+        self.spec_path = Some(spec_path);
+    }
+
+    pub fn vcs(&self) -> Option<&VcsKind> {
+        self.vcs.as_ref()
+    }
+
+    pub fn set_vcs(&mut self, vcs: VcsKind) {
+        self.vcs = Some(vcs);
+    }
+
+    pub fn git_settings(&self) -> &GitSettings {
+        &self.git_settings
+    }
+
+    pub fn set_git_settings(&mut self, git_settings: GitSettings) {
+        self.git_settings = git_settings;
+    }
+
+    pub fn publish_options(&self) -> Option<&PublishOptions> {
+        self.publish.as_ref()
+    }
+
+    pub fn set_publish_options(&mut self, publish: PublishOptions) {
+        self.publish = Some(publish);
+    }
+
+    pub fn remote(&self) -> Option<&String> {
+        self.remote.as_ref()
+    }
+
+    pub fn set_remote(&mut self, remote: &str, push: bool) {
+        self.remote = Some(String::from(remote));
+        self.push = push;
+    }
+
+    pub fn set_verify(&mut self, verify: bool) {
+        self.verify = verify;
+    }
+
+    pub fn set_hook_policy(&mut self, hook_policy: HookPolicy) {
+        self.hook_policy = hook_policy;
+    }
+
+    pub fn set_editor(&mut self, editor: &str) {
+        self.editor = Some(String::from(editor));
+    }
+
+    pub fn sink(&self) -> &Sink {
+        &self.sink
+    }
+
+    pub fn set_sink(&mut self, sink: Sink) {
+        self.sink = sink;
     }
 
     pub fn lang(&self) -> &Lang {
@@ -125,19 +851,24 @@ impl Project {
         match &self.domain {
             Some(domain) => Ok(domain.to_string()),
             None => {
-                let domain_file = match self.lang {
-                    Lang::Go | Lang::Java => Path::new(&self.template_dir.as_ref().unwrap())
-                        .join("domain")
-                        .display()
-                        .to_string(),
-                    _ => String::from(""),
-                };
-
-                if domain_file.is_empty() {
-                    Err(anyhow!("Could not find domain file"))
-                } else {
-                    let domain = fs::read_to_string(domain_file)?;
-                    Ok(domain.trim().to_string())
+                let domain_file = self
+                    .template_dir
+                    .as_ref()
+                    .map(|template_dir| template_dir.join("domain"))
+                    .filter(|domain_file| domain_file.is_file());
+
+                match domain_file {
+                    Some(domain_file) => {
+                        println!(
+                            "Warning: {} is deprecated, set domain in config.toml instead",
+                            domain_file.display()
+                        );
+                        let domain = fs::read_to_string(domain_file)?;
+                        Ok(domain.trim().to_string())
+                    }
+                    None => Err(anyhow!(
+                        "No domain set! Use --domain, or set domain in config.toml"
+                    )),
                 }
             }
         }
@@ -145,20 +876,27 @@ impl Project {
 
     pub fn create_dir(&self) -> anyhow::Result<()> {
         if let Some(project_dir) = &self.project_dir {
-            match fs::create_dir(&self.name) {
+            match self.sink.create_dir_all(project_dir) {
                 Ok(()) => {
-                    println!("Created dir  {}", self.name);
+                    self.sink.created_dir(project_dir);
                 }
                 Err(error) => {
-                    println!("Error creating directory {}: {:?}", self.name, error);
-                    return Err(error.into());
+                    println!(
+                        "Error creating directory {}: {:?}",
+                        project_dir.display(),
+                        error
+                    );
+                    return Err(error);
                 }
             }
 
-            if self.lang != Lang::Go {
-                match fs::create_dir(project_dir.join("src")) {
+            if !matches!(
+                self.lang,
+                Lang::Go | Lang::Shell | Lang::Ruby | Lang::Terraform | Lang::Latex
+            ) {
+                match self.sink.create_dir_all(&project_dir.join("src")) {
                     Ok(()) => {
-                        println!("Created dir  {}", project_dir.join("src").display());
+                        self.sink.created_dir(&project_dir.join("src"));
                     }
                     Err(error) => {
                         println!(
@@ -166,7 +904,7 @@ impl Project {
                             project_dir.join("src").display(),
                             error
                         );
-                        return Err(error.into());
+                        return Err(error);
                     }
                 }
             }
@@ -176,45 +914,171 @@ impl Project {
         }
     }
 
-    pub fn template(
+    pub fn template(&self, from_path: &Path, to_path: &Path) -> anyhow::Result<()> {
+        if self.template_syntax(from_path)? == Syntax::Raw {
+            self.sink.copy(from_path, to_path)?;
+        } else {
+            self.sink
+                .write(to_path, self.render_source(from_path, &self)?)?;
+            self.sink.copy_executable_bits(from_path, to_path)?;
+        }
+        self.sink.created(to_path);
+        Ok(())
+    }
+
+    fn template_syntax(&self, from_path: &Path) -> anyhow::Result<Syntax> {
+        match self
+            .template_dir
+            .as_ref()
+            .and_then(|template_dir| from_path.strip_prefix(template_dir).ok())
+        {
+            Some(relative_path) => Ok(self.template_manifest()?.syntax(relative_path)),
+            None => Ok(Syntax::Handlebars),
+        }
+    }
+
+    pub fn render_source<T: Serialize>(
         &self,
-        target_name: &str,
         from_path: &Path,
-        to_path: &Path,
+        data: &T,
+    ) -> anyhow::Result<String> {
+        template::render_file_as(from_path, &self.template_syntax(from_path)?, data)
+    }
+
+    pub fn render_template_with(
+        &self,
+        template_path: &Path,
+        output_path: &Path,
+        variables: &serde_json::Value,
+    ) -> anyhow::Result<PathBuf> {
+        if let Some(project_dir) = &self.project_dir {
+            let from_path = self.template_file(template_path)?;
+
+            let to_path = project_dir.join(template::render_str(
+                &output_path.display().to_string(),
+                variables,
+            )?);
+            if let Some(parent) = to_path.parent() {
+                self.sink.create_dir_all(parent)?;
+            }
+            self.sink
+                .write(&to_path, self.render_source(&from_path, variables)?)?;
+            self.sink.copy_executable_bits(&from_path, &to_path)?;
+            self.sink.created(&to_path);
+            Ok(to_path)
+        } else {
+            Err(anyhow!("Template or project directory not set"))
+        }
+    }
+
+    pub fn render_template_file(
+        &self,
+        template_path: &Path,
+        output_path: &Path,
     ) -> anyhow::Result<()> {
-        let mut handlebars = Handlebars::new();
-        handlebars.register_template_file(target_name, from_path)?;
-        let rendered_makefile = handlebars.render(target_name, &self)?;
-        File::create(to_path)?;
-        fs::write(to_path, rendered_makefile)?;
-        println!("Created file {}", to_path.display());
-        Ok(())
+        if let Some(project_dir) = &self.project_dir {
+            let from_path = self.template_file(template_path)?;
+
+            let to_path = project_dir.join(output_path);
+            if let Some(parent) = to_path.parent() {
+                self.sink.create_dir_all(parent)?;
+            }
+            self.template(&from_path, &to_path)
+        } else {
+            Err(anyhow!("Template or project directory not set"))
+        }
     }
 
-    pub fn create_makefile(&self) -> anyhow::Result<()> {
-        if let (Some(template_dir), Some(project_dir)) = (&self.template_dir, &self.project_dir) {
-            let makefile_name = match self.kind {
-                ProjectKind::Library => "Makefile.lib",
-                ProjectKind::Executable => "Makefile.bin",
-            };
-            self.template(
-                "Makefile",
-                &template_dir.join(makefile_name),
-                &project_dir.join("Makefile"),
-            )?;
+    pub fn templates_root(&self) -> Option<&Path> {
+        self.template_dir
+            .as_ref()
+            .and_then(|template_dir| template_dir.parent())
+    }
+
+    pub fn template_source(&self, template_path: &Path) -> Option<PathBuf> {
+        let template_dir = self.template_dir.as_ref()?;
+        for overlay_dir in self.kind.overlay_dirs() {
+            let overlay_path = template_dir.join(overlay_dir).join(template_path);
+            if overlay_path.is_file() {
+                return Some(overlay_path);
+            }
+        }
+
+        let legacy_path = legacy_template_path(template_dir, self.kind, template_path);
+        if legacy_path.is_file() {
+            return Some(legacy_path);
+        }
+
+        let from_path = template_dir.join(template_path);
+        if from_path.is_file() {
+            return Some(from_path);
+        }
+
+        let base_path = self.templates_root()?.join("_base").join(template_path);
+        if base_path.is_file() {
+            Some(base_path)
+        } else {
+            None
+        }
+    }
+
+    pub fn template_file(&self, template_path: &Path) -> anyhow::Result<PathBuf> {
+        match self.template_source(template_path) {
+            Some(from_path) => Ok(from_path),
+            None => match &self.template_dir {
+                Some(template_dir) => Err(anyhow!(
+                    "Template {} does not exist!",
+                    template_dir.join(template_path).display()
+                )),
+                None => Err(anyhow!("Template directory not set")),
+            },
+        }
+    }
+
+    fn provided_by_plugin(&self, template_path: &Path) -> bool {
+        let generated = self
+            .project_dir
+            .as_ref()
+            .is_some_and(|project_dir| self.sink.exists(&project_dir.join(template_path)));
+        matches!(self.lang, Lang::Custom(_))
+            && (generated || self.template_source(template_path).is_none())
+    }
+
+    pub fn create_readme(&self) -> anyhow::Result<()> {
+        if let Some(project_dir) = &self.project_dir {
+            if let Some(from_path) = self.template_source(Path::new("README.md")) {
+                if !self.sink.exists(&project_dir.join("README.md")) {
+                    self.template(&from_path, &project_dir.join("README.md"))?;
+                }
+            }
             Ok(())
         } else {
-            Err(anyhow::anyhow!("Project directory not set"))
+            Err(anyhow::anyhow!("Template or project directory not set"))
         }
     }
 
     pub fn create_gitignore(&self) -> anyhow::Result<()> {
-        if let (Some(template_dir), Some(project_dir)) = (&self.template_dir, &self.project_dir) {
-            fs::copy(
-                template_dir.join(".gitignore"),
-                project_dir.join(".gitignore"),
-            )?;
-            println!("Created file {}", project_dir.join(".gitignore").display());
+        if let Some(project_dir) = &self.project_dir {
+            if self.provided_by_plugin(Path::new(".gitignore")) {
+                return Ok(());
+            }
+            let ignore_file = self.vcs_backend().ignore_file().to_string();
+            let mut contents = String::new();
+            let base_ignore = self
+                .templates_root()
+                .map(|templates_root| templates_root.join("_base").join(".gitignore"));
+            if let Some(base_ignore) = base_ignore.as_ref().filter(|path| path.is_file()) {
+                contents.push_str(&fs::read_to_string(base_ignore)?);
+            }
+            let ignore_template = self.template_file(Path::new(".gitignore"))?;
+            if Some(&ignore_template) != base_ignore.as_ref() {
+                contents.push_str(&fs::read_to_string(ignore_template)?);
+            }
+            if ignore_file == ".hgignore" {
+                contents = format!("syntax: glob\n{}", contents);
+            }
+            self.sink.write(&project_dir.join(&ignore_file), contents)?;
+            self.sink.created(&project_dir.join(&ignore_file));
             Ok(())
         } else {
             Err(anyhow::anyhow!("Template or project directory not set"))
@@ -222,17 +1086,27 @@ impl Project {
     }
 
     pub fn create_clang_format(&self) -> anyhow::Result<()> {
+        if self.sink.is_memory() {
+            return Ok(());
+        }
         if let Some(project_dir) = &self.project_dir {
-            let output = Command::new("clang-format")
-                .arg("-style={BasedOnStyle: Google, IndentWidth: 4}")
+            let clang_format = self
+                .clang_format
+                .as_deref()
+                .unwrap_or(Path::new("clang-format"));
+            let output = Command::new(clang_format)
+                .arg(format!(
+                    "-style={{BasedOnStyle: Google, IndentWidth: {}, ColumnLimit: {}}}",
+                    self.format.indent_width, self.format.max_width
+                ))
                 .arg("--dump-config")
                 .stdout(File::create(project_dir.join(".clang-format"))?)
                 .output();
 
             match output {
                 Ok(output) => {
-                    if output.stderr.is_empty() {
-                        println!("{}", String::from_utf8(output.stderr)?);
+                    if !output.status.success() {
+                        println!("{}", String::from_utf8_lossy(&output.stderr));
                         return Err(anyhow::anyhow!("Error creating .clang-format file"));
                     }
 
@@ -253,17 +1127,33 @@ impl Project {
     }
 
     pub fn create_c_project(&self) -> anyhow::Result<()> {
-        if let (Some(project_dir), Some(template_dir)) = (&self.project_dir, &self.template_dir) {
-            self.create_clang_format()?;
-            if self.kind == ProjectKind::Executable {
-                fs::copy(
-                    template_dir.join("src").join("main.c"),
-                    project_dir.join("src").join("main.c"),
+        if let Some(project_dir) = &self.project_dir {
+            if self.kind == ProjectKind::Kmod {
+                self.render_template_file(
+                    &Path::new("src").join("main.c"),
+                    &Path::new("src").join("main.c"),
                 )?;
-                println!(
-                    "Created file {}",
-                    project_dir.join("src").join("main.c").display()
-                );
+                self.render_template_file(Path::new("Kbuild"), Path::new("Kbuild"))?;
+            } else if self.kind.is_executable()
+                || matches!(self.kind, ProjectKind::Baremetal | ProjectKind::Wasm)
+            {
+                self.sink.copy(
+                    &self.template_file(&Path::new("src").join("main.c"))?,
+                    &project_dir.join("src").join("main.c"),
+                )?;
+                self.sink.created(&project_dir.join("src").join("main.c"));
+                if self.kind == ProjectKind::Baremetal {
+                    self.render_template_file(
+                        &Path::new("src").join("startup.s"),
+                        &Path::new("src").join("startup.s"),
+                    )?;
+                    self.render_template_file(Path::new("linker.ld"), Path::new("linker.ld"))?;
+                }
+                if self.kind == ProjectKind::Wasm {
+                    self.render_template_file(Path::new("index.html"), Path::new("index.html"))?;
+                }
+            } else if self.kind == ProjectKind::Library {
+                self.create_pkgconfig_file()?;
             }
             Ok(())
         } else {
@@ -272,14 +1162,23 @@ impl Project {
     }
 
     pub fn create_cpp_project(&self) -> anyhow::Result<()> {
-        if let (Some(project_dir), Some(template_dir)) = (&self.project_dir, &self.template_dir) {
-            self.create_clang_format()?;
-            if self.kind == ProjectKind::Executable {
+        if let Some(project_dir) = &self.project_dir {
+            if self.kind.is_executable() || self.kind == ProjectKind::Wasm {
                 self.template(
-                    "main.cpp",
-                    &template_dir.join("src").join("main.cpp"),
+                    &self.template_file(&Path::new("src").join("main.cpp"))?,
                     &project_dir.join("src").join("main.cpp"),
                 )?;
+                if self.kind == ProjectKind::Qt {
+                    self.render_template_file(
+                        Path::new("CMakeLists.txt"),
+                        Path::new("CMakeLists.txt"),
+                    )?;
+                }
+                if self.kind == ProjectKind::Wasm {
+                    self.render_template_file(Path::new("index.html"), Path::new("index.html"))?;
+                }
+            } else if self.kind == ProjectKind::Library {
+                self.create_pkgconfig_file()?;
             }
             Ok(())
         } else {
@@ -287,41 +1186,117 @@ impl Project {
         }
     }
 
+    pub fn create_asm_project(&self) -> anyhow::Result<()> {
+        if let Some(project_dir) = &self.project_dir {
+            self.sink.copy(
+                &self.template_file(&Path::new("src").join("main.asm"))?,
+                &project_dir.join("src").join("main.asm"),
+            )?;
+            self.sink.created(&project_dir.join("src").join("main.asm"));
+            Ok(())
+        } else {
+            Err(anyhow::anyhow!("Template or project directory not set"))
+        }
+    }
+
+    pub fn create_shell_project(&self) -> anyhow::Result<()> {
+        if let Some(project_dir) = &self.project_dir {
+            if self.kind == ProjectKind::Executable {
+                let script = Path::new("bin").join(&self.bin_name);
+                self.render_template_file(Path::new("main.sh"), &script)?;
+                self.sink.make_executable(&project_dir.join(script))?;
+            }
+            self.render_template_file(
+                Path::new("lib.sh"),
+                &Path::new("lib").join(format!("{}.sh", self.bin_name)),
+            )?;
+            self.render_template_file(
+                Path::new("test.bats"),
+                &Path::new("test").join(format!("{}.bats", self.bin_name)),
+            )?;
+            self.render_template_file(Path::new(".shellcheckrc"), Path::new(".shellcheckrc"))
+        } else {
+            Err(anyhow::anyhow!("Template or project directory not set"))
+        }
+    }
+
+    pub fn create_python_project(&self) -> anyhow::Result<()> {
+        let module_name = match &self.module {
+            Some(module_name) => module_name,
+            None => return Err(anyhow!("Python module name not set")),
+        };
+        let module = Path::new("src").join(module_name);
+        self.render_template_file(Path::new("pyproject.toml"), Path::new("pyproject.toml"))?;
+        self.render_template_file(
+            &Path::new("src").join("__init__.py"),
+            &module.join("__init__.py"),
+        )?;
+        if self.kind == ProjectKind::Executable {
+            self.render_template_file(
+                &Path::new("src").join("__main__.py"),
+                &module.join("__main__.py"),
+            )?;
+        }
+        if self.kind == ProjectKind::Datasci {
+            self.render_template_file(
+                &Path::new("notebooks").join("exploration.ipynb"),
+                &Path::new("notebooks").join("exploration.ipynb"),
+            )?;
+            self.render_template_file(
+                &Path::new("data").join(".gitkeep"),
+                &Path::new("data").join(".gitkeep"),
+            )?;
+        }
+        self.render_template_file(
+            Path::new("test.py"),
+            &Path::new("tests").join(format!("test_{}.py", module_name)),
+        )
+    }
+
+    fn create_pkgconfig_file(&self) -> anyhow::Result<()> {
+        self.render_template_file(
+            Path::new("pkgconfig.pc"),
+            Path::new(&format!("{}.pc", self.package_name)),
+        )
+    }
+
     pub fn create_go_project(&self) -> anyhow::Result<()> {
-        let domain = match &self.domain {
-            Some(domain) => domain.to_owned(),
-            None => {
+        let module = match (&self.module, &self.domain) {
+            (Some(module), _) => module.to_owned(),
+            (None, Some(domain)) => format!("{}/{}", domain, self.package_name),
+            (None, None) => {
                 let default_domain = self.get_default_domain()?;
                 println!(
                     "No domain specified, using default domain {}",
                     default_domain
                 );
-                default_domain
+                format!("{}/{}", default_domain, self.package_name)
             }
         };
 
-        let output = Command::new("go")
-            .arg("mod")
-            .arg("init")
-            .arg(format!("{}/{}", domain, self.name))
-            .current_dir(self.project_dir.as_ref().unwrap())
-            .output();
-
-        match output {
-            Ok(output) => {
-                println!("{}", String::from_utf8_lossy(&output.stdout));
-                println!("{}", String::from_utf8_lossy(&output.stderr));
-            }
-            Err(error) => {
-                println!("{}", error);
+        if !self.sink.is_memory() {
+            let output = Command::new("go")
+                .arg("mod")
+                .arg("init")
+                .arg(module)
+                .current_dir(self.project_dir.as_ref().unwrap())
+                .output();
+
+            match output {
+                Ok(output) => {
+                    println!("{}", String::from_utf8_lossy(&output.stdout));
+                    println!("{}", String::from_utf8_lossy(&output.stderr));
+                }
+                Err(error) => {
+                    println!("{}", error);
+                }
             }
         }
 
-        if let (Some(project_dir), Some(template_dir)) = (&self.project_dir, &self.template_dir) {
-            if self.kind == ProjectKind::Executable {
+        if let Some(project_dir) = &self.project_dir {
+            if self.kind.is_executable() {
                 self.template(
-                    "main.go",
-                    &template_dir.join("main.go"),
+                    &self.template_file(Path::new("main.go"))?,
                     &project_dir.join("main.go"),
                 )?;
             }
@@ -344,27 +1319,34 @@ impl Project {
             }
         };
 
-        let output = Command::new("mvn")
-            .arg("archetype:generate")
-            .arg(format!("-DgroupId={}.{}", domain, self.name))
-            .arg(format!("-DartifactId={}", self.name))
-            .arg("-DarchetypeArtifactId=maven-archetype-quickstart")
-            .arg("-DinteractiveMode=false")
-            .output();
-
-        match output {
-            Ok(output) => {
-                println!("{}", String::from_utf8_lossy(&output.stdout));
-                println!("{}", String::from_utf8_lossy(&output.stderr));
-            }
-            Err(error) => {
-                println!("{}", error);
+        if !self.sink.is_memory() {
+            let project_dir = self.project_dir.as_ref().unwrap();
+            let archetype_dir = project_dir.join(".archetype");
+            fs::create_dir_all(&archetype_dir)?;
+
+            let output = Command::new("mvn")
+                .arg("archetype:generate")
+                .arg(format!("-DgroupId={}.{}", domain, self.package_name))
+                .arg(format!("-DartifactId={}", self.package_name))
+                .arg("-DarchetypeArtifactId=maven-archetype-quickstart")
+                .arg("-DinteractiveMode=false")
+                .current_dir(&archetype_dir)
+                .output();
+
+            match output {
+                Ok(output) => {
+                    println!("{}", String::from_utf8_lossy(&output.stdout));
+                    println!("{}", String::from_utf8_lossy(&output.stderr));
+                }
+                Err(error) => {
+                    println!("{}", error);
+                }
             }
         }
-        if let (Some(project_dir), Some(template_dir)) = (&self.project_dir, &self.template_dir) {
+
+        if let Some(project_dir) = &self.project_dir {
             self.template(
-                "manifest.txt",
-                &template_dir.join("manifest.txt"),
+                &self.template_file(Path::new("manifest.txt"))?,
                 &project_dir.join("manifest.txt"),
             )?;
         } else {
@@ -373,73 +1355,567 @@ impl Project {
         Ok(())
     }
 
-    pub fn create_rust_project(&self) -> anyhow::Result<()> {
-        let args = match self.kind {
-            ProjectKind::Library => "--lib",
-            ProjectKind::Executable => "--bin",
+    // mvn generates into .archetype, which nothing else writes to, so it can run alongside the
+    // standalone files. Its output is moved into the project only once they are written, and
+    // never replaces them, just as when mvn ran before they were rendered
+    pub fn move_java_archetype(&self) -> anyhow::Result<()> {
+        if self.sink.is_memory() {
+            return Ok(());
+        }
+
+        let project_dir = match &self.project_dir {
+            Some(project_dir) => project_dir,
+            None => return Err(anyhow!("Project directory not set")),
         };
+        let archetype_dir = project_dir.join(".archetype");
+        let generated_dir = archetype_dir.join(&self.package_name);
+        if generated_dir.is_dir() {
+            move_into(&generated_dir, project_dir)?;
+        }
+        if archetype_dir.exists() {
+            fs::remove_dir_all(&archetype_dir)?;
+        }
+        Ok(())
+    }
 
-        let output = Command::new("cargo")
-            .arg("new")
-            .arg(&self.name)
-            .arg(args)
-            .output();
-
-        match output {
-            Ok(output) => {
-                println!("{}", String::from_utf8_lossy(&output.stdout));
-                println!("{}", String::from_utf8_lossy(&output.stderr));
-            }
-            Err(error) => {
-                println!("{}", error);
-            }
+    pub fn create_lua_project(&self) -> anyhow::Result<()> {
+        let module = match &self.module {
+            Some(module) => module,
+            None => return Err(anyhow!("Lua module name not set")),
+        };
+
+        if self.kind == ProjectKind::Love {
+            self.render_template_file(Path::new("main.lua"), Path::new("main.lua"))?;
+            self.render_template_file(Path::new("conf.lua"), Path::new("conf.lua"))?;
+        } else {
+            self.render_template_file(
+                Path::new("rockspec"),
+                Path::new(&format!("{}-dev-1.rockspec", self.package_name)),
+            )?;
+        }
+        if self.kind == ProjectKind::Executable {
+            let to_path = self
+                .project_dir
+                .as_ref()
+                .unwrap()
+                .join("bin")
+                .join(&self.bin_name);
+            self.render_template_file(
+                Path::new("main.lua"),
+                &Path::new("bin").join(&self.bin_name),
+            )?;
+            self.sink.make_executable(&to_path)?;
         }
 
-        if let (Some(project_dir), Some(template_dir)) = (&self.project_dir, &self.template_dir) {
-            if self.kind == ProjectKind::Executable {
-                fs::copy(
-                    template_dir.join("src").join("main.rs"),
-                    project_dir.join("src").join("main.rs"),
+        self.render_template_file(
+            &Path::new("src").join("lib.lua"),
+            &Path::new("src").join(format!("{}.lua", module)),
+        )?;
+        self.render_template_file(Path::new(".busted"), Path::new(".busted"))?;
+        self.render_template_file(Path::new(".luacheckrc"), Path::new(".luacheckrc"))?;
+        self.render_template_file(
+            Path::new("spec.lua"),
+            &Path::new("spec").join(format!("{}_spec.lua", module)),
+        )
+    }
+
+    pub fn create_terraform_project(&self) -> anyhow::Result<()> {
+        for file in ["main.tf", "variables.tf", "outputs.tf", "versions.tf"] {
+            self.render_template_file(Path::new(file), Path::new(file))?;
+        }
+        let example = Path::new("examples").join("basic").join("main.tf");
+        self.render_template_file(&example, &example)
+    }
+
+    pub fn create_php_project(&self) -> anyhow::Result<()> {
+        let namespace = match &self.module {
+            Some(namespace) => namespace,
+            None => return Err(anyhow!("PHP namespace not set")),
+        };
+        let vendor = namespace.split('\\').next().unwrap_or(namespace);
+        let mut variables = serde_json::to_value(self)?;
+        variables["composer_name"] = serde_json::Value::from(format!(
+            "{}/{}",
+            snake_case(vendor).replace('_', "-"),
+            self.package_name.to_lowercase()
+        ));
+        variables["composer_namespace"] = serde_json::Value::from(namespace.replace('\\', "\\\\"));
+
+        let template_dir = Path::new("composer");
+        let files = [
+            ("composer.json", PathBuf::from("composer.json")),
+            ("phpunit.xml", PathBuf::from("phpunit.xml.dist")),
+            ("Greeter.php", Path::new("src").join("Greeter.php")),
+            (
+                "GreeterTest.php",
+                Path::new("tests").join("GreeterTest.php"),
+            ),
+        ];
+        for (template_name, output_path) in files {
+            self.render_template_with(&template_dir.join(template_name), &output_path, &variables)?;
+        }
+
+        if self.kind == ProjectKind::Executable {
+            let to_path = self.render_template_with(
+                &template_dir.join("main.php"),
+                &Path::new("bin").join(&self.bin_name),
+                &variables,
+            )?;
+            self.sink.make_executable(&to_path)?;
+        }
+        Ok(())
+    }
+
+    pub fn create_scala_project(&self) -> anyhow::Result<()> {
+        let package_dir = match &self.module {
+            Some(package) => package.split('.').collect::<PathBuf>(),
+            None => return Err(anyhow!("Scala package not set")),
+        };
+
+        self.render_template_file(Path::new("build.sbt"), Path::new("build.sbt"))?;
+        self.render_template_file(
+            &Path::new("project").join("build.properties"),
+            &Path::new("project").join("build.properties"),
+        )?;
+        self.render_template_file(
+            &Path::new("project").join("plugins.sbt"),
+            &Path::new("project").join("plugins.sbt"),
+        )?;
+        self.render_template_file(Path::new(".scalafmt.conf"), Path::new(".scalafmt.conf"))?;
+
+        let main_dir = Path::new("src")
+            .join("main")
+            .join("scala")
+            .join(&package_dir);
+        let test_dir = Path::new("src")
+            .join("test")
+            .join("scala")
+            .join(&package_dir);
+        self.render_template_file(
+            &Path::new("scala").join("Greeting.scala"),
+            &main_dir.join("Greeting.scala"),
+        )?;
+        if self.kind == ProjectKind::Executable {
+            self.render_template_file(
+                &Path::new("scala").join("Main.scala"),
+                &main_dir.join("Main.scala"),
+            )?;
+        }
+        self.render_template_file(
+            &Path::new("scala").join("GreetingSuite.scala"),
+            &test_dir.join("GreetingSuite.scala"),
+        )
+    }
+
+    pub fn create_ruby_project(&self) -> anyhow::Result<()> {
+        let gem = match &self.module {
+            Some(gem) => gem,
+            None => return Err(anyhow!("Ruby gem name not set")),
+        };
+        let mut variables = serde_json::to_value(self)?;
+        variables["ruby_module"] = serde_json::Value::from(pascal_case(gem));
+
+        let template_dir = Path::new("gem");
+        let files = [
+            (
+                "gemspec",
+                PathBuf::from(format!("{}.gemspec", self.package_name)),
+            ),
+            ("Gemfile", PathBuf::from("Gemfile")),
+            ("Rakefile", PathBuf::from("Rakefile")),
+            ("lib.rb", Path::new("lib").join(format!("{}.rb", gem))),
+            ("version.rb", Path::new("lib").join(gem).join("version.rb")),
+            (".rspec", PathBuf::from(".rspec")),
+            ("spec_helper.rb", Path::new("spec").join("spec_helper.rb")),
+            (
+                "spec.rb",
+                Path::new("spec").join(format!("{}_spec.rb", gem)),
+            ),
+        ];
+        for (template_name, output_path) in files {
+            self.render_template_with(&template_dir.join(template_name), &output_path, &variables)?;
+        }
+
+        if self.kind == ProjectKind::Executable {
+            let executables = [
+                ("exe", Path::new("exe").join(&self.bin_name)),
+                ("setup", Path::new("bin").join("setup")),
+                ("console", Path::new("bin").join("console")),
+            ];
+            for (template_name, output_path) in executables {
+                let to_path = self.render_template_with(
+                    &template_dir.join(template_name),
+                    &output_path,
+                    &variables,
                 )?;
-                println!(
-                    "Created file {}",
-                    project_dir.join("src").join("main.rs").display()
-                );
+                self.sink.make_executable(&to_path)?;
             }
+        }
+        Ok(())
+    }
 
-            File::create(project_dir.join("src").join("lib.rs"))?;
-            println!(
-                "Created file {}",
-                project_dir.join("src").join("lib.rs").display()
+    pub fn create_elixir_project(&self) -> anyhow::Result<()> {
+        let (project_dir, app) = match (&self.project_dir, &self.module) {
+            (Some(project_dir), Some(app)) => (project_dir, app),
+            _ => return Err(anyhow!("Project directory or app name not set")),
+        };
+        let module = pascal_case(app);
+
+        if !self.sink.is_memory() {
+            self.create_mix_project(project_dir, app, &module)?;
+        }
+
+        let mut variables = serde_json::to_value(self)?;
+        variables["elixir_module"] = serde_json::Value::from(module.as_str());
+        if self.kind == ProjectKind::Executable {
+            self.render_template_with(
+                &Path::new("mix").join("cli.ex"),
+                &Path::new("lib").join(app).join("cli.ex"),
+                &variables,
+            )?;
+        }
+        if self.kind == ProjectKind::Daemon {
+            self.render_template_with(
+                &Path::new("mix").join("worker.ex"),
+                &Path::new("lib").join(app).join("worker.ex"),
+                &variables,
+            )?;
+        }
+        Ok(())
+    }
+
+    fn create_mix_project(
+        &self,
+        project_dir: &Path,
+        app: &str,
+        module: &str,
+    ) -> anyhow::Result<()> {
+        let mut command = Command::new("mix");
+        command
+            .arg("new")
+            .arg(project_dir)
+            .arg("--app")
+            .arg(app)
+            .arg("--module")
+            .arg(module);
+        if self.kind == ProjectKind::Daemon {
+            command.arg("--sup");
+        }
+        let output = command.output()?;
+        println!("{}", String::from_utf8_lossy(&output.stdout));
+        println!("{}", String::from_utf8_lossy(&output.stderr));
+        if !output.status.success() {
+            return Err(anyhow!("`mix new` failed"));
+        }
+
+        // gen's README replaces the one mix generates
+        fs::remove_file(project_dir.join("README.md"))?;
+
+        let mix_path = project_dir.join("mix.exs");
+        let mut mix = fs::read_to_string(&mix_path)?;
+        if self.kind == ProjectKind::Executable {
+            mix = mix.replacen(
+                "      deps: deps()",
+                &format!(
+                    "      escript: [main_module: {}.CLI, name: \"{}\"],\n      deps: deps()",
+                    module, self.bin_name
+                ),
+                1,
+            );
+        }
+        if self.kind == ProjectKind::Daemon {
+            let application_path = project_dir.join("lib").join(app).join("application.ex");
+            let application = fs::read_to_string(&application_path)?.replacen(
+                &format!("# {{{}.Worker, arg}}", module),
+                &format!("{{{}.Worker, []}}", module),
+                1,
+            );
+            fs::write(&application_path, application)?;
+            mix = mix.replacen(
+                "      deps: deps()",
+                &format!(
+                    "      releases: [{}: [include_executables_for: [:unix]]],\n      deps: deps()",
+                    app
+                ),
+                1,
             );
         }
+        fs::write(&mix_path, mix)?;
         Ok(())
     }
 
-    pub fn generate(&self) -> anyhow::Result<()> {
-        match self.lang {
-            Lang::C => {
-                self.create_dir()?;
-                self.create_c_project()?;
+    pub fn create_rust_project(&self) -> anyhow::Result<()> {
+        let args = match self.kind {
+            ProjectKind::Library
+            | ProjectKind::Kmod
+            | ProjectKind::Ffi
+            | ProjectKind::Schema
+            | ProjectKind::Docs
+            | ProjectKind::Monorepo => "--lib",
+            ProjectKind::Executable
+            | ProjectKind::Daemon
+            | ProjectKind::Grpc
+            | ProjectKind::Openapi
+            | ProjectKind::Sdl
+            | ProjectKind::Qt
+            | ProjectKind::Embedded
+            | ProjectKind::Baremetal
+            | ProjectKind::Wasm
+            | ProjectKind::Datasci
+            | ProjectKind::Love => "--bin",
+        };
+
+        if !self.sink.is_memory() {
+            let mut command = Command::new("cargo");
+            command
+                .arg("new")
+                .arg(self.project_dir.as_ref().unwrap())
+                .arg("--name")
+                .arg(&self.package_name)
+                .arg(args);
+            if let Some(edition) = &self.edition {
+                command.arg("--edition").arg(edition);
             }
-            Lang::Cpp => {
-                self.create_dir()?;
-                self.create_cpp_project()?;
+            match self.vcs_kind() {
+                Some(VcsKind::Git) => {
+                    command.arg("--vcs").arg("git");
+                }
+                Some(VcsKind::Hg) => {
+                    command.arg("--vcs").arg("hg");
+                }
+                Some(VcsKind::Jj) | Some(VcsKind::None) => {
+                    command.arg("--vcs").arg("none");
+                }
+                None => {}
             }
-            Lang::Java => {
-                self.create_java_project()?;
+            let output = command.output();
+
+            match output {
+                Ok(output) => {
+                    println!("{}", String::from_utf8_lossy(&output.stdout));
+                    println!("{}", String::from_utf8_lossy(&output.stderr));
+                }
+                Err(error) => {
+                    println!("{}", error);
+                }
             }
-            Lang::Rust => {
-                self.create_rust_project()?;
+        }
+
+        if let Some(project_dir) = &self.project_dir {
+            if self.kind.is_executable() || self.kind == ProjectKind::Embedded {
+                self.template(
+                    &self.template_file(&Path::new("src").join("main.rs"))?,
+                    &project_dir.join("src").join("main.rs"),
+                )?;
+
+                let manifest_path = project_dir.join("Cargo.toml");
+                if self.bin_name != self.package_name && self.sink.is_file(&manifest_path) {
+                    let mut manifest = self.sink.read_to_string(&manifest_path)?;
+                    manifest.push_str(&format!(
+                        "\n[[bin]]\nname = \"{}\"\npath = \"src/main.rs\"\n",
+                        self.bin_name
+                    ));
+                    self.sink.write(&manifest_path, manifest)?;
+                }
             }
-            Lang::Go => {
-                self.create_dir()?;
-                self.create_go_project()?;
+
+            if !matches!(self.kind, ProjectKind::Embedded | ProjectKind::Ffi) {
+                self.sink
+                    .write(&project_dir.join("src").join("lib.rs"), "\n")?;
+                self.sink.created(&project_dir.join("src").join("lib.rs"));
             }
         }
+        Ok(())
+    }
 
+    pub fn create_language_project(&self) -> anyhow::Result<()> {
+        if self.kind == ProjectKind::Monorepo {
+            return self.create_monorepo_dir();
+        }
+        if self.kind == ProjectKind::Schema {
+            return self.create_schema_dir();
+        }
+        if self.kind == ProjectKind::Docs {
+            return self.create_docs_dir();
+        }
+
+        if self.template_manifest()?.snapshot {
+            return match &self.project_dir {
+                Some(project_dir) => self.sink.create_dir_all(project_dir),
+                None => Err(anyhow!("Project directory not set")),
+            };
+        }
+
+        if !self.sink.is_memory() {
+            let provided: &[&str] = match self.clang_format {
+                Some(_) => &["clang-format"],
+                None => &[],
+            };
+            languages::check_tools(self.lang, provided)?;
+        }
+        let generator = generator::generator(self.lang)?;
+        generator.prepare(self)?;
+        if generator.scaffolds_in_place() {
+            generator::concurrently(
+                || generator.scaffold(self),
+                || self.create_standalone_files(),
+            )?;
+        } else {
+            generator.scaffold(self)?;
+        }
+        generator.post_process(self)
+    }
+
+    fn create_standalone_files(&self) -> anyhow::Result<()> {
         self.create_gitignore()?;
-        self.create_makefile()?;
+        self.create_task_file()?;
+        self.create_readme()?;
+        self.create_ci()?;
+        self.create_docker()?;
+        self.create_compose()?;
+        self.create_packaging()?;
+        self.create_systemd_files()?;
+        self.create_k8s()?;
+        self.create_devcontainer()?;
+        self.create_nix()
+    }
+
+    pub fn create_files(&self) -> anyhow::Result<()> {
+        if self.kind == ProjectKind::Monorepo {
+            return self.create_monorepo_files();
+        }
+        if self.kind == ProjectKind::Schema {
+            return self.create_schema_files();
+        }
+        if self.kind == ProjectKind::Docs {
+            return self.create_docs_files();
+        }
+        if self.template_manifest()?.snapshot {
+            self.create_template_tree()?;
+            return self.mark_executables();
+        }
+
+        self.apply_metadata()?;
+        self.create_grpc_service()?;
+        self.create_embedded_files()?;
+        self.create_cross_config()?;
+        self.create_ffi_files()?;
+        self.create_async_main()?;
+        self.create_benches()?;
+        self.create_fuzz_target()?;
+        self.create_property_tests()?;
+        self.create_features()?;
+        self.add_deps()?;
+        self.create_error_module()?;
+        self.create_logging()?;
+        self.create_config_loader()?;
+        self.create_db()?;
+        if !generator::generator(self.lang)?.scaffolds_in_place() {
+            self.create_standalone_files()?;
+        }
+        self.create_format_files()?;
+        self.create_openapi_service()?;
+        self.create_hooks()?;
+        self.create_community_files()?;
+        self.create_changelog()?;
+        self.create_template_tree()?;
+        self.mark_executables()?;
+        self.add_license_headers()?;
+        Ok(())
+    }
+
+    pub fn generate(&self) -> anyhow::Result<()> {
+        if self.compose && !self.kind.is_executable() {
+            return Err(anyhow!(
+                "--compose is only supported for executable and daemon projects"
+            ));
+        }
+        if self.docker && !self.kind.is_executable() {
+            return Err(anyhow!(
+                "--docker is only supported for executable projects"
+            ));
+        }
+        if self.docker && matches!(self.kind, ProjectKind::Sdl | ProjectKind::Qt) {
+            return Err(anyhow!("--docker is not supported for graphical projects"));
+        }
+        if self.k8s.is_some() && !self.kind.is_executable() {
+            return Err(anyhow!(
+                "--k8s is only supported for executable and daemon projects"
+            ));
+        }
+        self.check_target()?;
+        self.check_packaging()?;
+        self.check_benches()?;
+        self.check_fuzz()?;
+        self.check_proptest()?;
+        self.check_toolchain()?;
+        self.check_features()?;
+        self.check_deps()?;
+        self.check_async_runtime()?;
+        self.check_error_style()?;
+        self.check_logging()?;
+        self.check_config_loader()?;
+        self.check_db()?;
+        self.check_openapi()?;
+        self.check_members()?;
+
+        if let Some(project_dir) = &self.project_dir {
+            if project_dir.exists() {
+                return Err(anyhow!(
+                    "Directory {} already exists! Refusing to overwrite",
+                    project_dir.display()
+                ));
+            }
+            journal::begin_run(project_dir)?;
+        }
+
+        self.create_language_project()?;
+        self.init_vcs()?;
+        self.create_files()?;
+        self.run_post_generate_hooks()?;
+        self.write_record()?;
+        self.commit_initial()?;
+        self.publish()?;
+        self.connect_remote()?;
+        self.verify()?;
+        self.open_in_editor()?;
         Ok(())
     }
 }
+
+fn copy_file(from_path: &Path, to_path: &Path) -> anyhow::Result<()> {
+    match reflink_copy::reflink(from_path, to_path) {
+        Ok(()) => fs::set_permissions(to_path, fs::metadata(from_path)?.permissions())?,
+        Err(_) => {
+            fs::copy(from_path, to_path)?;
+        }
+    }
+    Ok(())
+}
+
+// Template sets installed before the bin/ and lib/ overlays kept kind-specific templates next
+// to the shared ones, as Makefile.bin and Makefile.lib
+pub(crate) fn legacy_template_path(
+    template_dir: &Path,
+    kind: ProjectKind,
+    template_path: &Path,
+) -> PathBuf {
+    let suffix = if kind.is_executable() { ".bin" } else { ".lib" };
+    let mut legacy_path = template_dir.join(template_path).into_os_string();
+    legacy_path.push(suffix);
+    PathBuf::from(legacy_path)
+}
+
+fn move_into(from_dir: &Path, to_dir: &Path) -> anyhow::Result<()> {
+    for entry in fs::read_dir(from_dir)? {
+        let entry = entry?;
+        let from_path = entry.path();
+        let to_path = to_dir.join(entry.file_name());
+        if from_path.is_dir() && to_path.is_dir() {
+            move_into(&from_path, &to_path)?;
+        } else if !to_path.exists() {
+            fs::rename(&from_path, &to_path)?;
+        }
+    }
+    Ok(())
+}
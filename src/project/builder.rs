@@ -0,0 +1,194 @@
+use super::{Lang, Project, ProjectKind};
+use crate::component::{php_namespace, snake_case};
+use crate::config::{config_dir, Config};
+use crate::name::{
+    validate_dir_name, validate_module, validate_module_path, validate_name, validate_namespace,
+    validate_package_path,
+};
+use anyhow::anyhow;
+use std::path::PathBuf;
+
+#[derive(Debug, Clone)]
+pub struct ProjectBuilder {
+    name: String,
+    package_name: Option<String>,
+    lang: Option<Lang>,
+    kind: ProjectKind,
+    domain: Option<String>,
+    module: Option<String>,
+    output_dir: Option<PathBuf>,
+    templates_root: Option<PathBuf>,
+    template_set: Option<String>,
+}
+
+impl ProjectBuilder {
+    pub fn new(name: &str) -> ProjectBuilder {
+        ProjectBuilder {
+            name: String::from(name),
+            package_name: None,
+            lang: None,
+            kind: ProjectKind::Executable,
+            domain: None,
+            module: None,
+            output_dir: None,
+            templates_root: None,
+            template_set: None,
+        }
+    }
+
+    pub fn package_name(mut self, package_name: &str) -> ProjectBuilder {
+        self.package_name = Some(String::from(package_name));
+        self
+    }
+
+    pub fn lang(mut self, lang: Lang) -> ProjectBuilder {
+        self.lang = Some(lang);
+        self
+    }
+
+    pub fn kind(mut self, kind: ProjectKind) -> ProjectBuilder {
+        self.kind = kind;
+        self
+    }
+
+    pub fn domain(mut self, domain: &str) -> ProjectBuilder {
+        self.domain = Some(String::from(domain));
+        self
+    }
+
+    pub fn module(mut self, module: &str) -> ProjectBuilder {
+        self.module = Some(String::from(module));
+        self
+    }
+
+    pub fn output_dir(mut self, output_dir: impl Into<PathBuf>) -> ProjectBuilder {
+        self.output_dir = Some(output_dir.into());
+        self
+    }
+
+    pub fn templates_root(mut self, templates_root: impl Into<PathBuf>) -> ProjectBuilder {
+        self.templates_root = Some(templates_root.into());
+        self
+    }
+
+    pub fn template_set(mut self, template_set: &str) -> ProjectBuilder {
+        self.template_set = Some(String::from(template_set));
+        self
+    }
+
+    // The template set from --template, or else the config's, read from the configured templates
+    // directory
+    pub fn config_templates(
+        mut self,
+        config: &Config,
+        template_set: Option<&str>,
+    ) -> ProjectBuilder {
+        if let Some(templates_root) = config.templates() {
+            self = self.templates_root(templates_root);
+        }
+        match template_set.or(config.template.as_deref()) {
+            Some(template_set) => self.template_set(template_set),
+            None => self,
+        }
+    }
+
+    pub fn build(self) -> anyhow::Result<Project> {
+        let lang = match self.lang {
+            Some(lang) => lang,
+            None => return Err(anyhow!("Missing project language!")),
+        };
+        // The name is the directory the project is created in, whatever its package is called
+        validate_dir_name(&self.name)?;
+        let package_name = self.package_name.as_deref().unwrap_or(&self.name);
+        validate_name(package_name, &lang)?;
+
+        if lang == Lang::Asm && self.kind != ProjectKind::Executable {
+            return Err(anyhow!(
+                "Only executable projects are supported for assembly"
+            ));
+        }
+        if lang == Lang::Latex && self.kind != ProjectKind::Executable {
+            return Err(anyhow!(
+                "Only executable (document) projects are supported for LaTeX"
+            ));
+        }
+        if lang == Lang::Terraform && self.kind != ProjectKind::Library {
+            return Err(anyhow!(
+                "Only library (module) projects are supported for Terraform"
+            ));
+        }
+
+        let mut project = Project::with_defaults(&self.name, lang, self.kind, self.domain);
+        project.set_package_name(package_name);
+
+        let templates_root = self
+            .templates_root
+            .unwrap_or_else(|| config_dir().join("templates"));
+        match &self.template_set {
+            Some(template_set) => project.set_template_set(&templates_root, template_set)?,
+            None => {
+                if let Err(error) = project.set_templates_root(&templates_root) {
+                    match lang {
+                        Lang::Custom(_) => {
+                            project.template_dir = Some(templates_root.join(lang.template_subdir()))
+                        }
+                        _ => return Err(error),
+                    }
+                }
+            }
+        }
+
+        if let (Some(overlay_dir), Some(template_dir)) =
+            (self.kind.required_overlay(), &project.template_dir)
+        {
+            if !template_dir.join(overlay_dir).is_dir() {
+                return Err(anyhow!(
+                    "{} projects are not supported by the {} templates",
+                    self.kind.info().label,
+                    lang.name()
+                ));
+            }
+        }
+
+        // The deprecated domain file is read from the template set, so the domain can only fall
+        // back to it once the template directory is resolved
+        let needs_domain = matches!(lang, Lang::Java | Lang::Go | Lang::Scala | Lang::Php);
+        if needs_domain && self.module.is_none() && project.domain.is_none() {
+            project.domain = Some(project.get_default_domain()?);
+        }
+
+        let module = match (lang, &self.module, &project.domain) {
+            (Lang::Go, Some(module), _) => Some(module.clone()),
+            (Lang::Go, None, Some(domain)) => Some(format!("{}/{}", domain, package_name)),
+            (Lang::Python | Lang::Elixir | Lang::Ruby | Lang::Lua, Some(module), _) => {
+                Some(module.clone())
+            }
+            (Lang::Python | Lang::Elixir | Lang::Ruby | Lang::Lua, None, _) => {
+                Some(snake_case(package_name))
+            }
+            (Lang::Scala, Some(module), _) => Some(module.clone()),
+            (Lang::Scala, None, Some(domain)) => {
+                Some(format!("{}.{}", domain, snake_case(package_name)))
+            }
+            (Lang::Php, Some(module), _) => Some(module.clone()),
+            (Lang::Php, None, Some(domain)) => Some(php_namespace(domain, package_name)),
+            _ => None,
+        };
+        match (lang, &module) {
+            (Lang::Go, Some(module)) => validate_module_path(module)?,
+            (Lang::Scala, Some(module)) => validate_package_path(module)?,
+            (Lang::Php, Some(module)) => validate_namespace(module)?,
+            (Lang::Python | Lang::Elixir | Lang::Ruby | Lang::Lua, Some(module)) => {
+                validate_module(module, &lang)?
+            }
+            _ => {}
+        }
+        project.module = module;
+
+        project.project_dir = Some(match self.output_dir {
+            Some(output_dir) => output_dir.join(&self.name),
+            None => PathBuf::from(&self.name),
+        });
+        Ok(project)
+    }
+}
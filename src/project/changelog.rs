@@ -0,0 +1,25 @@
+use super::Project;
+use anyhow::anyhow;
+
+impl Project {
+    pub fn create_changelog(&self) -> anyhow::Result<()> {
+        if !self.changelog {
+            return Ok(());
+        }
+
+        if let (Some(templates_root), Some(project_dir)) =
+            (self.templates_root(), &self.project_dir)
+        {
+            let changelog_dir = templates_root.join("_base").join("changelog");
+            if !changelog_dir.is_dir() {
+                return Err(anyhow!(
+                    "Template directory {} does not exist!",
+                    changelog_dir.display()
+                ));
+            }
+            self.render_template_tree(&changelog_dir, project_dir)
+        } else {
+            Err(anyhow!("Template or project directory not set"))
+        }
+    }
+}
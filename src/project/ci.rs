@@ -0,0 +1,57 @@
+use super::Project;
+use anyhow::anyhow;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+#[derive(Debug, Deserialize, Serialize, Eq, PartialEq, Clone, Copy)]
+pub enum CiProvider {
+    Github,
+    Gitlab,
+    CircleCi,
+    Woodpecker,
+}
+
+impl CiProvider {
+    pub fn template_name(&self) -> &'static str {
+        match self {
+            CiProvider::Github => "github.yml",
+            CiProvider::Gitlab => "gitlab.yml",
+            CiProvider::CircleCi => "circleci.yml",
+            CiProvider::Woodpecker => "woodpecker.yml",
+        }
+    }
+
+    pub fn output_path(&self) -> PathBuf {
+        match self {
+            CiProvider::Github => PathBuf::from(".github/workflows/ci.yml"),
+            CiProvider::Gitlab => PathBuf::from(".gitlab-ci.yml"),
+            CiProvider::CircleCi => PathBuf::from(".circleci/config.yml"),
+            CiProvider::Woodpecker => PathBuf::from(".woodpecker.yml"),
+        }
+    }
+}
+
+impl FromStr for CiProvider {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "github" | "gh" | "github-actions" => Ok(CiProvider::Github),
+            "gitlab" | "gitlab-ci" => Ok(CiProvider::Gitlab),
+            "circleci" | "circle" => Ok(CiProvider::CircleCi),
+            "woodpecker" => Ok(CiProvider::Woodpecker),
+            _ => Err(anyhow!("Unknown CI provider {}", s)),
+        }
+    }
+}
+
+impl Project {
+    pub fn create_ci(&self) -> anyhow::Result<()> {
+        match self.ci {
+            Some(ci) => self
+                .render_template_file(&Path::new("ci").join(ci.template_name()), &ci.output_path()),
+            None => Ok(()),
+        }
+    }
+}
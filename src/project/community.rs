@@ -0,0 +1,25 @@
+use super::Project;
+use anyhow::anyhow;
+
+impl Project {
+    pub fn create_community_files(&self) -> anyhow::Result<()> {
+        if !self.community {
+            return Ok(());
+        }
+
+        if let (Some(templates_root), Some(project_dir)) =
+            (self.templates_root(), &self.project_dir)
+        {
+            let community_dir = templates_root.join("_base").join("community");
+            if !community_dir.is_dir() {
+                return Err(anyhow!(
+                    "Template directory {} does not exist!",
+                    community_dir.display()
+                ));
+            }
+            self.render_template_tree(&community_dir, project_dir)
+        } else {
+            Err(anyhow!("Template or project directory not set"))
+        }
+    }
+}
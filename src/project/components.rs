@@ -0,0 +1,99 @@
+use super::{Project, ProjectKind};
+use crate::component::Component;
+use anyhow::anyhow;
+
+impl Project {
+    pub fn check_benches(&self) -> anyhow::Result<()> {
+        if !self.benches {
+            return Ok(());
+        }
+        if matches!(self.kind, ProjectKind::Embedded | ProjectKind::Kmod) {
+            return Err(anyhow!(
+                "--benches is not supported for embedded and kernel module projects"
+            ));
+        }
+        self.check_component("--benches", "bench")
+    }
+
+    pub fn check_fuzz(&self) -> anyhow::Result<()> {
+        if !self.fuzz {
+            return Ok(());
+        }
+        if !matches!(
+            self.kind,
+            ProjectKind::Library | ProjectKind::Executable | ProjectKind::Daemon | ProjectKind::Ffi
+        ) {
+            return Err(anyhow!(
+                "--fuzz is only supported for library, executable and daemon projects"
+            ));
+        }
+        self.check_component("--fuzz", "fuzz")
+    }
+
+    pub fn check_proptest(&self) -> anyhow::Result<()> {
+        if !self.proptest {
+            return Ok(());
+        }
+        if matches!(self.kind, ProjectKind::Embedded | ProjectKind::Kmod) {
+            return Err(anyhow!(
+                "--proptest is not supported for embedded and kernel module projects"
+            ));
+        }
+        self.check_component("--proptest", "proptest")
+    }
+
+    pub fn create_benches(&self) -> anyhow::Result<()> {
+        if !self.benches {
+            return Ok(());
+        }
+        self.generate_component("bench")
+    }
+
+    pub fn create_fuzz_target(&self) -> anyhow::Result<()> {
+        if !self.fuzz {
+            return Ok(());
+        }
+        self.generate_component("fuzz")
+    }
+
+    pub fn create_property_tests(&self) -> anyhow::Result<()> {
+        if !self.proptest {
+            return Ok(());
+        }
+        self.generate_component("proptest")
+    }
+
+    fn check_component(&self, flag: &str, component: &str) -> anyhow::Result<()> {
+        match &self.template_dir {
+            Some(template_dir) if !template_dir.join("components").join(component).is_dir() => {
+                Err(anyhow!(
+                    "{} is not supported by the {} templates",
+                    flag,
+                    template_dir
+                        .file_name()
+                        .map(|name| name.to_string_lossy())
+                        .unwrap_or_default()
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn generate_component(&self, component: &str) -> anyhow::Result<()> {
+        match (&self.template_dir, &self.project_dir) {
+            (Some(template_dir), Some(project_dir)) => {
+                let mut component = Component::with_template_dir(
+                    component,
+                    &self.package_name,
+                    project_dir,
+                    self.lang,
+                    template_dir,
+                )?;
+                component.set_project_kind(self.kind);
+                component.set_sink(self.sink.clone());
+                component.generate()
+            }
+            _ => Err(anyhow!("Template or project directory not set")),
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use super::Project;
+use crate::name::dns_label;
+use anyhow::anyhow;
+use std::path::Path;
+
+pub const COMPOSE_SERVICES: &[&str] = &["postgres", "mysql", "redis", "mongodb", "rabbitmq"];
+
+impl Project {
+    pub fn add_compose_service(&mut self, service: &str) -> anyhow::Result<()> {
+        if !COMPOSE_SERVICES.contains(&service) {
+            return Err(anyhow!(
+                "Unknown compose service {}. Use one of {}",
+                service,
+                COMPOSE_SERVICES.join(", ")
+            ));
+        }
+        if !self
+            .compose_services
+            .iter()
+            .any(|existing| existing == service)
+        {
+            self.compose_services.push(String::from(service));
+        }
+        Ok(())
+    }
+
+    pub fn create_compose(&self) -> anyhow::Result<()> {
+        if !self.compose {
+            return Ok(());
+        }
+
+        let mut with = serde_json::Map::new();
+        for service in &self.compose_services {
+            with.insert(service.to_owned(), serde_json::Value::from(true));
+        }
+        let mut variables = serde_json::to_value(self)?;
+        variables["service_name"] = serde_json::Value::from(dns_label(&self.package_name));
+        variables["with"] = serde_json::Value::Object(with);
+
+        self.render_template_with(
+            &Path::new("compose").join("docker-compose.yml"),
+            Path::new("docker-compose.yml"),
+            &variables,
+        )?;
+        self.render_template_with(
+            &Path::new("compose").join("env"),
+            Path::new(".env"),
+            &variables,
+        )?;
+        Ok(())
+    }
+}
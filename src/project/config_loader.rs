@@ -0,0 +1,134 @@
+use super::{Lang, Project, ProjectKind};
+use crate::component::{java_package, merge_toml};
+use anyhow::anyhow;
+use std::fs;
+use std::path::Path;
+
+const VIPER: &str = "github.com/spf13/viper@v1.19.0";
+
+impl Project {
+    pub fn check_config_loader(&self) -> anyhow::Result<()> {
+        if !self.config_loader {
+            return Ok(());
+        }
+        match (&self.lang, &self.kind) {
+            (Lang::Rust | Lang::Go, ProjectKind::Daemon | ProjectKind::Grpc)
+            | (Lang::Java, ProjectKind::Grpc) => Ok(()),
+            _ => Err(anyhow!(
+                "--config-loader is only supported for Rust and Go daemons and gRPC servers and Java gRPC servers"
+            )),
+        }
+    }
+
+    pub fn create_config_loader(&self) -> anyhow::Result<()> {
+        if !self.config_loader {
+            return Ok(());
+        }
+        let project_dir = match &self.project_dir {
+            Some(project_dir) => project_dir,
+            None => return Err(anyhow!("Project directory not set")),
+        };
+        let template_dir = Path::new("config-loader");
+
+        match self.lang {
+            Lang::Rust => {
+                let variables = serde_json::to_value(self)?;
+                self.render_template_with(
+                    &template_dir.join("config.rs"),
+                    &Path::new("src").join("config.rs"),
+                    &variables,
+                )?;
+                self.render_template_with(
+                    &template_dir.join("config.toml"),
+                    Path::new("config.toml"),
+                    &variables,
+                )?;
+
+                let manifest_path = project_dir.join("Cargo.toml");
+                let source =
+                    fs::read_to_string(self.template_file(&template_dir.join("Cargo.toml"))?)?
+                        .parse::<toml_edit::Document>()?;
+                self.sink.update(&manifest_path, |manifest| {
+                    let mut manifest = manifest.parse::<toml_edit::Document>()?;
+                    merge_toml(manifest.as_table_mut(), source.as_table());
+                    Ok(manifest.to_string())
+                })?;
+                Ok(())
+            }
+            Lang::Go => {
+                let variables = serde_json::to_value(self)?;
+                self.render_template_with(
+                    &template_dir.join("config.go"),
+                    Path::new("config.go"),
+                    &variables,
+                )?;
+                self.render_template_with(
+                    &template_dir.join("config.yaml"),
+                    Path::new("config.yaml"),
+                    &variables,
+                )?;
+
+                if !self.run_dep_tool("go", "get", &[String::from(VIPER)]) {
+                    let go_mod_path = project_dir.join("go.mod");
+                    self.sink.update(&go_mod_path, |mut go_mod| {
+                        go_mod.push_str(&format!("\nrequire {}\n", VIPER.replace('@', " ")));
+                        Ok(go_mod)
+                    })?;
+                }
+                Ok(())
+            }
+            Lang::Java => self.create_java_config_loader(project_dir),
+            _ => Ok(()),
+        }
+    }
+
+    fn create_java_config_loader(&self, project_dir: &Path) -> anyhow::Result<()> {
+        let template_dir = Path::new("config-loader");
+        let pom_path = project_dir.join("pom.xml");
+        let package = java_package(project_dir)
+            .ok_or_else(|| anyhow!("No groupId in {}", pom_path.display()))?;
+        let source_dir = Path::new("src")
+            .join("main")
+            .join("java")
+            .join(package.replace('.', "/"));
+
+        let mut variables = serde_json::to_value(self)?;
+        variables["package"] = serde_json::Value::from(package.as_str());
+        self.render_template_with(
+            &template_dir.join("AppConfig.java"),
+            &source_dir.join("AppConfig.java"),
+            &variables,
+        )?;
+        self.sink
+            .create_dir_all(&project_dir.join("src").join("main").join("resources"))?;
+        self.render_template_with(
+            &template_dir.join("application.yaml"),
+            &Path::new("src")
+                .join("main")
+                .join("resources")
+                .join("application.yaml"),
+            &variables,
+        )?;
+
+        let dependencies =
+            fs::read_to_string(self.template_file(&template_dir.join("dependencies.xml"))?)?;
+        self.sink.update(&pom_path, |mut pom| {
+            let index = pom
+                .find("  </dependencies>")
+                .ok_or_else(|| anyhow!("No </dependencies> in {}", pom_path.display()))?;
+            pom.insert_str(index, &dependencies);
+            Ok(pom)
+        })?;
+
+        let server_path = project_dir.join(&source_dir).join("GreeterServer.java");
+        if let Ok(server) = self.sink.read_to_string(&server_path) {
+            let server = server.replace(
+                "Integer.parseInt(System.getenv().getOrDefault(\"PORT\", \"50051\"))",
+                "AppConfig.load().getPort()",
+            );
+            self.sink.write(&server_path, server)?;
+            self.sink.updated(&server_path);
+        }
+        Ok(())
+    }
+}
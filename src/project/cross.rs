@@ -0,0 +1,97 @@
+use super::{Lang, Project, ProjectKind};
+use anyhow::anyhow;
+
+impl Project {
+    pub fn check_target(&self) -> anyhow::Result<()> {
+        let target = match &self.target {
+            Some(target) if self.kind != ProjectKind::Embedded => target,
+            _ => return Ok(()),
+        };
+
+        match self.lang {
+            Lang::Rust => Ok(()),
+            Lang::Go if !matches!(
+                self.kind,
+                ProjectKind::Executable
+                    | ProjectKind::Library
+                    | ProjectKind::Daemon
+                    | ProjectKind::Grpc
+                    | ProjectKind::Openapi
+            ) =>
+            {
+                Err(anyhow!(
+                    "--target is only supported for Go executable, library, daemon, gRPC and OpenAPI projects"
+                ))
+            }
+            Lang::Go => match target.split_once('/') {
+                Some((goos, goarch))
+                    if !goos.is_empty() && !goarch.is_empty() && !goarch.contains('/') =>
+                {
+                    Ok(())
+                }
+                _ => Err(anyhow!(
+                    "Go targets are written as GOOS/GOARCH, such as linux/arm64, not {}",
+                    target
+                )),
+            },
+            Lang::C | Lang::Cpp
+                if matches!(
+                    self.kind,
+                    ProjectKind::Executable | ProjectKind::Library | ProjectKind::Daemon
+                ) =>
+            {
+                Ok(())
+            }
+            Lang::C | Lang::Cpp => Err(anyhow!(
+                "--target is only supported for C and C++ executable, library and daemon projects"
+            )),
+            _ => Err(anyhow!(
+                "--target is only supported for Rust, Go, C and C++ projects"
+            )),
+        }
+    }
+
+    pub fn create_cross_config(&self) -> anyhow::Result<()> {
+        let target = match &self.target {
+            Some(target) if self.lang == Lang::Rust && self.kind != ProjectKind::Embedded => target,
+            _ => return Ok(()),
+        };
+        let project_dir = match &self.project_dir {
+            Some(project_dir) => project_dir,
+            None => return Err(anyhow!("Project directory not set")),
+        };
+
+        let mut config = toml_edit::Document::new();
+        let mut build = toml_edit::Table::new();
+        build["target"] = toml_edit::value(target.as_str());
+        config["build"] = toml_edit::Item::Table(build);
+        if let Some(linker) = gnu_linker(target) {
+            let mut target_table = toml_edit::Table::new();
+            target_table["linker"] = toml_edit::value(linker);
+            let mut targets = toml_edit::Table::new();
+            targets.set_implicit(true);
+            targets.insert(target, toml_edit::Item::Table(target_table));
+            config["target"] = toml_edit::Item::Table(targets);
+        }
+
+        let config_path = project_dir.join(".cargo").join("config.toml");
+        self.sink.create_dir_all(&project_dir.join(".cargo"))?;
+        self.sink.write(&config_path, config.to_string())?;
+        self.sink.created(&config_path);
+        Ok(())
+    }
+}
+
+fn gnu_linker(target: &str) -> Option<String> {
+    let parts = target.split('-').collect::<Vec<_>>();
+    let arch = match parts.first()? {
+        arch if arch.starts_with("arm") || arch.starts_with("thumb") => "arm",
+        arch if arch.starts_with("riscv64") => "riscv64",
+        arch => arch,
+    };
+    match parts.as_slice() {
+        [_, _, "windows", "gnu" | "gnullvm"] => Some(format!("{}-w64-mingw32-gcc", arch)),
+        [_, _, "linux", env] => Some(format!("{}-linux-{}-gcc", arch, env)),
+        _ => None,
+    }
+}
@@ -0,0 +1,182 @@
+use super::{Lang, Project, ProjectKind};
+use crate::component::{java_package, merge_toml};
+use anyhow::anyhow;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+const PGX: &str = "github.com/jackc/pgx/v5@v5.7.1";
+
+#[derive(Debug, Deserialize, Serialize, Eq, PartialEq, Clone, Copy)]
+pub enum Database {
+    Postgres,
+}
+
+impl Database {
+    pub fn compose_service(&self) -> &'static str {
+        match self {
+            Database::Postgres => "postgres",
+        }
+    }
+}
+
+impl FromStr for Database {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "postgres" | "postgresql" | "pg" => Ok(Database::Postgres),
+            _ => Err(anyhow!("Unknown database {}. Use postgres", s)),
+        }
+    }
+}
+
+impl Project {
+    pub fn check_db(&self) -> anyhow::Result<()> {
+        if self.db.is_none() {
+            return Ok(());
+        }
+        match (&self.lang, &self.kind) {
+            (Lang::Rust | Lang::Go, ProjectKind::Daemon | ProjectKind::Grpc)
+            | (Lang::Java, ProjectKind::Grpc) => Ok(()),
+            _ => Err(anyhow!(
+                "--db is only supported for Rust and Go daemons and gRPC servers and Java gRPC servers"
+            )),
+        }
+    }
+
+    pub fn create_db(&self) -> anyhow::Result<()> {
+        if self.db.is_none() {
+            return Ok(());
+        }
+        let project_dir = match &self.project_dir {
+            Some(project_dir) => project_dir,
+            None => return Err(anyhow!("Project directory not set")),
+        };
+
+        let mut variables = serde_json::to_value(self)?;
+        let (template_dir, files): (&Path, Vec<(&str, PathBuf)>) = match (&self.lang, &self.kind) {
+            (Lang::Rust, ProjectKind::Daemon) => (
+                Path::new("db/diesel"),
+                vec![
+                    ("db.rs", Path::new("src").join("db.rs")),
+                    (
+                        "migrations/00000000000001_init/up.sql",
+                        PathBuf::from("migrations/00000000000001_init/up.sql"),
+                    ),
+                    (
+                        "migrations/00000000000001_init/down.sql",
+                        PathBuf::from("migrations/00000000000001_init/down.sql"),
+                    ),
+                ],
+            ),
+            (Lang::Rust, _) => (
+                Path::new("db/sqlx"),
+                vec![
+                    ("db.rs", Path::new("src").join("db.rs")),
+                    (
+                        "migrations/0001_init.sql",
+                        PathBuf::from("migrations/0001_init.sql"),
+                    ),
+                ],
+            ),
+            (Lang::Go, _) => (
+                Path::new("db/pgx"),
+                vec![
+                    ("db.go", PathBuf::from("db.go")),
+                    (
+                        "migrations/0001_init.sql",
+                        PathBuf::from("migrations/0001_init.sql"),
+                    ),
+                ],
+            ),
+            (Lang::Java, _) => {
+                let package = java_package(project_dir).ok_or_else(|| {
+                    anyhow!("No groupId in {}", project_dir.join("pom.xml").display())
+                })?;
+                variables["package"] = serde_json::Value::from(package.as_str());
+                (
+                    Path::new("db/flyway"),
+                    vec![
+                        (
+                            "Database.java",
+                            Path::new("src")
+                                .join("main")
+                                .join("java")
+                                .join(package.replace('.', "/"))
+                                .join("Database.java"),
+                        ),
+                        (
+                            "V1__init.sql",
+                            PathBuf::from("src/main/resources/db/migration/V1__init.sql"),
+                        ),
+                    ],
+                )
+            }
+            _ => return Ok(()),
+        };
+
+        for (template, output) in &files {
+            if let Some(parent) = output.parent() {
+                self.sink.create_dir_all(&project_dir.join(parent))?;
+            }
+            self.render_template_with(&template_dir.join(template), output, &variables)?;
+        }
+
+        match self.lang {
+            Lang::Rust => {
+                let manifest_path = project_dir.join("Cargo.toml");
+                let source =
+                    fs::read_to_string(self.template_file(&template_dir.join("Cargo.toml"))?)?
+                        .parse::<toml_edit::Document>()?;
+                self.sink.update(&manifest_path, |manifest| {
+                    let mut manifest = manifest.parse::<toml_edit::Document>()?;
+                    merge_toml(manifest.as_table_mut(), source.as_table());
+                    Ok(manifest.to_string())
+                })?;
+            }
+            Lang::Go if !self.run_dep_tool("go", "get", &[String::from(PGX)]) => {
+                let go_mod_path = project_dir.join("go.mod");
+                self.sink.update(&go_mod_path, |mut go_mod| {
+                    go_mod.push_str(&format!("\nrequire {}\n", PGX.replace('@', " ")));
+                    Ok(go_mod)
+                })?;
+            }
+            Lang::Java => self.wire_java_database(project_dir, template_dir)?,
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn wire_java_database(&self, project_dir: &Path, template_dir: &Path) -> anyhow::Result<()> {
+        let pom_path = project_dir.join("pom.xml");
+        let dependencies =
+            fs::read_to_string(self.template_file(&template_dir.join("dependencies.xml"))?)?;
+        self.sink.update(&pom_path, |mut pom| {
+            let index = pom
+                .find("  </dependencies>")
+                .ok_or_else(|| anyhow!("No </dependencies> in {}", pom_path.display()))?;
+            pom.insert_str(index, &dependencies);
+            Ok(pom)
+        })?;
+
+        let package = java_package(project_dir).unwrap_or_default();
+        let server_path = project_dir
+            .join("src")
+            .join("main")
+            .join("java")
+            .join(package.replace('.', "/"))
+            .join("GreeterServer.java");
+        if let Ok(server) = self.sink.read_to_string(&server_path) {
+            let server = server.replacen(
+                "        Server server = ServerBuilder",
+                "        javax.sql.DataSource dataSource = Database.connect();\n        if (!Database.isHealthy(dataSource)) {\n            throw new IllegalStateException(\"Database is unhealthy\");\n        }\n        Server server = ServerBuilder",
+                1,
+            );
+            self.sink.write(&server_path, server)?;
+            self.sink.updated(&server_path);
+        }
+        Ok(())
+    }
+}
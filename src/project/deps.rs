@@ -0,0 +1,192 @@
+use super::metadata::xml_escape;
+use super::{Lang, Project};
+use anyhow::anyhow;
+use std::process::Command;
+
+impl Project {
+    pub fn check_deps(&self) -> anyhow::Result<()> {
+        if self.deps.is_empty() {
+            return Ok(());
+        }
+        for dep in &self.deps {
+            let valid = match self.lang {
+                Lang::Rust | Lang::Go | Lang::Python => !split_version(dep).0.is_empty(),
+                Lang::Java => maven_coordinates(dep).is_some(),
+                _ => {
+                    return Err(anyhow!(
+                        "--deps is only supported for Rust, Go, Python and Java projects"
+                    ))
+                }
+            };
+            if !valid {
+                return Err(match self.lang {
+                    Lang::Java => anyhow!(
+                        "Java dependencies are written as groupId:artifactId:version, not {}",
+                        dep
+                    ),
+                    _ => anyhow!("Invalid dependency {}", dep),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    pub fn add_deps(&self) -> anyhow::Result<()> {
+        if self.deps.is_empty() {
+            return Ok(());
+        }
+        match self.lang {
+            Lang::Rust => {
+                if !self.run_dep_tool("cargo", "add", &self.deps) {
+                    self.add_cargo_deps()?;
+                }
+                Ok(())
+            }
+            Lang::Go => {
+                if !self.run_dep_tool("go", "get", &self.deps) {
+                    self.add_go_deps()?;
+                }
+                Ok(())
+            }
+            Lang::Python => self.add_python_deps(),
+            Lang::Java => self.add_maven_deps(),
+            _ => Ok(()),
+        }
+    }
+
+    pub(super) fn run_dep_tool(&self, program: &str, command: &str, deps: &[String]) -> bool {
+        if self.sink.is_memory() {
+            return false;
+        }
+        let output = Command::new(program)
+            .arg(command)
+            .args(deps)
+            .current_dir(self.project_dir.as_ref().unwrap())
+            .output();
+
+        match output {
+            Ok(output) => {
+                println!("{}", String::from_utf8_lossy(&output.stdout));
+                println!("{}", String::from_utf8_lossy(&output.stderr));
+                output.status.success()
+            }
+            Err(error) => {
+                println!("{}", error);
+                false
+            }
+        }
+    }
+
+    fn add_cargo_deps(&self) -> anyhow::Result<()> {
+        let manifest_path = self.project_dir.as_ref().unwrap().join("Cargo.toml");
+        self.sink.update(&manifest_path, |manifest| {
+            let mut manifest = manifest.parse::<toml_edit::Document>()?;
+            if !manifest.contains_key("dependencies") {
+                manifest["dependencies"] = toml_edit::table();
+            }
+            for dep in &self.deps {
+                let (name, version) = split_version(dep);
+                manifest["dependencies"][name] = toml_edit::value(version.unwrap_or("*"));
+            }
+            Ok(manifest.to_string())
+        })
+    }
+
+    fn add_go_deps(&self) -> anyhow::Result<()> {
+        let go_mod_path = self.project_dir.as_ref().unwrap().join("go.mod");
+        self.sink.update(&go_mod_path, |mut go_mod| {
+            for dep in &self.deps {
+                match split_version(dep) {
+                    (module, Some(version)) => {
+                        go_mod.push_str(&format!("\nrequire {} {}\n", module, version));
+                    }
+                    (module, None) => println!(
+                        "Could not add {} to go.mod, add it with `go get {}`",
+                        module, module
+                    ),
+                }
+            }
+            Ok(go_mod)
+        })
+    }
+
+    fn add_python_deps(&self) -> anyhow::Result<()> {
+        let pyproject_path = self.project_dir.as_ref().unwrap().join("pyproject.toml");
+        if !self.sink.is_file(&pyproject_path) {
+            return Ok(());
+        }
+        self.sink.update(&pyproject_path, |pyproject| {
+            let mut pyproject = pyproject.parse::<toml_edit::Document>()?;
+            let dependencies = pyproject["project"]["dependencies"]
+                .or_insert(toml_edit::value(toml_edit::Array::new()))
+                .as_array_mut()
+                .ok_or_else(|| anyhow!("project.dependencies in pyproject.toml is not an array"))?;
+            for dep in &self.deps {
+                match split_version(dep) {
+                    (name, Some(version)) => dependencies.push(format!("{}>={}", name, version)),
+                    (name, None) => dependencies.push(name),
+                }
+            }
+            Ok(pyproject.to_string())
+        })
+    }
+
+    fn add_maven_deps(&self) -> anyhow::Result<()> {
+        let pom_path = self.project_dir.as_ref().unwrap().join("pom.xml");
+        if !self.sink.is_file(&pom_path) {
+            return Ok(());
+        }
+        let mut blocks = String::new();
+        for dep in &self.deps {
+            if let Some((group, artifact, version)) = maven_coordinates(dep) {
+                blocks.push_str(&format!(
+                    "    <dependency>\n      <groupId>{}</groupId>\n      <artifactId>{}</artifactId>\n      <version>{}</version>\n    </dependency>\n",
+                    xml_escape(group),
+                    xml_escape(artifact),
+                    xml_escape(version)
+                ));
+            }
+        }
+
+        self.sink.update(&pom_path, |mut pom| {
+            match pom.find("  </dependencies>") {
+                Some(index) => pom.insert_str(index, &blocks),
+                None => {
+                    let index = pom
+                        .rfind("</project>")
+                        .ok_or_else(|| anyhow!("No </project> in {}", pom_path.display()))?;
+                    pom.insert_str(
+                        index,
+                        &format!("  <dependencies>\n{}  </dependencies>\n", blocks),
+                    );
+                }
+            }
+            Ok(pom)
+        })
+    }
+}
+
+fn split_version(dep: &str) -> (&str, Option<&str>) {
+    match dep.split_once('@') {
+        Some((name, version)) => (name, Some(version)),
+        None => (dep, None),
+    }
+}
+
+fn maven_coordinates(dep: &str) -> Option<(&str, &str, &str)> {
+    let (coordinates, version) = match split_version(dep) {
+        (coordinates, Some(version)) => (coordinates, version),
+        (coordinates, None) => coordinates.rsplit_once(':')?,
+    };
+    match coordinates.split_once(':') {
+        Some((group, artifact))
+            if !group.is_empty()
+                && !artifact.is_empty()
+                && !artifact.contains(':')
+                && !version.is_empty() =>
+        {
+            Some((group, artifact, version))
+        }
+        _ => None,
+    }
+}
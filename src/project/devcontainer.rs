@@ -0,0 +1,28 @@
+use super::Project;
+use std::path::Path;
+
+impl Project {
+    pub fn create_devcontainer(&self) -> anyhow::Result<()> {
+        if !self.devcontainer {
+            return Ok(());
+        }
+
+        let template_path = Path::new("devcontainer");
+        let output_path = Path::new(".devcontainer");
+        self.render_template_file(
+            &template_path.join("devcontainer.json"),
+            &output_path.join("devcontainer.json"),
+        )?;
+
+        if self
+            .template_source(&template_path.join("Dockerfile"))
+            .is_some()
+        {
+            self.render_template_file(
+                &template_path.join("Dockerfile"),
+                &output_path.join("Dockerfile"),
+            )?;
+        }
+        Ok(())
+    }
+}
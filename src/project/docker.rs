@@ -0,0 +1,20 @@
+use super::Project;
+use std::path::Path;
+
+impl Project {
+    pub fn create_docker(&self) -> anyhow::Result<()> {
+        if !self.docker {
+            return Ok(());
+        }
+
+        self.render_template_file(
+            &Path::new("docker").join("Dockerfile"),
+            Path::new("Dockerfile"),
+        )?;
+        self.render_template_file(
+            &Path::new("docker").join(".dockerignore"),
+            Path::new(".dockerignore"),
+        )?;
+        Ok(())
+    }
+}
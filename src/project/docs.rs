@@ -0,0 +1,64 @@
+use super::metadata::{json_string, toml_string};
+use super::Project;
+use anyhow::anyhow;
+use std::path::Path;
+
+const MDBOOK_FILES: &[&str] = &[
+    "book.toml",
+    "src/SUMMARY.md",
+    "src/introduction.md",
+    "src/getting-started.md",
+];
+
+const MKDOCS_FILES: &[&str] = &[
+    "mkdocs.yml",
+    "requirements.txt",
+    "docs/index.md",
+    "docs/getting-started.md",
+];
+
+impl Project {
+    pub fn create_docs_dir(&self) -> anyhow::Result<()> {
+        match &self.project_dir {
+            Some(project_dir) => {
+                self.sink.create_dir_all(project_dir)?;
+                self.sink.created_dir(project_dir);
+                Ok(())
+            }
+            None => Err(anyhow!("Project directory not set")),
+        }
+    }
+
+    pub fn create_docs_files(&self) -> anyhow::Result<()> {
+        let (files, quote): (&[&str], fn(&str) -> String) = match self.template_set().as_str() {
+            "mkdocs" => (MKDOCS_FILES, json_string),
+            _ => (MDBOOK_FILES, toml_string),
+        };
+
+        let mut variables = serde_json::to_value(self)?;
+        variables["site_name"] = serde_json::Value::from(quote(&self.name));
+        if let Some(author) = &self.author {
+            variables["site_author"] = serde_json::Value::from(quote(author));
+        }
+        if let Some(description) = &self.description {
+            variables["site_description"] = serde_json::Value::from(quote(description));
+        }
+        if let Some(repository) = &self.repository {
+            variables["site_repository"] = serde_json::Value::from(quote(repository));
+        }
+
+        let template_dir = Path::new("site");
+        for file in files {
+            self.render_template_with(&template_dir.join(file), Path::new(file), &variables)?;
+        }
+
+        self.create_gitignore()?;
+        self.create_task_file()?;
+        self.create_readme()?;
+        self.create_ci()?;
+        self.create_format_files()?;
+        self.create_community_files()?;
+        self.create_changelog()?;
+        Ok(())
+    }
+}
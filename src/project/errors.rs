@@ -0,0 +1,101 @@
+use super::{Lang, Project, ProjectKind};
+use crate::component::merge_toml;
+use anyhow::anyhow;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+#[derive(Debug, Deserialize, Serialize, Eq, PartialEq, Clone, Copy)]
+pub enum ErrorStyle {
+    Anyhow,
+    Eyre,
+    Thiserror,
+}
+
+impl ErrorStyle {
+    fn dir_name(&self) -> &'static str {
+        match self {
+            ErrorStyle::Anyhow => "anyhow",
+            ErrorStyle::Eyre => "eyre",
+            ErrorStyle::Thiserror => "thiserror",
+        }
+    }
+}
+
+impl FromStr for ErrorStyle {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "anyhow" => Ok(ErrorStyle::Anyhow),
+            "eyre" | "color-eyre" => Ok(ErrorStyle::Eyre),
+            "thiserror" => Ok(ErrorStyle::Thiserror),
+            _ => Err(anyhow!("Unknown error style {}", s)),
+        }
+    }
+}
+
+impl Project {
+    pub fn supports_error_style(&self) -> bool {
+        self.lang == Lang::Rust
+            && matches!(self.kind, ProjectKind::Executable | ProjectKind::Library)
+    }
+
+    pub fn check_error_style(&self) -> anyhow::Result<()> {
+        if self.error_style.is_some() && !self.supports_error_style() {
+            return Err(anyhow!(
+                "--errors is only supported for Rust executable and library projects"
+            ));
+        }
+        Ok(())
+    }
+
+    pub fn create_error_module(&self) -> anyhow::Result<()> {
+        let error_style = match self.error_style {
+            Some(error_style) => error_style,
+            None => return Ok(()),
+        };
+        let project_dir = match &self.project_dir {
+            Some(project_dir) => project_dir,
+            None => return Err(anyhow!("Project directory not set")),
+        };
+        let template_dir = Path::new("errors").join(error_style.dir_name());
+
+        self.template(
+            &self.template_file(&template_dir.join("error.rs"))?,
+            &project_dir.join("src").join("error.rs"),
+        )?;
+
+        let manifest_path = project_dir.join("Cargo.toml");
+        let source = fs::read_to_string(self.template_file(&template_dir.join("Cargo.toml"))?)?
+            .parse::<toml_edit::Document>()?;
+        self.sink.update(&manifest_path, |manifest| {
+            let mut manifest = manifest.parse::<toml_edit::Document>()?;
+            merge_toml(manifest.as_table_mut(), source.as_table());
+            Ok(manifest.to_string())
+        })?;
+
+        if self.kind == ProjectKind::Library {
+            let lib_path = project_dir.join("src").join("lib.rs");
+            let lib = match self
+                .sink
+                .read_to_string(&lib_path)
+                .unwrap_or_default()
+                .trim()
+            {
+                "" => String::new(),
+                lib => format!("\n{}\n", lib),
+            };
+            self.sink.write(
+                &lib_path,
+                format!(
+                    "pub mod error;\n\npub use error::{{Error, Result}};\n{}",
+                    lib
+                ),
+            )?;
+            self.sink.updated(&lib_path);
+        }
+        Ok(())
+    }
+}
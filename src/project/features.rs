@@ -0,0 +1,94 @@
+use super::{Lang, Project, ProjectKind};
+use crate::component::merge_toml;
+use anyhow::anyhow;
+use std::fs;
+use std::path::Path;
+
+impl Project {
+    pub fn check_features(&self) -> anyhow::Result<()> {
+        if self.features.is_empty() {
+            return Ok(());
+        }
+        if self.lang != Lang::Rust || self.kind != ProjectKind::Library {
+            return Err(anyhow!(
+                "--features is only supported for Rust library projects"
+            ));
+        }
+        for feature in &self.features {
+            let valid = feature.starts_with(|c: char| c.is_ascii_alphabetic())
+                && feature
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+            if !valid || feature == "default" {
+                return Err(anyhow!("Invalid feature name {}", feature));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn create_features(&self) -> anyhow::Result<()> {
+        if self.features.is_empty() {
+            return Ok(());
+        }
+        let project_dir = match &self.project_dir {
+            Some(project_dir) => project_dir,
+            None => return Err(anyhow!("Project directory not set")),
+        };
+
+        let mut sources = Vec::new();
+        let mut modules = String::new();
+        for feature in &self.features {
+            let module = feature.replace('-', "_");
+            let template = Path::new("features").join(feature);
+            let source = match self.template_source(&template.join("Cargo.toml")) {
+                Some(path) => fs::read_to_string(path)?,
+                None => format!("[features]\n{} = []\n", feature),
+            };
+            sources.push(source.parse::<toml_edit::Document>()?);
+
+            let source_path = match self.template_source(&template.join("module.rs")) {
+                Some(_) => template.join("module.rs"),
+                None => Path::new("features").join("module.rs"),
+            };
+            let mut variables = serde_json::to_value(self)?;
+            variables["feature"] = serde_json::Value::from(feature.as_str());
+            self.render_template_with(
+                &source_path,
+                &Path::new("src").join(format!("{}.rs", module)),
+                &variables,
+            )?;
+            modules.push_str(&format!(
+                "#[cfg(feature = \"{}\")]\npub mod {};\n",
+                feature, module
+            ));
+        }
+
+        let manifest_path = project_dir.join("Cargo.toml");
+        self.sink.update(&manifest_path, |manifest| {
+            let mut manifest = manifest.parse::<toml_edit::Document>()?;
+            if !manifest.contains_key("features") {
+                let mut features = toml_edit::Table::new();
+                features.insert("default", toml_edit::value(toml_edit::Array::new()));
+                manifest["features"] = toml_edit::Item::Table(features);
+            }
+            for source in &sources {
+                merge_toml(manifest.as_table_mut(), source.as_table());
+            }
+            Ok(manifest.to_string())
+        })?;
+
+        let lib_path = project_dir.join("src").join("lib.rs");
+        let lib = match self
+            .sink
+            .read_to_string(&lib_path)
+            .unwrap_or_default()
+            .trim()
+        {
+            "" => String::new(),
+            lib => format!("\n{}\n", lib),
+        };
+        self.sink.write(&lib_path, format!("{}{}", modules, lib))?;
+        self.sink.updated(&lib_path);
+        Ok(())
+    }
+}
@@ -0,0 +1,34 @@
+use super::{Project, ProjectKind};
+use crate::component::merge_toml;
+use anyhow::anyhow;
+use std::fs;
+use std::path::Path;
+
+const FILES: &[&str] = &["cbindgen.toml", "src/lib.rs", "c/main.c"];
+
+impl Project {
+    pub fn create_ffi_files(&self) -> anyhow::Result<()> {
+        if self.kind != ProjectKind::Ffi {
+            return Ok(());
+        }
+
+        let mut variables = serde_json::to_value(self)?;
+        variables["lib_name"] = serde_json::Value::from(self.package_name.replace('-', "_"));
+        for file in FILES {
+            self.render_template_with(Path::new(file), Path::new(file), &variables)?;
+        }
+
+        let manifest_path = match &self.project_dir {
+            Some(project_dir) => project_dir.join("Cargo.toml"),
+            None => return Err(anyhow!("Project directory not set")),
+        };
+        let source = fs::read_to_string(self.template_file(Path::new("Cargo.toml"))?)?
+            .parse::<toml_edit::Document>()?;
+        self.sink.update(&manifest_path, |manifest| {
+            let mut manifest = manifest.parse::<toml_edit::Document>()?;
+            merge_toml(manifest.as_table_mut(), source.as_table());
+            Ok(manifest.to_string())
+        })?;
+        Ok(())
+    }
+}
@@ -0,0 +1,55 @@
+use super::{Lang, Project, ProjectKind};
+use std::path::Path;
+
+impl Project {
+    pub fn create_format_files(&self) -> anyhow::Result<()> {
+        if !self.format.enabled {
+            return Ok(());
+        }
+
+        let template_path = Path::new("format");
+        self.render_template_file(
+            &template_path.join(".editorconfig"),
+            Path::new(".editorconfig"),
+        )?;
+
+        let formatter_config = match self.lang {
+            _ if matches!(
+                self.kind,
+                ProjectKind::Monorepo | ProjectKind::Schema | ProjectKind::Docs
+            ) =>
+            {
+                None
+            }
+            Lang::Rust => Some("rustfmt.toml"),
+            Lang::Go => Some(".golangci.yml"),
+            Lang::Java => Some("checkstyle.xml"),
+            Lang::C
+            | Lang::Cpp
+            | Lang::Asm
+            | Lang::Shell
+            | Lang::Python
+            | Lang::Elixir
+            | Lang::Ruby
+            | Lang::Scala
+            | Lang::Lua
+            | Lang::Php
+            | Lang::Terraform
+            | Lang::Latex
+            | Lang::Custom(_) => None,
+        };
+        if let Some(formatter_config) = formatter_config {
+            if self
+                .template_source(&template_path.join(formatter_config))
+                .is_none()
+            {
+                return Ok(());
+            }
+            self.render_template_file(
+                &template_path.join(formatter_config),
+                Path::new(formatter_config),
+            )?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,34 @@
+use super::{Lang, Project, ProjectKind};
+use crate::component::Component;
+use anyhow::anyhow;
+use std::path::Path;
+
+const SERVICE_NAME: &str = "greeter";
+
+impl Project {
+    pub fn create_grpc_service(&self) -> anyhow::Result<()> {
+        if self.kind != ProjectKind::Grpc {
+            return Ok(());
+        }
+
+        match (&self.template_dir, &self.project_dir) {
+            (Some(template_dir), Some(project_dir)) => {
+                grpc_component(template_dir, project_dir, self.lang, self.kind)?.generate()
+            }
+            _ => Err(anyhow!("Template or project directory not set")),
+        }
+    }
+}
+
+// The component grpc projects are generated with
+pub(crate) fn grpc_component(
+    template_dir: &Path,
+    project_dir: &Path,
+    lang: Lang,
+    kind: ProjectKind,
+) -> anyhow::Result<Component> {
+    let mut component =
+        Component::with_template_dir("grpc", SERVICE_NAME, project_dir, lang, template_dir)?;
+    component.set_project_kind(kind);
+    Ok(component)
+}
@@ -0,0 +1,119 @@
+use super::Project;
+use anyhow::anyhow;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use std::str::FromStr;
+
+#[derive(Debug, Deserialize, Serialize, Eq, PartialEq, Clone, Copy)]
+pub enum HooksKind {
+    PreCommit,
+    Native,
+}
+
+impl FromStr for HooksKind {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pre-commit" | "precommit" => Ok(HooksKind::PreCommit),
+            "native" | "git" => Ok(HooksKind::Native),
+            _ => Err(anyhow!("Unknown hooks kind {}", s)),
+        }
+    }
+}
+
+impl Project {
+    pub fn create_hooks(&self) -> anyhow::Result<()> {
+        let hooks = match self.hooks {
+            Some(hooks) => hooks,
+            None => return Ok(()),
+        };
+        let project_dir = match &self.project_dir {
+            Some(project_dir) => project_dir,
+            None => return Err(anyhow!("Project directory not set")),
+        };
+        let git_initialized = project_dir.join(".git").is_dir() && !self.sink.is_memory();
+
+        match hooks {
+            HooksKind::PreCommit => {
+                self.render_template_file(
+                    &Path::new("hooks").join("pre-commit-config.yaml"),
+                    Path::new(".pre-commit-config.yaml"),
+                )?;
+
+                if git_initialized {
+                    self.run_hook_installer(Command::new("pre-commit").arg("install"))?;
+                }
+            }
+            HooksKind::Native => {
+                let hook_path = Path::new(".githooks").join("pre-commit");
+                self.render_template_file(&Path::new("hooks").join("pre-commit"), &hook_path)?;
+                self.sink.make_executable(&project_dir.join(&hook_path))?;
+
+                if git_initialized {
+                    self.run_hook_installer(
+                        Command::new("git")
+                            .arg("config")
+                            .arg("core.hooksPath")
+                            .arg(".githooks"),
+                    )?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn run_hook_installer(&self, command: &mut Command) -> anyhow::Result<()> {
+        let output = command
+            .current_dir(self.project_dir.as_ref().unwrap())
+            .output();
+
+        match output {
+            Ok(output) if output.status.success() => {
+                println!("Installed git hooks");
+            }
+            Ok(output) => {
+                println!("{}", String::from_utf8_lossy(&output.stderr));
+            }
+            Err(error) => {
+                println!("Could not install git hooks: {}", error);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(unix)]
+pub(crate) fn make_executable(path: &Path) -> anyhow::Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let mut permissions = fs::metadata(path)?.permissions();
+    permissions.set_mode(permissions.mode() | 0o111);
+    fs::set_permissions(path, permissions)?;
+    Ok(())
+}
+
+#[cfg(not(unix))]
+pub(crate) fn make_executable(_path: &Path) -> anyhow::Result<()> {
+    Ok(())
+}
+
+#[cfg(unix)]
+pub(crate) fn copy_executable_bits(from_path: &Path, to_path: &Path) -> anyhow::Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let executable_bits = fs::metadata(from_path)?.permissions().mode() & 0o111;
+    if executable_bits != 0 {
+        let mut permissions = fs::metadata(to_path)?.permissions();
+        permissions.set_mode(permissions.mode() | executable_bits);
+        fs::set_permissions(to_path, permissions)?;
+    }
+    Ok(())
+}
+
+#[cfg(not(unix))]
+pub(crate) fn copy_executable_bits(_from_path: &Path, _to_path: &Path) -> anyhow::Result<()> {
+    Ok(())
+}
@@ -0,0 +1,80 @@
+use super::Project;
+use crate::name::dns_label;
+use anyhow::anyhow;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::str::FromStr;
+
+#[derive(Debug, Deserialize, Serialize, Eq, PartialEq, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum K8s {
+    Manifests,
+    Helm,
+}
+
+impl K8s {
+    pub fn files(&self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            K8s::Manifests => &[
+                ("manifests/deployment.yaml", "k8s/deployment.yaml"),
+                ("manifests/service.yaml", "k8s/service.yaml"),
+            ],
+            K8s::Helm => &[
+                ("helm/Chart.yaml", "charts/{{ k8s_name }}/Chart.yaml"),
+                ("helm/values.yaml", "charts/{{ k8s_name }}/values.yaml"),
+                ("helm/.helmignore", "charts/{{ k8s_name }}/.helmignore"),
+                (
+                    "helm/templates/deployment.yaml",
+                    "charts/{{ k8s_name }}/templates/deployment.yaml",
+                ),
+                (
+                    "helm/templates/service.yaml",
+                    "charts/{{ k8s_name }}/templates/service.yaml",
+                ),
+            ],
+        }
+    }
+
+    pub fn variables() -> &'static [&'static str] {
+        &["k8s_name", "image"]
+    }
+}
+
+impl FromStr for K8s {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "manifests" | "kubectl" | "yaml" => Ok(K8s::Manifests),
+            "helm" | "chart" => Ok(K8s::Helm),
+            _ => Err(anyhow!("Unknown Kubernetes format {}", s)),
+        }
+    }
+}
+
+impl Project {
+    pub fn create_k8s(&self) -> anyhow::Result<()> {
+        let k8s = match self.k8s {
+            Some(k8s) => k8s,
+            None => return Ok(()),
+        };
+
+        let k8s_name = dns_label(&self.package_name);
+        let image = match &self.registry {
+            Some(registry) => format!("{}/{}", registry.trim_end_matches('/'), k8s_name),
+            None => k8s_name.clone(),
+        };
+        let mut variables = serde_json::to_value(self)?;
+        variables["k8s_name"] = serde_json::Value::from(k8s_name);
+        variables["image"] = serde_json::Value::from(image);
+
+        for (template_path, output_path) in k8s.files() {
+            self.render_template_with(
+                &Path::new("k8s").join(template_path),
+                Path::new(output_path),
+                &variables,
+            )?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,45 @@
+use super::Project;
+use std::path::Path;
+
+const FILES: &[&str] = &[
+    "main.tex",
+    "references.bib",
+    "sections/introduction.tex",
+    ".latexmkrc",
+];
+
+impl Project {
+    pub fn create_latex_project(&self) -> anyhow::Result<()> {
+        let mut variables = serde_json::to_value(self)?;
+        variables["latex_title"] = serde_json::Value::from(latex_escape(&self.name));
+        if let Some(author) = &self.author {
+            variables["latex_author"] = serde_json::Value::from(latex_escape(author));
+        }
+        if let Some(description) = &self.description {
+            variables["latex_abstract"] = serde_json::Value::from(latex_escape(description));
+        }
+
+        let template_dir = Path::new("document");
+        for file in FILES {
+            self.render_template_with(&template_dir.join(file), Path::new(file), &variables)?;
+        }
+        Ok(())
+    }
+}
+
+fn latex_escape(value: &str) -> String {
+    let mut escaped = String::new();
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\textbackslash{}"),
+            '~' => escaped.push_str("\\textasciitilde{}"),
+            '^' => escaped.push_str("\\textasciicircum{}"),
+            '&' | '%' | '$' | '#' | '_' | '{' | '}' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
@@ -0,0 +1,144 @@
+use super::Project;
+use crate::languages;
+use anyhow::anyhow;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+impl Project {
+    pub fn add_license_headers(&self) -> anyhow::Result<()> {
+        if !self.license_header {
+            return Ok(());
+        }
+
+        let definition = match languages::definition(self.lang.name()) {
+            Some(definition) => definition,
+            None => return Ok(()),
+        };
+        let comment = match &definition.comment {
+            Some(comment) => comment,
+            None => return Ok(()),
+        };
+        let project_dir = match &self.project_dir {
+            Some(project_dir) => project_dir,
+            None => return Err(anyhow!("Project directory not set")),
+        };
+        let header_template = match self.template_source(Path::new("license-header")) {
+            Some(header_template) => header_template,
+            None => return Err(anyhow!("Template license-header does not exist!")),
+        };
+
+        let mut variables = serde_json::to_value(self)?;
+        variables["year"] = serde_json::Value::from(current_year());
+        let rendered = self.render_source(&header_template, &variables)?;
+        let header: String = rendered
+            .lines()
+            .map(|line| match line {
+                "" => format!("{}\n", comment),
+                line => format!("{} {}\n", comment, line),
+            })
+            .collect();
+
+        for path in self.sink.paths(project_dir)? {
+            let source_file = path
+                .extension()
+                .and_then(|extension| extension.to_str())
+                .is_some_and(|extension| definition.extensions.iter().any(|ext| ext == extension));
+            if !source_file {
+                continue;
+            }
+
+            let contents = self.sink.read_to_string(&path)?;
+            if let Some(contents) = with_header(&header, &contents) {
+                self.sink.write(&path, contents)?;
+                self.sink.updated(&path);
+            }
+        }
+        Ok(())
+    }
+}
+
+// Puts the header first, after a shebang or <?php line that has to stay on the first line.
+// None when the file already starts with it
+fn with_header(header: &str, contents: &str) -> Option<String> {
+    let (prologue, rest) = match contents.split_once('\n') {
+        Some((first, rest)) if first.starts_with("#!") || first.trim() == "<?php" => {
+            (format!("{}\n\n", first), rest.trim_start_matches('\n'))
+        }
+        _ => (String::new(), contents),
+    };
+    if rest.starts_with(header) {
+        return None;
+    }
+    Some(format!("{}{}\n{}", prologue, header, rest))
+}
+
+fn current_year() -> i64 {
+    civil_from_days(unix_time().div_euclid(86400)).0
+}
+
+pub(super) fn unix_time() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs() as i64)
+        .unwrap_or(0)
+}
+
+// days since 1970-01-01 to (year, month, day), after Howard Hinnant's days_from_civil inverse
+pub(super) fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let day_of_era = z.rem_euclid(146097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let year = year_of_era + era * 400;
+    if month_index >= 10 {
+        (year + 1, month_index - 9, day)
+    } else {
+        (year, month_index + 3, day)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::with_header;
+
+    const HEADER: &str = "// SPDX-License-Identifier: MIT\n";
+
+    #[test]
+    fn prepends_the_header() {
+        assert_eq!(
+            with_header(HEADER, "fn main() {}\n").unwrap(),
+            "// SPDX-License-Identifier: MIT\n\nfn main() {}\n"
+        );
+    }
+
+    #[test]
+    fn keeps_php_open_tag_first() {
+        assert_eq!(
+            with_header(HEADER, "<?php\n\ndeclare(strict_types=1);\n").unwrap(),
+            "<?php\n\n// SPDX-License-Identifier: MIT\n\ndeclare(strict_types=1);\n"
+        );
+    }
+
+    #[test]
+    fn keeps_shebang_first() {
+        let header = "# SPDX-License-Identifier: MIT\n";
+        assert_eq!(
+            with_header(header, "#!/usr/bin/env bats\n\n@test \"runs\" {\n}\n").unwrap(),
+            "#!/usr/bin/env bats\n\n# SPDX-License-Identifier: MIT\n\n@test \"runs\" {\n}\n"
+        );
+    }
+
+    #[test]
+    fn skips_files_that_have_the_header() {
+        let php = "<?php\n\n// SPDX-License-Identifier: MIT\n\ndeclare(strict_types=1);\n";
+        assert_eq!(with_header(HEADER, php), None);
+        assert_eq!(
+            with_header(HEADER, "// SPDX-License-Identifier: MIT\n\nfn main() {}\n"),
+            None
+        );
+    }
+}
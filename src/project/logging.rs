@@ -0,0 +1,117 @@
+use super::{Lang, Project, ProjectKind};
+use crate::component::{java_package, merge_toml};
+use anyhow::anyhow;
+use std::fs;
+use std::path::Path;
+
+impl Project {
+    pub fn check_logging(&self) -> anyhow::Result<()> {
+        if !self.logging {
+            return Ok(());
+        }
+        match (&self.lang, &self.kind) {
+            (
+                Lang::Rust | Lang::Go,
+                ProjectKind::Executable | ProjectKind::Daemon | ProjectKind::Grpc,
+            )
+            | (Lang::Java, ProjectKind::Executable) => Ok(()),
+            _ => Err(anyhow!(
+                "--logging is only supported for Rust and Go executables, daemons and gRPC servers and Java executables"
+            )),
+        }
+    }
+
+    pub fn create_logging(&self) -> anyhow::Result<()> {
+        if !self.logging {
+            return Ok(());
+        }
+        let project_dir = match &self.project_dir {
+            Some(project_dir) => project_dir,
+            None => return Err(anyhow!("Project directory not set")),
+        };
+
+        match self.lang {
+            Lang::Rust => {
+                let manifest_path = project_dir.join("Cargo.toml");
+                let source = fs::read_to_string(
+                    self.template_file(&Path::new("logging").join("Cargo.toml"))?,
+                )?
+                .parse::<toml_edit::Document>()?;
+                self.sink.update(&manifest_path, |manifest| {
+                    let mut manifest = manifest.parse::<toml_edit::Document>()?;
+                    merge_toml(manifest.as_table_mut(), source.as_table());
+                    Ok(manifest.to_string())
+                })?;
+                Ok(())
+            }
+            Lang::Java => self.create_java_logging(project_dir),
+            _ => Ok(()),
+        }
+    }
+
+    fn create_java_logging(&self, project_dir: &Path) -> anyhow::Result<()> {
+        let resources_dir = project_dir.join("src").join("main").join("resources");
+        self.sink.create_dir_all(&resources_dir)?;
+        self.template(
+            &self.template_file(&Path::new("logging").join("log4j2.xml"))?,
+            &resources_dir.join("log4j2.xml"),
+        )?;
+
+        let pom_path = project_dir.join("pom.xml");
+        let dependencies = fs::read_to_string(
+            self.template_file(&Path::new("logging").join("dependencies.xml"))?,
+        )?;
+        self.sink.update(&pom_path, |mut pom| {
+            let index = pom
+                .find("  </dependencies>")
+                .ok_or_else(|| anyhow!("No </dependencies> in {}", pom_path.display()))?;
+            pom.insert_str(index, &dependencies);
+            Ok(pom)
+        })?;
+
+        let package = java_package(project_dir)
+            .ok_or_else(|| anyhow!("No groupId in {}", pom_path.display()))?;
+        let app_path = project_dir
+            .join("src")
+            .join("main")
+            .join("java")
+            .join(package.replace('.', "/"))
+            .join("App.java");
+        if !self.sink.is_file(&app_path) {
+            println!("No {} to add a logger to, skipping", app_path.display());
+            return Ok(());
+        }
+        let app = self.sink.read_to_string(&app_path)?;
+        self.sink.write(&app_path, add_java_logger(&app))?;
+        self.sink.updated(&app_path);
+        Ok(())
+    }
+}
+
+fn add_java_logger(source: &str) -> String {
+    let mut lines: Vec<String> = source
+        .lines()
+        .map(|line| line.replace("System.out.println", "LOGGER.info"))
+        .collect();
+
+    if let Some(class_at) = lines.iter().position(|line| line.contains("class App")) {
+        let brace_at = lines[class_at..]
+            .iter()
+            .position(|line| line.contains('{'))
+            .map(|offset| class_at + offset)
+            .unwrap_or(class_at);
+        lines.insert(
+            brace_at + 1,
+            String::from(
+                "    private static final Logger LOGGER = LoggerFactory.getLogger(App.class);\n",
+            ),
+        );
+    }
+    if let Some(package_at) = lines.iter().position(|line| line.starts_with("package ")) {
+        lines.insert(
+            package_at + 1,
+            String::from("\nimport org.slf4j.Logger;\nimport org.slf4j.LoggerFactory;"),
+        );
+    }
+    lines.join("\n") + "\n"
+}
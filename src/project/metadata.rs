@@ -0,0 +1,465 @@
+use super::{Lang, Project};
+
+impl Project {
+    pub fn apply_metadata(&self) -> anyhow::Result<()> {
+        if self.description.is_none()
+            && self.author.is_none()
+            && self.repository.is_none()
+            && self.license.is_none()
+            && self.msrv.is_none()
+        {
+            return Ok(());
+        }
+
+        match self.lang {
+            Lang::Rust => self.update_cargo_manifest(),
+            Lang::Java => self.update_pom(),
+            Lang::Go => self.update_go_mod(),
+            Lang::Python => self.update_pyproject(),
+            Lang::Elixir => self.update_mix_exs(),
+            Lang::Ruby => self.update_gemspec(),
+            Lang::Scala => self.update_build_sbt(),
+            Lang::Lua => self.update_rockspec(),
+            Lang::Php => self.update_composer_json(),
+            Lang::C
+            | Lang::Cpp
+            | Lang::Asm
+            | Lang::Shell
+            | Lang::Terraform
+            | Lang::Latex
+            | Lang::Custom(_) => Ok(()),
+        }
+    }
+
+    fn update_cargo_manifest(&self) -> anyhow::Result<()> {
+        let manifest_path = self.project_dir.as_ref().unwrap().join("Cargo.toml");
+        if !self.sink.is_file(&manifest_path) {
+            return Ok(());
+        }
+
+        let mut fields = Vec::new();
+        if let Some(msrv) = &self.msrv {
+            fields.push(format!("rust-version = {}", toml_string(msrv)));
+        }
+        if let Some(description) = &self.description {
+            fields.push(format!("description = {}", toml_string(description)));
+        }
+        if let Some(author) = &self.author {
+            fields.push(format!("authors = [{}]", toml_string(author)));
+        }
+        if let Some(repository) = &self.repository {
+            fields.push(format!("repository = {}", toml_string(repository)));
+        }
+        if let Some(license) = &self.license {
+            fields.push(format!("license = {}", toml_string(license)));
+        }
+
+        let manifest = self.sink.read_to_string(&manifest_path)?;
+        let mut lines: Vec<String> = manifest.lines().map(String::from).collect();
+        let insert_at = lines
+            .iter()
+            .position(|line| line.starts_with("edition"))
+            .or_else(|| lines.iter().position(|line| line.starts_with("version")))
+            .map(|index| index + 1)
+            .unwrap_or(lines.len());
+        for (offset, field) in fields.into_iter().enumerate() {
+            lines.insert(insert_at + offset, field);
+        }
+
+        self.sink.write(&manifest_path, lines.join("\n") + "\n")?;
+        self.sink.updated(&manifest_path);
+        Ok(())
+    }
+
+    fn update_pyproject(&self) -> anyhow::Result<()> {
+        let pyproject_path = self.project_dir.as_ref().unwrap().join("pyproject.toml");
+        if !self.sink.is_file(&pyproject_path) {
+            return Ok(());
+        }
+
+        let mut fields = Vec::new();
+        if let Some(description) = &self.description {
+            fields.push(format!("description = {}", toml_string(description)));
+        }
+        if let Some(author) = &self.author {
+            fields.push(match author.split_once('<') {
+                Some((name, email)) => format!(
+                    "authors = [{{ name = {}, email = {} }}]",
+                    toml_string(name.trim()),
+                    toml_string(email.trim_end().trim_end_matches('>'))
+                ),
+                None => format!("authors = [{{ name = {} }}]", toml_string(author)),
+            });
+        }
+        if let Some(license) = &self.license {
+            fields.push(format!("license = {}", toml_string(license)));
+        }
+
+        let pyproject = self.sink.read_to_string(&pyproject_path)?;
+        let mut lines: Vec<String> = pyproject.lines().map(String::from).collect();
+        let insert_at = lines
+            .iter()
+            .position(|line| line.starts_with("version"))
+            .map(|index| index + 1)
+            .unwrap_or(lines.len());
+        for (offset, field) in fields.into_iter().enumerate() {
+            lines.insert(insert_at + offset, field);
+        }
+        if let Some(repository) = &self.repository {
+            let insert_at = lines
+                .iter()
+                .position(|line| line.starts_with("[dependency-groups]"))
+                .unwrap_or(lines.len());
+            lines.insert(insert_at, String::new());
+            lines.insert(
+                insert_at,
+                format!("Repository = {}", toml_string(repository)),
+            );
+            lines.insert(insert_at, String::from("[project.urls]"));
+        }
+
+        self.sink.write(&pyproject_path, lines.join("\n") + "\n")?;
+        self.sink.updated(&pyproject_path);
+        Ok(())
+    }
+
+    fn update_mix_exs(&self) -> anyhow::Result<()> {
+        let mix_path = self.project_dir.as_ref().unwrap().join("mix.exs");
+        if !self.sink.is_file(&mix_path) {
+            return Ok(());
+        }
+
+        let mut fields = String::new();
+        if let Some(description) = &self.description {
+            fields.push_str(&format!(
+                "      description: {},\n",
+                interpolated_string(description)
+            ));
+        }
+        if let Some(repository) = &self.repository {
+            fields.push_str(&format!(
+                "      source_url: {},\n",
+                interpolated_string(repository)
+            ));
+        }
+        if let Some(license) = &self.license {
+            fields.push_str(&format!(
+                "      package: [licenses: [{}]],\n",
+                interpolated_string(license)
+            ));
+        }
+
+        let mut mix = self.sink.read_to_string(&mix_path)?;
+        if let Some(index) = mix.find("      deps: deps()") {
+            mix.insert_str(index, &fields);
+        }
+        if let Some(author) = &self.author {
+            mix.insert_str(0, &format!("# Author: {}\n", author));
+        }
+
+        self.sink.write(&mix_path, mix)?;
+        self.sink.updated(&mix_path);
+        Ok(())
+    }
+
+    fn update_gemspec(&self) -> anyhow::Result<()> {
+        let gemspec_path = self
+            .project_dir
+            .as_ref()
+            .unwrap()
+            .join(format!("{}.gemspec", self.package_name));
+        if !self.sink.is_file(&gemspec_path) {
+            return Ok(());
+        }
+
+        let mut fields = Vec::new();
+        if let Some(description) = &self.description {
+            fields.push(format!(
+                "  spec.description = {}",
+                interpolated_string(description)
+            ));
+        }
+        if let Some(author) = &self.author {
+            fields.push(format!(
+                "  spec.authors = [{}]",
+                interpolated_string(author)
+            ));
+        }
+        if let Some(repository) = &self.repository {
+            fields.push(format!(
+                "  spec.homepage = {}",
+                interpolated_string(repository)
+            ));
+        }
+        if let Some(license) = &self.license {
+            fields.push(format!("  spec.license = {}", interpolated_string(license)));
+        }
+
+        let gemspec = self.sink.read_to_string(&gemspec_path)?;
+        let mut lines: Vec<String> = gemspec.lines().map(String::from).collect();
+        let insert_at = lines
+            .iter()
+            .position(|line| line.trim_start().starts_with("spec.summary"))
+            .map(|index| index + 1)
+            .unwrap_or(lines.len());
+        for (offset, field) in fields.into_iter().enumerate() {
+            lines.insert(insert_at + offset, field);
+        }
+
+        self.sink.write(&gemspec_path, lines.join("\n") + "\n")?;
+        self.sink.updated(&gemspec_path);
+        Ok(())
+    }
+
+    fn update_build_sbt(&self) -> anyhow::Result<()> {
+        let build_path = self.project_dir.as_ref().unwrap().join("build.sbt");
+        if !self.sink.is_file(&build_path) {
+            return Ok(());
+        }
+
+        let mut settings = String::new();
+        if let Some(description) = &self.description {
+            settings.push_str(&format!(
+                "ThisBuild / description := {}\n",
+                quoted_string(description)
+            ));
+        }
+        if let Some(author) = &self.author {
+            settings.push_str(&format!(
+                "ThisBuild / organizationName := {}\n",
+                quoted_string(author)
+            ));
+        }
+        if let Some(repository) = &self.repository {
+            settings.push_str(&format!(
+                "ThisBuild / homepage := Some(url({}))\n",
+                quoted_string(repository)
+            ));
+        }
+        if let Some(license) = &self.license {
+            settings.push_str(&format!(
+                "ThisBuild / licenses := List({} -> url({}))\n",
+                quoted_string(license),
+                quoted_string(&format!("https://spdx.org/licenses/{}.html", license))
+            ));
+        }
+
+        let mut build = self.sink.read_to_string(&build_path)?;
+        let anchor = match build.find("\nlazy val") {
+            Some(index) => index,
+            None => build.len(),
+        };
+        build.insert_str(anchor, &settings);
+
+        self.sink.write(&build_path, build)?;
+        self.sink.updated(&build_path);
+        Ok(())
+    }
+
+    fn update_composer_json(&self) -> anyhow::Result<()> {
+        let composer_path = self.project_dir.as_ref().unwrap().join("composer.json");
+        if !self.sink.is_file(&composer_path) {
+            return Ok(());
+        }
+
+        let mut fields = Vec::new();
+        if let Some(repository) = &self.repository {
+            fields.push(format!("    \"homepage\": {},", json_string(repository)));
+        }
+        if let Some(author) = &self.author {
+            fields.push(match author.split_once('<') {
+                Some((name, email)) => format!(
+                    "    \"authors\": [{{ \"name\": {}, \"email\": {} }}],",
+                    json_string(name.trim()),
+                    json_string(email.trim_end().trim_end_matches('>'))
+                ),
+                None => format!(
+                    "    \"authors\": [{{ \"name\": {} }}],",
+                    json_string(author)
+                ),
+            });
+        }
+
+        let composer = self.sink.read_to_string(&composer_path)?;
+        let mut lines: Vec<String> = composer.lines().map(String::from).collect();
+        let license_at = lines
+            .iter()
+            .position(|line| line.trim_start().starts_with("\"license\""));
+        if let Some(description) = &self.description {
+            if let Some(line) = lines
+                .iter_mut()
+                .find(|line| line.trim_start().starts_with("\"description\""))
+            {
+                *line = format!("    \"description\": {},", json_string(description));
+            }
+        }
+        let insert_at = license_at.map(|index| index + 1).unwrap_or(1);
+        for (offset, field) in fields.into_iter().enumerate() {
+            lines.insert(insert_at + offset, field);
+        }
+
+        self.sink.write(&composer_path, lines.join("\n") + "\n")?;
+        self.sink.updated(&composer_path);
+        Ok(())
+    }
+
+    fn update_rockspec(&self) -> anyhow::Result<()> {
+        let rockspec_path = self
+            .project_dir
+            .as_ref()
+            .unwrap()
+            .join(format!("{}-dev-1.rockspec", self.package_name));
+        if !self.sink.is_file(&rockspec_path) {
+            return Ok(());
+        }
+
+        let mut fields = Vec::new();
+        if let Some(description) = &self.description {
+            fields.push(format!("   detailed = {},", quoted_string(description)));
+        }
+        if let Some(repository) = &self.repository {
+            fields.push(format!("   homepage = {},", quoted_string(repository)));
+        }
+        if let Some(license) = &self.license {
+            fields.push(format!("   license = {},", quoted_string(license)));
+        }
+        if let Some(author) = &self.author {
+            fields.push(format!("   maintainer = {},", quoted_string(author)));
+        }
+
+        let rockspec = self.sink.read_to_string(&rockspec_path)?;
+        let mut lines: Vec<String> = rockspec.lines().map(String::from).collect();
+        if let Some(repository) = &self.repository {
+            if let Some(line) = lines
+                .iter_mut()
+                .find(|line| line.trim_start().starts_with("url = "))
+            {
+                *line = format!(
+                    "   url = {},",
+                    quoted_string(&format!("git+{}", repository))
+                );
+            }
+        }
+        let insert_at = lines
+            .iter()
+            .position(|line| line.trim_start().starts_with("summary = "))
+            .map(|index| index + 1)
+            .unwrap_or(lines.len());
+        for (offset, field) in fields.into_iter().enumerate() {
+            lines.insert(insert_at + offset, field);
+        }
+
+        self.sink.write(&rockspec_path, lines.join("\n") + "\n")?;
+        self.sink.updated(&rockspec_path);
+        Ok(())
+    }
+
+    fn update_pom(&self) -> anyhow::Result<()> {
+        let pom_path = self.project_dir.as_ref().unwrap().join("pom.xml");
+        if !self.sink.is_file(&pom_path) {
+            return Ok(());
+        }
+
+        let mut pom = self.sink.read_to_string(&pom_path)?;
+        let mut metadata = String::new();
+        if let Some(description) = &self.description {
+            metadata.push_str(&format!(
+                "\n  <description>{}</description>",
+                xml_escape(description)
+            ));
+        }
+        if let Some(author) = &self.author {
+            metadata.push_str(&format!(
+                "\n  <developers>\n    <developer>\n      <name>{}</name>\n    </developer>\n  </developers>",
+                xml_escape(author)
+            ));
+        }
+        if let Some(repository) = &self.repository {
+            metadata.push_str(&format!(
+                "\n  <scm>\n    <url>{}</url>\n  </scm>",
+                xml_escape(repository)
+            ));
+            if let Some(start) = pom.find("<url>http://www.example.com</url>") {
+                pom.replace_range(
+                    start..start + "<url>http://www.example.com</url>".len(),
+                    &format!("<url>{}</url>", xml_escape(repository)),
+                );
+            }
+        }
+        if let Some(license) = &self.license {
+            metadata.push_str(&format!(
+                "\n  <licenses>\n    <license>\n      <name>{}</name>\n    </license>\n  </licenses>",
+                xml_escape(license)
+            ));
+        }
+
+        let anchor = match pom.find("</url>") {
+            Some(index) => index + "</url>".len(),
+            None => match pom.find("</name>") {
+                Some(index) => index + "</name>".len(),
+                None => return Ok(()),
+            },
+        };
+        pom.insert_str(anchor, &metadata);
+
+        self.sink.write(&pom_path, pom)?;
+        self.sink.updated(&pom_path);
+        Ok(())
+    }
+
+    fn update_go_mod(&self) -> anyhow::Result<()> {
+        let go_mod_path = self.project_dir.as_ref().unwrap().join("go.mod");
+        if !self.sink.is_file(&go_mod_path) {
+            return Ok(());
+        }
+
+        let mut header = String::new();
+        if let Some(description) = &self.description {
+            header.push_str(&format!("// {}\n", description));
+        }
+        if let Some(author) = &self.author {
+            header.push_str(&format!("// Author: {}\n", author));
+        }
+        if let Some(repository) = &self.repository {
+            header.push_str(&format!("// Repository: {}\n", repository));
+        }
+        if let Some(license) = &self.license {
+            header.push_str(&format!("// License: {}\n", license));
+        }
+
+        let go_mod = self.sink.read_to_string(&go_mod_path)?;
+        self.sink.write(&go_mod_path, header + "\n" + &go_mod)?;
+        self.sink.updated(&go_mod_path);
+        Ok(())
+    }
+}
+
+pub(super) fn toml_string(value: &str) -> String {
+    toml::Value::String(value.to_string()).to_string()
+}
+
+fn interpolated_string(value: &str) -> String {
+    format!(
+        "\"{}\"",
+        value
+            .replace('\\', "\\\\")
+            .replace('"', "\\\"")
+            .replace("#{", "\\#{")
+    )
+}
+
+pub(super) fn json_string(value: &str) -> String {
+    serde_json::Value::from(value).to_string()
+}
+
+fn quoted_string(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+pub(super) fn xml_escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -0,0 +1,135 @@
+use super::{Lang, Project, ProjectBuilder, ProjectKind};
+use crate::name::validate_name;
+use anyhow::anyhow;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Member {
+    pub lang: Lang,
+    pub name: String,
+    pub kind: ProjectKind,
+    pub path: PathBuf,
+}
+
+impl Member {
+    pub fn parse(spec: &str, default_lang: Lang) -> anyhow::Result<Member> {
+        let parts: Vec<&str> = spec.split(':').collect();
+        let (lang, name, kind) = match parts.as_slice() {
+            [name] => (default_lang, *name, ProjectKind::Executable),
+            [lang, name] => (Lang::from_str(lang)?, *name, ProjectKind::Executable),
+            [lang, name, kind] => (Lang::from_str(lang)?, *name, ProjectKind::from_str(kind)?),
+            _ => return Err(anyhow!("Invalid member {}. Use [lang:]name[:kind]", spec)),
+        };
+
+        validate_name(name, &lang)?;
+
+        let layout = match kind {
+            ProjectKind::Executable
+            | ProjectKind::Daemon
+            | ProjectKind::Grpc
+            | ProjectKind::Openapi
+            | ProjectKind::Sdl
+            | ProjectKind::Qt
+            | ProjectKind::Embedded
+            | ProjectKind::Baremetal
+            | ProjectKind::Wasm
+            | ProjectKind::Datasci
+            | ProjectKind::Love => "services",
+            ProjectKind::Library | ProjectKind::Kmod | ProjectKind::Ffi | ProjectKind::Schema => {
+                "packages"
+            }
+            ProjectKind::Docs => "docs",
+            ProjectKind::Monorepo => {
+                return Err(anyhow!("Member {} cannot itself be a monorepo", name))
+            }
+        };
+        Ok(Member {
+            lang,
+            name: name.to_string(),
+            kind,
+            path: PathBuf::from(layout).join(name),
+        })
+    }
+}
+
+impl Project {
+    pub fn members(&self) -> &Vec<Member> {
+        &self.members
+    }
+
+    pub fn add_member(&mut self, member: Member) {
+        self.members.push(member);
+    }
+
+    // Builds every member up front, so a member that can't be generated fails before the
+    // monorepo's own files are written
+    pub fn check_members(&self) -> anyhow::Result<()> {
+        for member in &self.members {
+            self.member_project(member)?;
+        }
+        Ok(())
+    }
+
+    fn member_project(&self, member: &Member) -> anyhow::Result<Project> {
+        let (project_dir, templates_root) = match (&self.project_dir, &self.template_dir) {
+            (Some(project_dir), Some(template_dir)) => match template_dir.parent() {
+                Some(templates_root) => (project_dir, templates_root),
+                None => return Err(anyhow!("Template directory has no parent")),
+            },
+            _ => return Err(anyhow!("Template or project directory not set")),
+        };
+
+        let mut builder = ProjectBuilder::new(&member.name)
+            .lang(member.lang)
+            .kind(member.kind)
+            .templates_root(templates_root);
+        if let Some(domain) = &self.domain {
+            builder = builder.domain(domain);
+        }
+        if let Some(layout_dir) = member.path.parent() {
+            builder = builder.output_dir(project_dir.join(layout_dir));
+        }
+        let mut project = builder.build()?;
+        project.author = self.author.clone();
+        project.repository = self.repository.clone();
+        project.license = self.license.clone();
+        project.license_header = self.license_header;
+        project.task_runner = self.task_runner;
+        project.format = self.format.clone();
+        project.sink = self.sink.clone();
+        Ok(project)
+    }
+
+    pub fn create_monorepo_dir(&self) -> anyhow::Result<()> {
+        match &self.project_dir {
+            Some(project_dir) => {
+                self.sink.create_dir_all(project_dir)?;
+                self.sink.created_dir(project_dir);
+                Ok(())
+            }
+            None => Err(anyhow!("Project directory not set")),
+        }
+    }
+
+    pub fn create_monorepo_files(&self) -> anyhow::Result<()> {
+        self.create_gitignore()?;
+        self.create_task_file()?;
+        self.create_readme()?;
+        self.create_ci()?;
+        self.create_format_files()?;
+        self.create_community_files()?;
+        self.create_changelog()?;
+
+        for member in &self.members {
+            if !self.sink.is_memory() {
+                println!("Generating {} {}", member.path.display(), member.name);
+            }
+            let project = self.member_project(member)?;
+            project.create_language_project()?;
+            project.create_files()?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,19 @@
+use super::Project;
+use std::path::Path;
+
+impl Project {
+    pub fn create_nix(&self) -> anyhow::Result<()> {
+        if !self.nix {
+            return Ok(());
+        }
+
+        self.render_template_file(&Path::new("nix").join("flake.nix"), Path::new("flake.nix"))?;
+
+        if let Some(project_dir) = &self.project_dir {
+            self.sink
+                .write(&project_dir.join(".envrc"), "use flake\n")?;
+            self.sink.created(&project_dir.join(".envrc"));
+        }
+        Ok(())
+    }
+}
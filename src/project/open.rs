@@ -0,0 +1,36 @@
+use super::Project;
+use anyhow::anyhow;
+use std::process::Command;
+
+impl Project {
+    pub fn open_in_editor(&self) -> anyhow::Result<()> {
+        let editor = match &self.editor {
+            Some(editor) => editor.clone(),
+            None => return Ok(()),
+        };
+        let project_dir = match &self.project_dir {
+            Some(project_dir) => project_dir.display().to_string(),
+            None => return Err(anyhow!("Project directory not set")),
+        };
+
+        let mut args: Vec<String> = editor.split_whitespace().map(String::from).collect();
+        if args.is_empty() {
+            return Err(anyhow!("Editor command is empty"));
+        }
+        if args.iter().any(|arg| arg.contains("{dir}")) {
+            args = args
+                .iter()
+                .map(|arg| arg.replace("{dir}", &project_dir))
+                .collect();
+        } else {
+            args.push(project_dir);
+        }
+
+        println!("Opening {}", args.join(" "));
+        let status = Command::new(&args[0]).args(&args[1..]).status()?;
+        if !status.success() {
+            return Err(anyhow!("Editor exited with {}", status));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,414 @@
+use super::{Lang, Project, ProjectKind};
+use crate::component::{camel_case, java_package, merge_toml, pascal_case, snake_case};
+use anyhow::anyhow;
+use serde::Serialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+const ECHO: &str = "github.com/labstack/echo/v4@v4.12.0";
+const METHODS: &[&str] = &["get", "put", "post", "delete", "options", "head", "patch"];
+
+#[derive(Debug, Serialize)]
+struct Operation {
+    name: String,
+    pascal_name: String,
+    camel_name: String,
+    method: String,
+    upper_method: String,
+    path: String,
+    colon_path: String,
+    summary: Option<String>,
+    params: Vec<Param>,
+    has_body: bool,
+}
+
+#[derive(Debug, Serialize)]
+struct Param {
+    name: String,
+    snake_name: String,
+    camel_name: String,
+}
+
+#[derive(Debug, Serialize)]
+struct PathItem {
+    path: String,
+    operations: Vec<Operation>,
+}
+
+struct StubFiles {
+    routes: PathBuf,
+    handlers: PathBuf,
+    handler: Option<&'static str>,
+}
+
+impl Project {
+    pub fn check_openapi(&self) -> anyhow::Result<()> {
+        match (&self.kind, &self.spec_path) {
+            (ProjectKind::Openapi, None) => Err(anyhow!(
+                "OpenAPI projects require a spec! Use --spec option."
+            )),
+            (ProjectKind::Openapi, Some(spec_path)) => match self.lang {
+                Lang::Rust | Lang::Go | Lang::Java => load_spec(spec_path).map(|_| ()),
+                _ => Err(anyhow!(
+                    "OpenAPI projects are only supported for Rust, Go and Java"
+                )),
+            },
+            (_, Some(_)) => Err(anyhow!("--spec is only supported for openapi projects")),
+            (_, None) => Ok(()),
+        }
+    }
+
+    pub fn create_openapi_service(&self) -> anyhow::Result<()> {
+        if self.kind != ProjectKind::Openapi {
+            return Ok(());
+        }
+        let (project_dir, spec_path, spec) = match (&self.project_dir, &self.spec_path, &self.spec)
+        {
+            (Some(project_dir), Some(spec_path), Some(spec)) => (project_dir, spec_path, spec),
+            _ => return Err(anyhow!("Project directory or spec not set")),
+        };
+
+        let project_spec_path = project_dir.join(spec);
+        if *spec_path != project_spec_path {
+            self.sink.copy(spec_path, &project_spec_path)?;
+            self.sink.created(&project_spec_path);
+        }
+
+        let template_dir = Path::new("openapi").join("stubs");
+        match self.lang {
+            Lang::Rust => {
+                let manifest_path = project_dir.join("Cargo.toml");
+                let source =
+                    fs::read_to_string(self.template_file(&template_dir.join("Cargo.toml"))?)?
+                        .parse::<toml_edit::Document>()?;
+                self.sink.update(&manifest_path, |manifest| {
+                    let mut manifest = manifest.parse::<toml_edit::Document>()?;
+                    merge_toml(manifest.as_table_mut(), source.as_table());
+                    Ok(manifest.to_string())
+                })?;
+            }
+            Lang::Go if !self.run_dep_tool("go", "get", &[String::from(ECHO)]) => {
+                let go_mod_path = project_dir.join("go.mod");
+                self.sink.update(&go_mod_path, |mut go_mod| {
+                    go_mod.push_str(&format!("\nrequire {}\n", ECHO.replace('@', " ")));
+                    Ok(go_mod)
+                })?;
+            }
+            Lang::Java => {
+                let pom_path = project_dir.join("pom.xml");
+                let dependencies = fs::read_to_string(
+                    self.template_file(&template_dir.join("dependencies.xml"))?,
+                )?;
+                self.sink.update(&pom_path, |mut pom| {
+                    let index = pom
+                        .find("  </dependencies>")
+                        .ok_or_else(|| anyhow!("No </dependencies> in {}", pom_path.display()))?;
+                    pom.insert_str(index, &dependencies);
+                    Ok(pom)
+                })?;
+
+                let variables = self.openapi_variables(project_dir)?;
+                self.render_template_with(
+                    &template_dir.join("Application.java"),
+                    &java_source_dir(project_dir)?.join("Application.java"),
+                    &variables,
+                )?;
+            }
+            _ => {}
+        }
+
+        self.update_openapi_stubs()
+    }
+
+    // Rewrites the routes from the spec and adds a handler stub for every operation that does not
+    // have one yet
+    pub fn update_openapi_stubs(&self) -> anyhow::Result<()> {
+        let project_dir = match &self.project_dir {
+            Some(project_dir) => project_dir,
+            None => return Err(anyhow!("Project directory not set")),
+        };
+        let spec_path = match &self.spec_path {
+            Some(spec_path) => spec_path,
+            None => return Err(anyhow!("{} has no OpenAPI spec", project_dir.display())),
+        };
+        let source_dir = match self.lang {
+            Lang::Java => java_source_dir(project_dir)?,
+            _ => PathBuf::new(),
+        };
+        let files = stub_files(self.lang, &source_dir)
+            .ok_or_else(|| anyhow!("No OpenAPI stubs for {:?} projects", self.lang))?;
+
+        let paths = load_spec(spec_path)?;
+        let template_dir = Path::new("openapi").join("stubs");
+        let routes_template = template_dir.join(file_name(&files.routes));
+        let handlers_template = template_dir.join(file_name(&files.handlers));
+
+        let mut variables = self.openapi_variables(project_dir)?;
+        variables["paths"] = serde_json::to_value(&paths)?;
+        variables["path_params"] = serde_json::Value::from(
+            operations(&paths).any(|operation| !operation.params.is_empty()),
+        );
+        variables["request_bodies"] =
+            serde_json::Value::from(operations(&paths).any(|operation| operation.has_body));
+        variables["operations"] = serde_json::to_value(operations(&paths).collect::<Vec<_>>())?;
+        self.render_template_with(&routes_template, &files.routes, &variables)?;
+
+        let handlers_path = project_dir.join(&files.handlers);
+        let mut handlers = match self.sink.read_to_string(&handlers_path) {
+            Ok(handlers) => handlers,
+            Err(_) => self.render_openapi_template(&handlers_template, &variables)?,
+        };
+        let existing = handlers.clone();
+        if let Some(handler) = files.handler {
+            for operation in operations(&paths) {
+                let signature = match self.lang {
+                    Lang::Go => format!(") {}(", operation.pascal_name),
+                    _ => format!("fn {}(", operation.name),
+                };
+                if !existing.contains(&signature) {
+                    variables["operation"] = serde_json::to_value(operation)?;
+                    handlers.push_str(
+                        &self.render_openapi_template(&template_dir.join(handler), &variables)?,
+                    );
+                }
+            }
+        }
+
+        if !self.sink.exists(&handlers_path) {
+            self.sink.write(&handlers_path, handlers)?;
+            self.sink.created(&handlers_path);
+        } else if handlers != existing {
+            self.sink.write(&handlers_path, handlers)?;
+            self.sink.updated(&handlers_path);
+        }
+
+        if self.lang == Lang::Rust && !self.sink.is_memory() {
+            self.rustfmt_stubs(project_dir, &[&files.routes, &files.handlers])?;
+        }
+        Ok(())
+    }
+
+    // Handler signatures with several extractors only fit once rustfmt has
+    // wrapped them
+    fn rustfmt_stubs(&self, project_dir: &Path, files: &[&Path]) -> anyhow::Result<()> {
+        let manifest =
+            fs::read_to_string(project_dir.join("Cargo.toml"))?.parse::<toml_edit::Document>()?;
+        let edition = manifest["package"]["edition"].as_str().unwrap_or("2021");
+        let output = Command::new("rustfmt")
+            .arg("--edition")
+            .arg(edition)
+            .args(files)
+            .current_dir(project_dir)
+            .output();
+        if !output.is_ok_and(|output| output.status.success()) {
+            println!("Could not run rustfmt, format the OpenAPI stubs with `cargo fmt`");
+        }
+        Ok(())
+    }
+
+    fn openapi_variables(&self, project_dir: &Path) -> anyhow::Result<serde_json::Value> {
+        let mut variables = serde_json::to_value(self)?;
+        if self.lang == Lang::Java {
+            let package = java_package(project_dir).ok_or_else(|| {
+                anyhow!("No groupId in {}", project_dir.join("pom.xml").display())
+            })?;
+            variables["package"] = serde_json::Value::from(package.as_str());
+        }
+        Ok(variables)
+    }
+
+    fn render_openapi_template(
+        &self,
+        template_path: &Path,
+        variables: &serde_json::Value,
+    ) -> anyhow::Result<String> {
+        self.render_source(&self.template_file(template_path)?, variables)
+    }
+}
+
+// source_dir is where Java sources go, the other languages ignore it
+fn stub_files(lang: Lang, source_dir: &Path) -> Option<StubFiles> {
+    match lang {
+        Lang::Rust => Some(StubFiles {
+            routes: Path::new("src").join("routes.rs"),
+            handlers: Path::new("src").join("handlers.rs"),
+            handler: Some("handler.rs"),
+        }),
+        Lang::Go => Some(StubFiles {
+            routes: PathBuf::from("routes.go"),
+            handlers: PathBuf::from("handlers.go"),
+            handler: Some("handler.go"),
+        }),
+        Lang::Java => Some(StubFiles {
+            routes: source_dir.join("Api.java"),
+            handlers: source_dir.join("ApiController.java"),
+            handler: None,
+        }),
+        _ => None,
+    }
+}
+
+// The sources an openapi project gets besides its spec, relative to the project directory
+pub(crate) fn openapi_files(lang: Lang, source_dir: &Path) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    if lang == Lang::Java {
+        files.push(source_dir.join("Application.java"));
+    }
+    if let Some(stub_files) = stub_files(lang, source_dir) {
+        files.push(stub_files.routes);
+        files.push(stub_files.handlers);
+    }
+    files
+}
+
+fn operations(paths: &[PathItem]) -> impl Iterator<Item = &Operation> {
+    paths.iter().flat_map(|path| &path.operations)
+}
+
+fn java_source_dir(project_dir: &Path) -> anyhow::Result<PathBuf> {
+    let package = java_package(project_dir)
+        .ok_or_else(|| anyhow!("No groupId in {}", project_dir.join("pom.xml").display()))?;
+    Ok(Path::new("src")
+        .join("main")
+        .join("java")
+        .join(package.replace('.', "/")))
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default()
+}
+
+fn load_spec(spec_path: &Path) -> anyhow::Result<Vec<PathItem>> {
+    let contents = fs::read_to_string(spec_path)
+        .map_err(|error| anyhow!("Could not read {}: {}", spec_path.display(), error))?;
+    let spec: serde_yaml::Value = serde_yaml::from_str(&contents)
+        .map_err(|error| anyhow!("Could not parse {}: {}", spec_path.display(), error))?;
+
+    let version = spec.get("openapi").and_then(|version| version.as_str());
+    if !version.is_some_and(|version| version.starts_with("3.")) {
+        return Err(anyhow!(
+            "{} is not an OpenAPI 3 document",
+            spec_path.display()
+        ));
+    }
+
+    let mut paths = Vec::new();
+    let mut names: Vec<String> = Vec::new();
+    let spec_paths = spec
+        .get("paths")
+        .and_then(|paths| paths.as_mapping())
+        .ok_or_else(|| anyhow!("{} has no paths", spec_path.display()))?;
+    for (path, item) in spec_paths {
+        let path = match path.as_str() {
+            Some(path) if path.starts_with('/') => path,
+            _ => {
+                return Err(anyhow!(
+                    "Invalid path {:?} in {}",
+                    path,
+                    spec_path.display()
+                ))
+            }
+        };
+
+        let mut operations = Vec::new();
+        for method in METHODS {
+            let operation = match item.get(*method) {
+                Some(operation) => operation,
+                None => continue,
+            };
+            let name = match operation.get("operationId").and_then(|id| id.as_str()) {
+                Some(id) => snake_case(&identifier(id)),
+                None => derived_name(method, path),
+            };
+            if name.is_empty() || name.starts_with(|c: char| c.is_ascii_digit()) {
+                return Err(anyhow!(
+                    "Cannot name the handler for {} {}, set an operationId",
+                    method.to_uppercase(),
+                    path
+                ));
+            }
+            if names.contains(&name) {
+                return Err(anyhow!(
+                    "Two operations in {} are named {}",
+                    spec_path.display(),
+                    name
+                ));
+            }
+            names.push(name.clone());
+
+            operations.push(Operation {
+                pascal_name: pascal_case(&name),
+                camel_name: camel_case(&name),
+                method: method.to_string(),
+                upper_method: method.to_uppercase(),
+                path: path.to_string(),
+                colon_path: colon_path(path),
+                summary: operation
+                    .get("summary")
+                    .and_then(|summary| summary.as_str())
+                    .and_then(|summary| summary.lines().next())
+                    .map(|summary| summary.trim().to_string()),
+                params: path_params(path)
+                    .into_iter()
+                    .map(|param| Param {
+                        snake_name: snake_case(&identifier(&param)),
+                        camel_name: camel_case(&identifier(&param)),
+                        name: param,
+                    })
+                    .collect(),
+                has_body: operation.get("requestBody").is_some(),
+                name,
+            });
+        }
+        if !operations.is_empty() {
+            paths.push(PathItem {
+                path: path.to_string(),
+                operations,
+            });
+        }
+    }
+
+    if paths.is_empty() {
+        return Err(anyhow!("{} has no operations", spec_path.display()));
+    }
+    Ok(paths)
+}
+
+fn identifier(name: &str) -> String {
+    name.chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect()
+}
+
+fn derived_name(method: &str, path: &str) -> String {
+    let mut name = String::from(method);
+    for segment in path.split('/').filter(|segment| !segment.is_empty()) {
+        match segment.strip_prefix('{').and_then(|s| s.strip_suffix('}')) {
+            Some(param) => name.push_str(&format!("_by_{}", param)),
+            None => name.push_str(&format!("_{}", segment)),
+        }
+    }
+    snake_case(&identifier(&name))
+}
+
+fn path_params(path: &str) -> Vec<String> {
+    path.split('/')
+        .filter_map(|segment| segment.strip_prefix('{')?.strip_suffix('}'))
+        .map(|param| param.to_string())
+        .collect()
+}
+
+fn colon_path(path: &str) -> String {
+    path.split('/')
+        .map(
+            |segment| match segment.strip_prefix('{').and_then(|s| s.strip_suffix('}')) {
+                Some(param) => format!(":{}", param),
+                None => segment.to_string(),
+            },
+        )
+        .collect::<Vec<_>>()
+        .join("/")
+}
@@ -0,0 +1,201 @@
+use super::license_header::{civil_from_days, unix_time};
+use super::{Lang, Project, ProjectKind};
+use crate::component::pascal_case;
+use anyhow::anyhow;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+const WEEKDAYS: [&str; 7] = ["Thu", "Fri", "Sat", "Sun", "Mon", "Tue", "Wed"];
+const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+#[derive(Debug, Deserialize, Serialize, Eq, PartialEq, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum Packaging {
+    Deb,
+    Rpm,
+    Arch,
+    Homebrew,
+}
+
+impl Packaging {
+    pub fn template_dir(&self) -> &'static str {
+        match self {
+            Packaging::Deb => "deb",
+            Packaging::Rpm => "rpm",
+            Packaging::Arch => "arch",
+            Packaging::Homebrew => "homebrew",
+        }
+    }
+
+    pub fn output_dir(&self) -> PathBuf {
+        match self {
+            Packaging::Deb => PathBuf::from("debian"),
+            Packaging::Rpm | Packaging::Arch => PathBuf::new(),
+            Packaging::Homebrew => PathBuf::from("Formula"),
+        }
+    }
+
+    pub fn files(&self) -> &'static [&'static str] {
+        match self {
+            Packaging::Deb => &["control", "rules", "changelog", "install", "source/format"],
+            Packaging::Rpm => &["{{ package_name }}.spec"],
+            Packaging::Arch => &["PKGBUILD"],
+            Packaging::Homebrew => &["{{ dist_name }}.rb"],
+        }
+    }
+
+    pub fn variables() -> &'static [&'static str] {
+        &[
+            "dist_name",
+            "formula_class",
+            "maintainer",
+            "date",
+            "short_date",
+        ]
+    }
+
+    pub fn executables(&self) -> &'static [&'static str] {
+        match self {
+            Packaging::Deb => &["rules"],
+            Packaging::Rpm | Packaging::Arch | Packaging::Homebrew => &[],
+        }
+    }
+}
+
+impl FromStr for Packaging {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "deb" | "debian" => Ok(Packaging::Deb),
+            "rpm" | "fedora" => Ok(Packaging::Rpm),
+            "arch" | "pkgbuild" => Ok(Packaging::Arch),
+            "homebrew" | "brew" => Ok(Packaging::Homebrew),
+            _ => Err(anyhow!("Unknown packaging format {}", s)),
+        }
+    }
+}
+
+impl Project {
+    pub fn check_packaging(&self) -> anyhow::Result<()> {
+        for packaging in &self.packaging {
+            let supported = match (packaging, self.kind) {
+                (
+                    _,
+                    ProjectKind::Executable
+                    | ProjectKind::Daemon
+                    | ProjectKind::Grpc
+                    | ProjectKind::Openapi
+                    | ProjectKind::Sdl
+                    | ProjectKind::Qt,
+                ) => true,
+                (Packaging::Homebrew, _) => false,
+                (_, ProjectKind::Library) => matches!(self.lang, Lang::C | Lang::Cpp),
+                (
+                    _,
+                    ProjectKind::Embedded
+                    | ProjectKind::Baremetal
+                    | ProjectKind::Kmod
+                    | ProjectKind::Wasm
+                    | ProjectKind::Datasci
+                    | ProjectKind::Love
+                    | ProjectKind::Ffi
+                    | ProjectKind::Schema
+                    | ProjectKind::Docs
+                    | ProjectKind::Monorepo,
+                ) => false,
+            };
+            if !supported {
+                return Err(match packaging {
+                    Packaging::Homebrew => {
+                        anyhow!("--packaging homebrew is only supported for executable projects")
+                    }
+                    _ => anyhow!(
+                        "--packaging is only supported for executable projects and C/C++ libraries"
+                    ),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    pub fn create_packaging(&self) -> anyhow::Result<()> {
+        let mut variables = serde_json::to_value(self)?;
+        variables["dist_name"] = serde_json::Value::from(dist_name(&self.package_name));
+        variables["formula_class"] = serde_json::Value::from(pascal_case(
+            &dist_name(&self.package_name).replace(['.', '+'], "-"),
+        ));
+        variables["maintainer"] = serde_json::Value::from(self.maintainer());
+        variables["date"] = serde_json::Value::from(rfc2822_date(unix_time()));
+        variables["short_date"] = serde_json::Value::from(short_date(unix_time()));
+
+        for packaging in &self.packaging {
+            let template_dir = Path::new("packaging").join(packaging.template_dir());
+            for file in packaging.files() {
+                let to_path = self.render_template_with(
+                    &template_dir.join(file),
+                    &packaging.output_dir().join(file),
+                    &variables,
+                )?;
+                if packaging.executables().contains(file) {
+                    self.sink.make_executable(&to_path)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn maintainer(&self) -> String {
+        match &self.author {
+            Some(author) if author.contains('<') => author.to_owned(),
+            Some(author) => format!("{} <{}@localhost>", author, dist_name(&self.package_name)),
+            None => format!(
+                "{} maintainers <{}@localhost>",
+                self.package_name,
+                dist_name(&self.package_name)
+            ),
+        }
+    }
+}
+
+fn dist_name(package_name: &str) -> String {
+    package_name
+        .to_lowercase()
+        .chars()
+        .map(|c| match c {
+            'a'..='z' | '0'..='9' | '+' | '-' | '.' => c,
+            _ => '-',
+        })
+        .collect()
+}
+
+fn rfc2822_date(time: i64) -> String {
+    let days = time.div_euclid(86400);
+    let seconds = time.rem_euclid(86400);
+    let (year, month, day) = civil_from_days(days);
+    format!(
+        "{}, {:02} {} {} {:02}:{:02}:{:02} +0000",
+        WEEKDAYS[days.rem_euclid(7) as usize],
+        day,
+        MONTHS[(month - 1) as usize],
+        year,
+        seconds / 3600,
+        seconds % 3600 / 60,
+        seconds % 60
+    )
+}
+
+fn short_date(time: i64) -> String {
+    let days = time.div_euclid(86400);
+    let (year, month, day) = civil_from_days(days);
+    format!(
+        "{} {} {:02} {}",
+        WEEKDAYS[days.rem_euclid(7) as usize],
+        MONTHS[(month - 1) as usize],
+        day,
+        year
+    )
+}
@@ -0,0 +1,129 @@
+use super::Project;
+use crate::journal;
+use crate::manifest::{PostGenerateHook, TemplateManifest};
+use anyhow::anyhow;
+use handlebars::Handlebars;
+use serde::{Deserialize, Serialize};
+use std::io::{self, BufRead, IsTerminal, Write};
+use std::process::Command;
+use std::str::FromStr;
+
+#[derive(Debug, Deserialize, Serialize, Eq, PartialEq, Clone, Copy, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum HookPolicy {
+    #[default]
+    Prompt,
+    Allow,
+    Deny,
+}
+
+impl FromStr for HookPolicy {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "prompt" | "ask" => Ok(HookPolicy::Prompt),
+            "allow" | "yes" => Ok(HookPolicy::Allow),
+            "deny" | "no" => Ok(HookPolicy::Deny),
+            _ => Err(anyhow!("Unknown post-generate policy {}", s)),
+        }
+    }
+}
+
+impl Project {
+    pub fn template_manifest(&self) -> anyhow::Result<TemplateManifest> {
+        match &self.template_dir {
+            Some(template_dir) => TemplateManifest::load(template_dir),
+            None => Err(anyhow!("Template directory not set")),
+        }
+    }
+
+    pub fn run_post_generate_hooks(&self) -> anyhow::Result<()> {
+        let manifest = self.template_manifest()?;
+        for hook in &manifest.post_generate {
+            self.run_post_generate_hook(hook)?;
+        }
+        Ok(())
+    }
+
+    fn run_post_generate_hook(&self, hook: &PostGenerateHook) -> anyhow::Result<()> {
+        let project_dir = match &self.project_dir {
+            Some(project_dir) => project_dir,
+            None => return Err(anyhow!("Project directory not set")),
+        };
+
+        let mut handlebars = Handlebars::new();
+        handlebars.register_escape_fn(handlebars::no_escape);
+        let command = &hook.run;
+        let working_dir = match &hook.working_dir {
+            Some(working_dir) => project_dir.join(handlebars.render_template(working_dir, &self)?),
+            None => project_dir.clone(),
+        };
+
+        if !self.hook_allowed(command)? {
+            println!("Skipped post-generate hook `{}`", command);
+            return Ok(());
+        }
+
+        println!("Running `{}` in {}", command, working_dir.display());
+        let output = Command::new("sh")
+            .arg("-c")
+            .arg(command)
+            .envs(self.hook_env()?)
+            .current_dir(&working_dir)
+            .output()?;
+        journal::record_side_effect(&format!("ran `{}` in {}", command, working_dir.display()))?;
+        println!("{}", String::from_utf8_lossy(&output.stdout));
+        println!("{}", String::from_utf8_lossy(&output.stderr));
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            Err(anyhow!(
+                "Post-generate hook `{}` exited with {}",
+                command,
+                output.status
+            ))
+        }
+    }
+
+    // Project values reach hooks as GEN_NAME, GEN_KIND, ... rather than being rendered into the
+    // command, since they can come from a .genrc or batch spec and must never become shell code
+    fn hook_env(&self) -> anyhow::Result<Vec<(String, String)>> {
+        let variables = match serde_json::to_value(self)? {
+            serde_json::Value::Object(variables) => variables,
+            _ => return Ok(Vec::new()),
+        };
+
+        let mut env = Vec::new();
+        for (name, value) in variables {
+            let value = match value {
+                serde_json::Value::String(value) => value,
+                serde_json::Value::Bool(value) => value.to_string(),
+                serde_json::Value::Number(value) => value.to_string(),
+                _ => continue,
+            };
+            env.push((format!("GEN_{}", name.to_uppercase()), value));
+        }
+        Ok(env)
+    }
+
+    // Only the user's config or --post-generate can skip the prompt, never the template set
+    fn hook_allowed(&self, command: &str) -> anyhow::Result<bool> {
+        match self.hook_policy {
+            HookPolicy::Allow => Ok(true),
+            HookPolicy::Deny => Ok(false),
+            HookPolicy::Prompt => {
+                if !io::stdin().is_terminal() {
+                    return Ok(false);
+                }
+
+                print!("Run post-generate hook `{}`? [y/N] ", command);
+                io::stdout().flush()?;
+                let mut answer = String::new();
+                io::stdin().lock().read_line(&mut answer)?;
+                Ok(matches!(answer.trim(), "y" | "Y" | "yes"))
+            }
+        }
+    }
+}
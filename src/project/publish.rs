@@ -0,0 +1,97 @@
+use super::Project;
+use crate::forge::{self, ForgeConfig, RepositoryRequest};
+use crate::journal;
+use crate::vcs::{self, Vcs, VcsKind};
+use anyhow::anyhow;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct PublishOptions {
+    pub forge: String,
+    pub owner: Option<String>,
+    pub private: bool,
+    #[serde(skip)]
+    pub settings: ForgeConfig,
+}
+
+impl Project {
+    pub fn vcs_kind(&self) -> Option<VcsKind> {
+        match self.vcs {
+            Some(vcs) => Some(vcs),
+            None if self.publish.is_some() || self.remote.is_some() => Some(VcsKind::Git),
+            None => None,
+        }
+    }
+
+    pub fn vcs_backend(&self) -> Box<dyn Vcs> {
+        vcs::backend(self.vcs_kind().unwrap_or(VcsKind::None), &self.git_settings)
+    }
+
+    pub fn init_vcs(&self) -> anyhow::Result<()> {
+        match &self.project_dir {
+            Some(project_dir) => self.vcs_backend().init(project_dir),
+            None => Err(anyhow!("Project directory not set")),
+        }
+    }
+
+    pub fn commit_initial(&self) -> anyhow::Result<()> {
+        match &self.project_dir {
+            Some(project_dir) => self.vcs_backend().commit_all(project_dir, "Initial commit"),
+            None => Err(anyhow!("Project directory not set")),
+        }
+    }
+
+    pub fn publish(&self) -> anyhow::Result<()> {
+        let options = match &self.publish {
+            Some(options) => options,
+            None => return Ok(()),
+        };
+        let project_dir = match &self.project_dir {
+            Some(project_dir) => project_dir,
+            None => return Err(anyhow!("Project directory not set")),
+        };
+
+        let forge = forge::connect(&options.forge, &options.settings)?;
+        let repository = forge.create_repository(&RepositoryRequest {
+            name: self.package_name.clone(),
+            description: self.description.clone(),
+            owner: options.owner.clone(),
+            private: options.private,
+        })?;
+        println!(
+            "Published repository {} on {}",
+            repository.full_name,
+            forge.name()
+        );
+        journal::record_side_effect(&format!(
+            "created repository {} on {}",
+            repository.full_name,
+            forge.name()
+        ))?;
+
+        let vcs = self.vcs_backend();
+        vcs.add_remote(project_dir, "origin", &repository.url)?;
+        vcs.push(project_dir, "origin")?;
+        journal::record_side_effect(&format!("pushed to {}", repository.url))
+    }
+
+    pub fn connect_remote(&self) -> anyhow::Result<()> {
+        let url = match &self.remote {
+            Some(url) => url,
+            None => return Ok(()),
+        };
+        let project_dir = match &self.project_dir {
+            Some(project_dir) => project_dir,
+            None => return Err(anyhow!("Project directory not set")),
+        };
+
+        let vcs = self.vcs_backend();
+        vcs.add_remote(project_dir, "origin", url)?;
+        if self.push {
+            vcs.push(project_dir, "origin")?;
+            journal::record_side_effect(&format!("pushed to {}", url))
+        } else {
+            vcs.set_upstream(project_dir, "origin")
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use super::{Lang, Project, ProjectKind};
+use crate::git;
+use crate::record::{template_hash, ProjectRecord};
+use anyhow::anyhow;
+use std::path::{Path, PathBuf};
+
+// Template variables written to .gen.toml. The rest of Project holds local settings (editor, git
+// signing, publish and hook policy, ...) that must not be committed with the project.
+const RECORDED_VARIABLES: &[&str] = &[
+    "name",
+    "package_name",
+    "bin_name",
+    "lang",
+    "kind",
+    "domain",
+    "module",
+    "description",
+    "author",
+    "repository",
+    "license",
+    "license_header",
+    "task_runner",
+    "ci",
+    "docker",
+    "compose",
+    "compose_services",
+    "packaging",
+    "k8s",
+    "registry",
+    "target",
+    "devcontainer",
+    "nix",
+    "hooks",
+    "format",
+    "community",
+    "changelog",
+    "benches",
+    "fuzz",
+    "proptest",
+    "edition",
+    "msrv",
+    "features",
+    "deps",
+    "async_runtime",
+    "error_style",
+    "logging",
+    "config_loader",
+    "db",
+    "spec",
+    "vcs",
+    "members",
+];
+
+fn recorded_variables(variables: toml::Table) -> toml::Table {
+    variables
+        .into_iter()
+        .filter(|(key, _)| RECORDED_VARIABLES.contains(&key.as_str()))
+        .collect()
+}
+
+impl Project {
+    pub fn record(&self) -> anyhow::Result<ProjectRecord> {
+        let template_dir = match &self.template_dir {
+            Some(template_dir) => template_dir,
+            None => return Err(anyhow!("Template directory not set")),
+        };
+
+        Ok(ProjectRecord {
+            gen_version: String::from(env!("CARGO_PKG_VERSION")),
+            template_set: template_dir
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or_default(),
+            template_hash: template_hash(template_dir)?,
+            template_commit: git::run(template_dir, &["rev-parse", "HEAD"]).ok(),
+            base_tree: None,
+            lang: self.lang,
+            kind: self.kind,
+            variables: recorded_variables(toml::Table::try_from(self)?),
+        })
+    }
+
+    pub fn write_record(&self) -> anyhow::Result<()> {
+        match &self.project_dir {
+            Some(project_dir) => self.record()?.save(project_dir),
+            None => Err(anyhow!("Project directory not set")),
+        }
+    }
+
+    pub fn from_record(
+        record: &ProjectRecord,
+        templates_root: &Path,
+        project_dir: PathBuf,
+    ) -> anyhow::Result<Project> {
+        Project::from_variables(
+            recorded_variables(record.variables.clone()),
+            record.template_dir(templates_root),
+            project_dir,
+        )
+    }
+
+    pub fn from_variables(
+        variables: toml::Table,
+        template_dir: PathBuf,
+        project_dir: PathBuf,
+    ) -> anyhow::Result<Project> {
+        let name = variables
+            .get("name")
+            .and_then(|name| name.as_str())
+            .unwrap_or_default();
+        let mut defaults = Project::default_variables(name)?;
+        defaults.extend(variables);
+
+        let mut project: Project = toml::Value::Table(defaults).try_into()?;
+        project.template_dir = Some(template_dir);
+        project.spec_path = project.spec.as_ref().map(|spec| project_dir.join(spec));
+        project.project_dir = Some(project_dir);
+        Ok(project)
+    }
+
+    fn default_variables(name: &str) -> anyhow::Result<toml::Table> {
+        let project = Project::with_defaults(name, Lang::Rust, ProjectKind::Executable, None);
+        let mut variables = toml::Table::try_from(project)?;
+        variables.remove("project_dir");
+        variables.remove("template_dir");
+        variables.remove("spec_path");
+        Ok(variables)
+    }
+}
@@ -0,0 +1,20 @@
+use super::{Project, Sink};
+use anyhow::anyhow;
+use std::path::PathBuf;
+
+impl Project {
+    // Renders the project without writing anything, returning its files relative to the project
+    // directory. External tools don't run, so the files they create (Cargo.toml from cargo new,
+    // pom.xml from mvn, go.mod, mix.exs) aren't among them
+    pub fn render_in_memory(&self) -> anyhow::Result<Vec<(PathBuf, Vec<u8>)>> {
+        let project_dir = match &self.project_dir {
+            Some(project_dir) => project_dir,
+            None => return Err(anyhow!("Project directory not set")),
+        };
+        let mut rendered = self.clone();
+        rendered.set_sink(Sink::memory());
+        rendered.create_language_project()?;
+        rendered.create_files()?;
+        rendered.sink.files(project_dir)
+    }
+}
@@ -0,0 +1,88 @@
+use super::{Lang, Project, ProjectKind};
+use crate::component::merge_toml;
+use anyhow::anyhow;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+#[derive(Debug, Deserialize, Serialize, Eq, PartialEq, Clone, Copy)]
+pub enum AsyncRuntime {
+    Tokio,
+    AsyncStd,
+    Smol,
+    None,
+}
+
+impl AsyncRuntime {
+    fn dir_name(&self) -> &'static str {
+        match self {
+            AsyncRuntime::Tokio => "tokio",
+            AsyncRuntime::AsyncStd => "async-std",
+            AsyncRuntime::Smol => "smol",
+            AsyncRuntime::None => "none",
+        }
+    }
+}
+
+impl FromStr for AsyncRuntime {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "tokio" => Ok(AsyncRuntime::Tokio),
+            "async-std" | "async_std" => Ok(AsyncRuntime::AsyncStd),
+            "smol" => Ok(AsyncRuntime::Smol),
+            "none" => Ok(AsyncRuntime::None),
+            _ => Err(anyhow!("Unknown async runtime {}", s)),
+        }
+    }
+}
+
+impl Project {
+    pub fn check_async_runtime(&self) -> anyhow::Result<()> {
+        if self.async_runtime.is_none() {
+            return Ok(());
+        }
+        if self.lang != Lang::Rust {
+            return Err(anyhow!("--async is only supported for Rust projects"));
+        }
+        // The async main replaces src/main.rs, which the other kinds with one generate for
+        // themselves (grpc and openapi servers already run on tokio)
+        if self.kind != ProjectKind::Executable {
+            return Err(anyhow!(
+                "--async is not supported for {} projects, only for bin (executable) projects",
+                self.kind.name()
+            ));
+        }
+        Ok(())
+    }
+
+    pub fn create_async_main(&self) -> anyhow::Result<()> {
+        let runtime = match self.async_runtime {
+            Some(runtime) => runtime,
+            None => return Ok(()),
+        };
+        let project_dir = match &self.project_dir {
+            Some(project_dir) => project_dir,
+            None => return Err(anyhow!("Project directory not set")),
+        };
+        let template_dir = Path::new("async").join(runtime.dir_name());
+
+        let main_path = project_dir.join("src").join("main.rs");
+        self.template(
+            &self.template_file(&template_dir.join("main.rs"))?,
+            &main_path,
+        )?;
+
+        let manifest_path = project_dir.join("Cargo.toml");
+        let source = fs::read_to_string(self.template_file(&template_dir.join("Cargo.toml"))?)?
+            .parse::<toml_edit::Document>()?;
+        self.sink.update(&manifest_path, |manifest| {
+            let mut manifest = manifest.parse::<toml_edit::Document>()?;
+            merge_toml(manifest.as_table_mut(), source.as_table());
+            Ok(manifest.to_string())
+        })?;
+        Ok(())
+    }
+}
@@ -0,0 +1,50 @@
+use super::Project;
+use crate::component::{pascal_case, snake_case};
+use anyhow::anyhow;
+use std::path::Path;
+
+const CODEGEN_LANGS: &[&str] = &["go", "java", "python", "typescript", "rust"];
+
+impl Project {
+    pub fn create_schema_dir(&self) -> anyhow::Result<()> {
+        match &self.project_dir {
+            Some(project_dir) => {
+                self.sink.create_dir_all(project_dir)?;
+                self.sink.created_dir(project_dir);
+                Ok(())
+            }
+            None => Err(anyhow!("Project directory not set")),
+        }
+    }
+
+    pub fn create_schema_files(&self) -> anyhow::Result<()> {
+        let mut variables = serde_json::to_value(self)?;
+        variables["snake_name"] = serde_json::Value::from(snake_case(&self.package_name));
+        variables["pascal_name"] = serde_json::Value::from(pascal_case(&self.package_name));
+
+        for file in ["buf.yaml", "buf.gen.yaml"] {
+            self.render_template_with(Path::new(file), Path::new(file), &variables)?;
+        }
+        for lang in CODEGEN_LANGS {
+            let file = Path::new("codegen").join(format!("{}.yaml", lang));
+            self.render_template_with(&file, &file, &variables)?;
+        }
+        self.render_template_with(
+            &Path::new("proto").join("service.proto"),
+            &Path::new("proto")
+                .join("{{ snake_name }}")
+                .join("v1")
+                .join("{{ snake_name }}.proto"),
+            &variables,
+        )?;
+
+        self.create_gitignore()?;
+        self.create_task_file()?;
+        self.create_readme()?;
+        self.create_ci()?;
+        self.create_format_files()?;
+        self.create_community_files()?;
+        self.create_changelog()?;
+        Ok(())
+    }
+}
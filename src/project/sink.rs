@@ -0,0 +1,172 @@
+use super::copy_file;
+use super::hooks::{copy_executable_bits, make_executable};
+use crate::walk::{collect_files, VCS_DIRS};
+use anyhow::anyhow;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+type Files = Arc<Mutex<BTreeMap<PathBuf, Vec<u8>>>>;
+
+// Where generated files end up. Memory keeps them in a map instead of writing them, which is
+// how diff and upgrade render a project without touching disk. External tools can't write
+// into the map, so generation skips them in memory and the files they create aren't rendered
+#[derive(Debug, Clone, Default)]
+pub enum Sink {
+    #[default]
+    Disk,
+    Memory(Files),
+}
+
+impl Sink {
+    pub fn memory() -> Sink {
+        Sink::Memory(Files::default())
+    }
+
+    pub fn is_memory(&self) -> bool {
+        matches!(self, Sink::Memory(_))
+    }
+
+    pub fn write(&self, path: &Path, contents: impl AsRef<[u8]>) -> anyhow::Result<()> {
+        match self {
+            Sink::Disk => Ok(fs::write(path, contents)?),
+            Sink::Memory(files) => {
+                lock(files)?.insert(path.to_path_buf(), contents.as_ref().to_vec());
+                Ok(())
+            }
+        }
+    }
+
+    pub fn read_to_string(&self, path: &Path) -> anyhow::Result<String> {
+        match self {
+            Sink::Disk => Ok(fs::read_to_string(path)?),
+            Sink::Memory(files) => match lock(files)?.get(path) {
+                Some(contents) => Ok(String::from_utf8(contents.clone())?),
+                None => Err(anyhow!("{} does not exist", path.display())),
+            },
+        }
+    }
+
+    // Rewrites a file generation created. In memory a missing file is one an external tool
+    // would have created, so the edit is skipped
+    pub fn update(
+        &self,
+        path: &Path,
+        edit: impl FnOnce(String) -> anyhow::Result<String>,
+    ) -> anyhow::Result<()> {
+        if self.is_memory() && !self.is_file(path) {
+            return Ok(());
+        }
+        let contents = edit(self.read_to_string(path)?)?;
+        self.write(path, contents)?;
+        self.updated(path);
+        Ok(())
+    }
+
+    pub fn is_file(&self, path: &Path) -> bool {
+        match self {
+            Sink::Disk => path.is_file(),
+            Sink::Memory(files) => lock(files).is_ok_and(|files| files.contains_key(path)),
+        }
+    }
+
+    pub fn exists(&self, path: &Path) -> bool {
+        match self {
+            Sink::Disk => path.exists(),
+            Sink::Memory(files) => {
+                lock(files).is_ok_and(|files| files.keys().any(|file| file.starts_with(path)))
+            }
+        }
+    }
+
+    pub fn create_dir_all(&self, path: &Path) -> anyhow::Result<()> {
+        match self {
+            Sink::Disk => Ok(fs::create_dir_all(path)?),
+            Sink::Memory(_) => Ok(()),
+        }
+    }
+
+    pub fn copy(&self, from_path: &Path, to_path: &Path) -> anyhow::Result<()> {
+        match self {
+            Sink::Disk => copy_file(from_path, to_path),
+            Sink::Memory(_) => self.write(to_path, fs::read(from_path)?),
+        }
+    }
+
+    #[cfg(unix)]
+    pub fn symlink(&self, target: &str, path: &Path) -> anyhow::Result<()> {
+        match self {
+            Sink::Disk => Ok(std::os::unix::fs::symlink(target, path)?),
+            Sink::Memory(_) => self.write(path, target),
+        }
+    }
+
+    pub fn make_executable(&self, path: &Path) -> anyhow::Result<()> {
+        match self {
+            Sink::Disk => make_executable(path),
+            Sink::Memory(_) => Ok(()),
+        }
+    }
+
+    pub fn copy_executable_bits(&self, from_path: &Path, to_path: &Path) -> anyhow::Result<()> {
+        match self {
+            Sink::Disk => copy_executable_bits(from_path, to_path),
+            Sink::Memory(_) => Ok(()),
+        }
+    }
+
+    pub fn created_dir(&self, path: &Path) {
+        if !self.is_memory() {
+            println!("Created dir  {}", path.display());
+        }
+    }
+
+    pub fn created(&self, path: &Path) {
+        if !self.is_memory() {
+            println!("Created file {}", path.display());
+        }
+    }
+
+    pub fn updated(&self, path: &Path) {
+        if !self.is_memory() {
+            println!("Updated file {}", path.display());
+        }
+    }
+
+    // Every file under dir, sorted by path
+    pub fn paths(&self, dir: &Path) -> anyhow::Result<Vec<PathBuf>> {
+        let mut paths = Vec::new();
+        match self {
+            Sink::Disk => collect_files(dir, VCS_DIRS, &mut paths)?,
+            Sink::Memory(files) => paths.extend(
+                lock(files)?
+                    .keys()
+                    .filter(|path| path.starts_with(dir))
+                    .cloned(),
+            ),
+        }
+        paths.sort();
+        Ok(paths)
+    }
+
+    // The files written under dir, relative to it and sorted by path
+    pub fn files(&self, dir: &Path) -> anyhow::Result<Vec<(PathBuf, Vec<u8>)>> {
+        match self {
+            Sink::Disk => Err(anyhow!("Only files rendered in memory can be listed")),
+            Sink::Memory(files) => Ok(lock(files)?
+                .iter()
+                .filter_map(|(path, contents)| {
+                    let relative_path = path.strip_prefix(dir).ok()?;
+                    Some((relative_path.to_path_buf(), contents.clone()))
+                })
+                .collect()),
+        }
+    }
+}
+
+fn lock(files: &Files) -> anyhow::Result<std::sync::MutexGuard<'_, BTreeMap<PathBuf, Vec<u8>>>> {
+    files
+        .lock()
+        .map_err(|_| anyhow!("Rendered files are unavailable"))
+}
@@ -0,0 +1,21 @@
+use super::{Project, ProjectKind};
+use std::path::Path;
+
+// The unit templates under systemd/ and the extension each is written with, after the binary name
+pub(crate) const UNIT_FILES: &[(&str, &str)] = &[("unit.service", "service"), ("unit.env", "env")];
+
+impl Project {
+    pub fn create_systemd_files(&self) -> anyhow::Result<()> {
+        if self.kind != ProjectKind::Daemon {
+            return Ok(());
+        }
+
+        for (template, extension) in UNIT_FILES {
+            self.render_template_file(
+                &Path::new("systemd").join(template),
+                Path::new(&format!("{}.{}", self.bin_name, extension)),
+            )?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,55 @@
+use super::Project;
+use anyhow::anyhow;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::str::FromStr;
+
+#[derive(Debug, Deserialize, Serialize, Eq, PartialEq, Clone, Copy, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum TaskRunner {
+    #[default]
+    Make,
+    Just,
+    Task,
+}
+
+impl TaskRunner {
+    pub fn file_name(&self) -> &'static str {
+        match self {
+            TaskRunner::Make => "Makefile",
+            TaskRunner::Just => "justfile",
+            TaskRunner::Task => "Taskfile.yml",
+        }
+    }
+}
+
+impl FromStr for TaskRunner {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "make" => Ok(TaskRunner::Make),
+            "just" => Ok(TaskRunner::Just),
+            "task" | "go-task" => Ok(TaskRunner::Task),
+            _ => Err(anyhow!("Unknown task runner {}", s)),
+        }
+    }
+}
+
+impl Project {
+    pub fn create_task_file(&self) -> anyhow::Result<()> {
+        let file_name = Path::new(self.task_runner.file_name());
+        match &self.project_dir {
+            Some(project_dir) => {
+                if self.provided_by_plugin(file_name) {
+                    return Ok(());
+                }
+                self.template(
+                    &self.template_file(file_name)?,
+                    &project_dir.join(file_name),
+                )
+            }
+            None => Err(anyhow!("Project directory not set")),
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use super::{Lang, Project};
+use anyhow::anyhow;
+
+const EDITIONS: &[(&str, &str)] = &[
+    ("2015", "1.0"),
+    ("2018", "1.31"),
+    ("2021", "1.56"),
+    ("2024", "1.85"),
+];
+
+impl Project {
+    pub fn check_toolchain(&self) -> anyhow::Result<()> {
+        if self.edition.is_none() && self.msrv.is_none() {
+            return Ok(());
+        }
+        if self.lang != Lang::Rust {
+            return Err(anyhow!(
+                "--edition and --msrv are only supported for Rust projects"
+            ));
+        }
+
+        let minimum = match &self.edition {
+            Some(edition) => match EDITIONS.iter().find(|(name, _)| name == edition) {
+                Some((_, minimum)) => Some(*minimum),
+                None => {
+                    return Err(anyhow!(
+                        "Unknown edition {}, expected one of 2015, 2018, 2021 or 2024",
+                        edition
+                    ))
+                }
+            },
+            None => None,
+        };
+
+        if let Some(msrv) = &self.msrv {
+            let version = parse_version(msrv).ok_or_else(|| {
+                anyhow!(
+                    "--msrv takes a Rust version such as 1.74 or 1.74.1, not {}",
+                    msrv
+                )
+            })?;
+            if let Some(minimum) = minimum {
+                if version < parse_version(minimum).unwrap() {
+                    return Err(anyhow!(
+                        "Edition {} needs Rust {} or newer, but --msrv is {}",
+                        self.edition.as_ref().unwrap(),
+                        minimum,
+                        msrv
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn parse_version(version: &str) -> Option<(u32, u32, u32)> {
+    let mut parts = version.split('.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next()?.parse().ok()?;
+    let patch = match parts.next() {
+        Some(patch) => patch.parse().ok()?,
+        None => 0,
+    };
+    if parts.next().is_some() {
+        return None;
+    }
+    Some((major, minor, patch))
+}
@@ -0,0 +1,137 @@
+use super::Project;
+use crate::manifest::SymlinkPolicy;
+use crate::template;
+use anyhow::anyhow;
+use rayon::prelude::*;
+use std::fs;
+use std::path::Path;
+
+impl Project {
+    pub fn create_template_tree(&self) -> anyhow::Result<()> {
+        let manifest = self.template_manifest()?;
+        if !manifest.render_tree {
+            return Ok(());
+        }
+
+        let symlinks = manifest.symlinks;
+        match (&self.template_dir, &self.project_dir) {
+            (Some(template_dir), Some(project_dir)) => {
+                let entries = fs::read_dir(template_dir)?.collect::<Result<Vec<_>, _>>()?;
+                entries.par_iter().try_for_each(|entry| {
+                    let from_path = entry.path();
+                    let skipped = from_path
+                        .file_name()
+                        .and_then(|name| name.to_str())
+                        .is_some_and(|name| {
+                            template::TEMPLATE_ENTRIES.contains(&name)
+                                && !manifest.tree_entries.iter().any(|entry| entry == name)
+                        });
+                    if skipped {
+                        Ok(())
+                    } else {
+                        self.render_path(&from_path, project_dir, symlinks)
+                    }
+                })
+            }
+            _ => Err(anyhow!("Template or project directory not set")),
+        }
+    }
+
+    pub fn mark_executables(&self) -> anyhow::Result<()> {
+        let project_dir = match &self.project_dir {
+            Some(project_dir) => project_dir,
+            None => return Err(anyhow!("Project directory not set")),
+        };
+
+        for path in self.template_manifest()?.executable {
+            let path = project_dir.join(template::render_str(&path, &self)?);
+            if self.sink.is_file(&path) {
+                self.sink.make_executable(&path)?;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn render_template_tree(&self, from_dir: &Path, to_dir: &Path) -> anyhow::Result<()> {
+        let symlinks = self.template_manifest()?.symlinks;
+        let entries = fs::read_dir(from_dir)?.collect::<Result<Vec<_>, _>>()?;
+        entries
+            .par_iter()
+            .try_for_each(|entry| self.render_path(&entry.path(), to_dir, symlinks))
+    }
+
+    fn render_path(
+        &self,
+        from_path: &Path,
+        to_dir: &Path,
+        symlinks: SymlinkPolicy,
+    ) -> anyhow::Result<()> {
+        let file_name = match from_path.file_name().and_then(|name| name.to_str()) {
+            Some(file_name) => file_name,
+            None => return Err(anyhow!("Invalid template path {}", from_path.display())),
+        };
+        let to_path = to_dir.join(template::render_str(file_name, &self)?);
+
+        if from_path.is_symlink() {
+            if symlinks == SymlinkPolicy::Preserve {
+                return self.create_symlink(from_path, &to_path);
+            }
+            check_symlink(from_path)?;
+        }
+
+        if from_path.is_dir() {
+            self.sink.create_dir_all(&to_path)?;
+            let entries = fs::read_dir(from_path)?.collect::<Result<Vec<_>, _>>()?;
+            entries
+                .par_iter()
+                .try_for_each(|entry| self.render_path(&entry.path(), &to_path, symlinks))?;
+        } else if fs::read_to_string(from_path).is_ok() {
+            self.template(from_path, &to_path)?;
+        } else {
+            self.sink.copy(from_path, &to_path)?;
+            self.sink.created(&to_path);
+        }
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    fn create_symlink(&self, from_path: &Path, to_path: &Path) -> anyhow::Result<()> {
+        let target = fs::read_link(from_path)?;
+        let target = template::render_str(&target.to_string_lossy(), &self)?;
+        self.sink.symlink(&target, to_path)?;
+        if !self.sink.is_memory() {
+            println!("Created link {} -> {}", to_path.display(), target);
+        }
+        Ok(())
+    }
+
+    #[cfg(not(unix))]
+    fn create_symlink(&self, from_path: &Path, _to_path: &Path) -> anyhow::Result<()> {
+        Err(anyhow!(
+            "Cannot recreate template symlink {} on this platform, set symlinks = \"follow\"",
+            from_path.display()
+        ))
+    }
+}
+
+fn check_symlink(from_path: &Path) -> anyhow::Result<()> {
+    let target = fs::read_link(from_path)?;
+    if !from_path.exists() {
+        return Err(anyhow!(
+            "Template symlink {} points to {}, which does not exist",
+            from_path.display(),
+            target.display()
+        ));
+    }
+    if from_path.is_dir() {
+        let parent = from_path.parent().unwrap_or(Path::new("."));
+        if fs::canonicalize(parent)?.starts_with(fs::canonicalize(from_path)?) {
+            return Err(anyhow!(
+                "Template symlink {} points to {}, which contains it",
+                from_path.display(),
+                target.display()
+            ));
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use super::{Lang, Project, ProjectKind};
+use anyhow::anyhow;
+use std::process::Command;
+
+impl Project {
+    pub fn build_command(&self) -> (&'static str, Vec<&'static str>) {
+        match self.lang {
+            _ if self.kind == ProjectKind::Monorepo => ("make", vec![]),
+            _ if self.kind == ProjectKind::Schema => ("buf", vec!["build"]),
+            _ if self.kind == ProjectKind::Docs => ("make", vec![]),
+            Lang::Rust => ("cargo", vec!["build"]),
+            Lang::Go => ("go", vec!["build", "./..."]),
+            Lang::Java => ("mvn", vec!["-q", "package"]),
+            Lang::Shell | Lang::Lua | Lang::Php => ("make", vec!["lint"]),
+            Lang::Terraform => ("make", vec!["validate"]),
+            Lang::Python => ("python3", vec!["-m", "compileall", "-q", "src"]),
+            Lang::Elixir => ("mix", vec!["compile"]),
+            Lang::Ruby => ("rake", vec!["build"]),
+            Lang::Scala => ("sbt", vec!["compile"]),
+            Lang::C | Lang::Cpp | Lang::Asm | Lang::Latex | Lang::Custom(_) => ("make", vec![]),
+        }
+    }
+
+    pub fn verify(&self) -> anyhow::Result<()> {
+        if !self.verify {
+            return Ok(());
+        }
+        let project_dir = match &self.project_dir {
+            Some(project_dir) => project_dir,
+            None => return Err(anyhow!("Project directory not set")),
+        };
+
+        let (program, args) = self.build_command();
+        let description = format!("{} {}", program, args.join(" ")).trim().to_string();
+        println!("Verifying build with `{}`", description);
+
+        let output = match Command::new(program)
+            .args(&args)
+            .current_dir(project_dir)
+            .output()
+        {
+            Ok(output) => output,
+            Err(error) => {
+                return Err(anyhow!("Could not run `{}`: {}", description, error));
+            }
+        };
+
+        if output.status.success() {
+            println!("Build succeeded");
+            Ok(())
+        } else {
+            println!("{}", String::from_utf8_lossy(&output.stdout));
+            println!("{}", String::from_utf8_lossy(&output.stderr));
+            Err(anyhow!(
+                "Build failed: `{}` exited with {}",
+                description,
+                output.status
+            ))
+        }
+    }
+}
@@ -0,0 +1,85 @@
+use crate::project::{Lang, ProjectKind};
+use crate::walk::collect_files;
+use anyhow::anyhow;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const RECORD_FILE: &str = ".gen.toml";
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ProjectRecord {
+    pub gen_version: String,
+    pub template_set: String,
+    pub template_hash: String,
+    pub template_commit: Option<String>,
+    pub base_tree: Option<String>,
+    pub lang: Lang,
+    pub kind: ProjectKind,
+    pub variables: toml::Table,
+}
+
+impl ProjectRecord {
+    pub fn load(project_dir: &Path) -> anyhow::Result<ProjectRecord> {
+        let record_path = project_dir.join(RECORD_FILE);
+        if !record_path.is_file() {
+            return Err(anyhow!(
+                "{} does not exist! Was this project generated by gen?",
+                record_path.display()
+            ));
+        }
+
+        let contents = fs::read_to_string(&record_path)?;
+        match toml::from_str(&contents) {
+            Ok(record) => Ok(record),
+            Err(error) => Err(anyhow!(
+                "Could not parse {}: {}",
+                record_path.display(),
+                error
+            )),
+        }
+    }
+
+    pub fn template_dir(&self, templates_root: &Path) -> PathBuf {
+        templates_root.join(&self.template_set)
+    }
+
+    pub fn save(&self, project_dir: &Path) -> anyhow::Result<()> {
+        let record_path = project_dir.join(RECORD_FILE);
+        fs::write(&record_path, toml::to_string(self)?)?;
+        println!("Created file {}", record_path.display());
+        Ok(())
+    }
+}
+
+pub fn template_hash(template_dir: &Path) -> anyhow::Result<String> {
+    let mut files = Vec::new();
+    if template_dir.is_dir() {
+        collect_files(template_dir, &[], &mut files)?;
+    }
+    files.sort();
+
+    // FNV-1a, so the hash stays stable across Rust releases
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for file in files {
+        let relative_path = file.strip_prefix(template_dir)?.display().to_string();
+        for byte in relative_path
+            .bytes()
+            .chain([0])
+            .chain(file_contents(&file)?)
+            .chain([0])
+        {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(0x100000001b3);
+        }
+    }
+    Ok(format!("{:016x}", hash))
+}
+
+pub(crate) fn file_contents(path: &Path) -> anyhow::Result<Vec<u8>> {
+    if path.is_symlink() {
+        Ok(fs::read_link(path)?.to_string_lossy().as_bytes().to_vec())
+    } else {
+        Ok(fs::read(path)?)
+    }
+}
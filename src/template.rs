@@ -0,0 +1,967 @@
+use crate::diff::{print_diff, FileChange};
+use crate::inspect::inspect;
+use crate::manifest::{Syntax, TemplateManifest};
+use crate::project::{
+    copy_executable_bits, grpc_component, legacy_template_path, openapi_files, K8s, Lang,
+    Packaging, Project, ProjectKind, TaskRunner, KINDS, UNIT_FILES,
+};
+use crate::record::{ProjectRecord, RECORD_FILE};
+use crate::walk::{collect_files, VCS_DIRS};
+use anyhow::anyhow;
+use handlebars::template::{Parameter, Template, TemplateElement};
+use handlebars::{Handlebars, Path as JsonPath};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::{OnceLock, RwLock};
+use std::time::SystemTime;
+
+const MANIFEST: &str = r#"# Commands run in the generated project after all files are written.
+# Commands run as written, with the project's variables in the environment
+# as GEN_NAME, GEN_KIND, ...; working directories are rendered like the
+# templates.
+#
+# [[post_generate]]
+# run = "make build"
+# working_dir = "."
+
+# Generated files to mark executable, in addition to the ones that are
+# executable in the template set. Paths are rendered like the templates.
+#
+# executable = ["scripts/run.sh"]
+
+# Symlinks are followed and their targets rendered in their place. Set this
+# to "preserve" to recreate them in the generated project instead.
+#
+# symlinks = "follow"
+
+# Files copied as they are, and files that use other delimiters than {{ }},
+# such as Helm charts or GitHub Actions workflows. Patterns are relative to
+# the template set.
+#
+# raw = [".github/workflows/release.yml"]
+#
+# [[delimiters]]
+# files = ["charts/*"]
+# open = "[["
+# close = "]]"
+"#;
+
+const MAKEFILE_BIN: &str = "NAME={{ bin_name }}
+
+.PHONY: build
+build:
+\t@echo \"Building $(NAME)\"
+
+.PHONY: run
+run: build
+\t@echo \"Running $(NAME)\"
+
+.PHONY: test
+test:
+\t@echo \"Testing $(NAME)\"
+
+.PHONY: clean
+clean:
+\trm -rf bin
+";
+
+const MAKEFILE_LIB: &str = "NAME={{ package_name }}
+
+.PHONY: build
+build:
+\t@echo \"Building $(NAME)\"
+
+.PHONY: test
+test:
+\t@echo \"Testing $(NAME)\"
+
+.PHONY: clean
+clean:
+\trm -rf bin
+";
+
+const GITIGNORE: &str = "bin/\n";
+
+const README: &str = "# {{ name }}
+{{#if description}}
+
+{{ description }}
+{{/if}}
+
+## Building
+```sh
+make build
+```
+";
+
+const MAIN: &str = "{{ name }}: replace this stub with the entry point of the project.\n";
+
+pub fn new_template(templates_root: &Path, name: &str, ext: &str) -> anyhow::Result<PathBuf> {
+    let template_dir = templates_root.join(name);
+    if template_dir.exists() {
+        return Err(anyhow!(
+            "Template directory {} already exists!",
+            template_dir.display()
+        ));
+    }
+
+    let main_path = Path::new("bin").join("src").join(format!("main.{}", ext));
+    let files = [
+        (PathBuf::from("template.toml"), MANIFEST),
+        (Path::new("bin").join("Makefile"), MAKEFILE_BIN),
+        (Path::new("lib").join("Makefile"), MAKEFILE_LIB),
+        (PathBuf::from(".gitignore"), GITIGNORE),
+        (PathBuf::from("README.md"), README),
+        (main_path, MAIN),
+    ];
+    for (path, contents) in files {
+        let to_path = template_dir.join(path);
+        if let Some(parent) = to_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&to_path, contents)?;
+        println!("Created file {}", to_path.display());
+    }
+    Ok(template_dir)
+}
+
+pub struct Problem {
+    pub path: PathBuf,
+    pub position: Option<(usize, usize)>,
+    pub message: String,
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.position {
+            Some((line, column)) => write!(
+                f,
+                "{}:{}:{}: {}",
+                self.path.display(),
+                line,
+                column,
+                self.message
+            ),
+            None => write!(f, "{}: {}", self.path.display(), self.message),
+        }
+    }
+}
+
+// Top-level names reserved for gen's template layout. They are neither rendered as part of a
+// template tree nor listed as files a kind produces, unless template.toml lists them under
+// tree_entries.
+pub(crate) const TEMPLATE_ENTRIES: &[&str] = &[
+    ".git",
+    "async",
+    "baremetal",
+    "bin",
+    "changelog",
+    "ci",
+    "community",
+    "components",
+    "compose",
+    "config-loader",
+    "daemon",
+    "datasci",
+    "db",
+    "devcontainer",
+    "docker",
+    "domain",
+    "embedded",
+    "errors",
+    "features",
+    "ffi",
+    "format",
+    "grpc",
+    "hooks",
+    "k8s",
+    "kmod",
+    "lib",
+    "license-header",
+    "logging",
+    "love",
+    "nix",
+    "openapi",
+    "packaging",
+    "qt",
+    "sdl",
+    "stubs",
+    "systemd",
+    "template.toml",
+    "tests",
+    "wasm",
+];
+
+// Compiled templates shared across renders. Each file is registered once per syntax, and
+// recompiled when its modification time changes. Files without one are never cached.
+struct Registry {
+    handlebars: Handlebars<'static>,
+    modified: HashMap<String, SystemTime>,
+}
+
+fn registry() -> &'static RwLock<Registry> {
+    static REGISTRY: OnceLock<RwLock<Registry>> = OnceLock::new();
+    REGISTRY.get_or_init(|| {
+        let mut handlebars = Handlebars::new();
+        handlebars.register_escape_fn(handlebars::no_escape);
+        RwLock::new(Registry {
+            handlebars,
+            modified: HashMap::new(),
+        })
+    })
+}
+
+pub fn render_file<T: Serialize>(path: &Path, data: &T) -> anyhow::Result<String> {
+    render_file_as(path, &Syntax::Handlebars, data)
+}
+
+pub fn render_file_as<T: Serialize>(
+    path: &Path,
+    syntax: &Syntax,
+    data: &T,
+) -> anyhow::Result<String> {
+    if *syntax == Syntax::Raw {
+        return Ok(fs::read_to_string(path)?);
+    }
+
+    let name = format!("{}\0{:?}", path.display(), syntax);
+    // Without a modification time a changed file cannot be told apart from a cached one, so it is
+    // compiled for this render only
+    let modified = match fs::metadata(path)?.modified() {
+        Ok(modified) => modified,
+        Err(_) => {
+            let source = template_source(path, syntax)?;
+            let registry = registry()
+                .read()
+                .map_err(|_| anyhow!("Template registry is unavailable"))?;
+            return Ok(registry.handlebars.render_template(&source, data)?);
+        }
+    };
+    {
+        let registry = registry()
+            .read()
+            .map_err(|_| anyhow!("Template registry is unavailable"))?;
+        if registry.modified.get(&name) == Some(&modified) {
+            return Ok(registry.handlebars.render(&name, data)?);
+        }
+    }
+
+    let mut registry = registry()
+        .write()
+        .map_err(|_| anyhow!("Template registry is unavailable"))?;
+    if registry.modified.get(&name) != Some(&modified) {
+        let source = template_source(path, syntax)?;
+        registry
+            .handlebars
+            .register_template_string(&name, source)?;
+        registry.modified.insert(name.clone(), modified);
+    }
+    Ok(registry.handlebars.render(&name, data)?)
+}
+
+fn template_source(path: &Path, syntax: &Syntax) -> anyhow::Result<String> {
+    let source = fs::read_to_string(path)?;
+    Ok(match syntax {
+        Syntax::Delimiters { open, close } => translate_delimiters(&source, open, close),
+        _ => source,
+    })
+}
+
+fn translate_delimiters(source: &str, open: &str, close: &str) -> String {
+    let mut translated = String::new();
+    let mut rest = source;
+    while let Some(start) = rest.find(open) {
+        let expression = &rest[start + open.len()..];
+        let end = match expression.find(close) {
+            Some(end) => end,
+            None => break,
+        };
+        translated.push_str(&rest[..start].replace("{{", "\\{{"));
+        translated.push_str("{{");
+        translated.push_str(&expression[..end]);
+        translated.push_str("}}");
+        rest = &expression[end + close.len()..];
+    }
+    translated.push_str(&rest.replace("{{", "\\{{"));
+    translated
+}
+
+pub fn render_str<T: Serialize>(template: &str, data: &T) -> anyhow::Result<String> {
+    let registry = registry()
+        .read()
+        .map_err(|_| anyhow!("Template registry is unavailable"))?;
+    Ok(registry.handlebars.render_template(template, data)?)
+}
+
+pub fn sets(templates_root: &Path) -> anyhow::Result<Vec<String>> {
+    let mut sets = Vec::new();
+    for entry in fs::read_dir(templates_root)? {
+        let path = entry?.path();
+        if let Some(name) = path.file_name().and_then(|name| name.to_str()) {
+            if path.is_dir() && !name.starts_with('_') && !name.starts_with('.') {
+                sets.push(name.to_string());
+            }
+        }
+    }
+    sets.sort();
+    Ok(sets)
+}
+
+pub fn kinds(templates_root: &Path) -> anyhow::Result<Vec<String>> {
+    let mut kinds: Vec<String> = KINDS.iter().map(|info| String::from(info.name)).collect();
+    for set in sets(templates_root)? {
+        kinds.extend(TemplateManifest::load(&templates_root.join(set))?.kinds);
+    }
+    kinds.sort();
+    kinds.dedup();
+    Ok(kinds)
+}
+
+pub fn check(template_dir: &Path) -> anyhow::Result<Vec<Problem>> {
+    if !template_dir.is_dir() {
+        return Err(anyhow!(
+            "Template directory {} does not exist!",
+            template_dir.display()
+        ));
+    }
+
+    let mut problems = Vec::new();
+    let manifest = match TemplateManifest::load(template_dir) {
+        Ok(manifest) => manifest,
+        Err(error) => {
+            problems.push(Problem {
+                path: template_dir.join("template.toml"),
+                position: None,
+                message: error.to_string(),
+            });
+            TemplateManifest::default()
+        }
+    };
+
+    let mut declared = Project::variable_names();
+    declared.extend(manifest.variables.iter().cloned());
+
+    let mut files = Vec::new();
+    collect_files(template_dir, &[], &mut files)?;
+    files.sort();
+    for file in files {
+        let relative_path = file.strip_prefix(template_dir)?;
+        let tree_entry = manifest
+            .tree_entries
+            .iter()
+            .any(|entry| relative_path.starts_with(entry));
+        let layout = relative_path.starts_with("components") || relative_path.starts_with("tests");
+        if (layout && !tree_entry)
+            || relative_path.starts_with(".git")
+            || relative_path == Path::new("template.toml")
+        {
+            continue;
+        }
+        let source = match fs::read_to_string(&file) {
+            Ok(source) => source,
+            Err(_) => continue,
+        };
+        let source = match manifest.syntax(relative_path) {
+            Syntax::Raw => continue,
+            Syntax::Delimiters { open, close } => translate_delimiters(&source, &open, &close),
+            Syntax::Handlebars => source,
+        };
+
+        match Template::compile(&source) {
+            Ok(template) => {
+                let mut variables = Vec::new();
+                collect_variables(&template, &mut variables);
+                let extra = extra_variables(relative_path);
+                for (variable, position) in variables {
+                    let known = declared.contains(&variable) || extra.contains(&variable.as_str());
+                    if !known {
+                        problems.push(Problem {
+                            path: file.clone(),
+                            position,
+                            message: format!("undeclared variable `{}`", variable),
+                        });
+                    }
+                }
+            }
+            Err(error) => problems.push(Problem {
+                path: file.clone(),
+                position: error.line_no.zip(error.column_no),
+                message: error.reason().to_string(),
+            }),
+        }
+    }
+
+    let set_name = template_dir
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    for kind in set_kinds(template_dir, &manifest) {
+        let kind = ProjectKind::from_str(&kind)?;
+        for required in required_files(&set_name, kind) {
+            if resolve(template_dir, kind, &required).is_none() {
+                problems.push(Problem {
+                    path: template_dir.join(&required),
+                    position: None,
+                    message: format!("missing, but required for {:?} projects", kind),
+                });
+            }
+        }
+    }
+    Ok(problems)
+}
+
+fn extra_variables(relative_path: &Path) -> &'static [&'static str] {
+    let in_dir = |name: &str| {
+        relative_path
+            .components()
+            .any(|component| component.as_os_str() == name)
+    };
+    if in_dir("packaging") {
+        Packaging::variables()
+    } else if in_dir("k8s") {
+        K8s::variables()
+    } else if in_dir("compose") {
+        &["service_name", "with"]
+    } else if in_dir("mix") {
+        &["elixir_module"]
+    } else if in_dir("gem") {
+        &["ruby_module"]
+    } else if in_dir("document") {
+        &["latex_title", "latex_author", "latex_abstract"]
+    } else if in_dir("proto") {
+        &["snake_name", "pascal_name"]
+    } else if in_dir("site") {
+        &[
+            "site_name",
+            "site_author",
+            "site_description",
+            "site_repository",
+        ]
+    } else if in_dir("ffi") {
+        &["lib_name"]
+    } else if in_dir("config-loader") || in_dir("db") {
+        &["package"]
+    } else if in_dir("features") {
+        &["feature"]
+    } else if in_dir("openapi") {
+        &[
+            "package",
+            "paths",
+            "operations",
+            "operation",
+            "path_params",
+            "request_bodies",
+        ]
+    } else if in_dir("composer") {
+        &["composer_name", "composer_namespace"]
+    } else {
+        &[]
+    }
+}
+
+pub fn set_kinds(template_dir: &Path, manifest: &TemplateManifest) -> Vec<String> {
+    if !manifest.kinds.is_empty() {
+        return manifest.kinds.clone();
+    }
+    KINDS
+        .iter()
+        .filter(|info| match info.kind.required_overlay() {
+            Some(overlay_dir) => template_dir.join(overlay_dir).is_dir(),
+            None => !info.overlay_dirs.is_empty(),
+        })
+        .map(|info| String::from(info.name))
+        .collect()
+}
+
+fn required_files(set_name: &str, kind: ProjectKind) -> Vec<PathBuf> {
+    let mut required = vec![PathBuf::from("Makefile"), PathBuf::from(".gitignore")];
+    if let Some(entry_point) = entry_point(set_name, kind) {
+        required.push(entry_point);
+    }
+    required
+}
+
+fn entry_point(set_name: &str, kind: ProjectKind) -> Option<PathBuf> {
+    let entry_point = match Lang::from_str(set_name) {
+        Ok(Lang::Rust) => Path::new("src").join("main.rs"),
+        Ok(Lang::C) => Path::new("src").join("main.c"),
+        Ok(Lang::Cpp) => Path::new("src").join("main.cpp"),
+        Ok(Lang::Go) => PathBuf::from("main.go"),
+        Ok(Lang::Java) => return Some(PathBuf::from("manifest.txt")),
+        Ok(Lang::Asm) => Path::new("src").join("main.asm"),
+        Ok(Lang::Shell) => PathBuf::from("main.sh"),
+        Ok(Lang::Python) => Path::new("src").join("__main__.py"),
+        Ok(Lang::Elixir) => Path::new("mix").join("cli.ex"),
+        Ok(Lang::Ruby) => Path::new("gem").join("exe"),
+        Ok(Lang::Scala) => Path::new("scala").join("Main.scala"),
+        Ok(Lang::Lua) => PathBuf::from("main.lua"),
+        Ok(Lang::Php) => Path::new("composer").join("main.php"),
+        Ok(Lang::Terraform) => return Some(PathBuf::from("main.tf")),
+        Ok(Lang::Latex) => Path::new("document").join("main.tex"),
+        Ok(Lang::Custom(_)) | Err(_) => return None,
+    };
+    if kind.has_entry_point() {
+        Some(entry_point)
+    } else {
+        None
+    }
+}
+
+pub fn kind_tree(
+    template_dir: &Path,
+    kind: ProjectKind,
+    task_runner: TaskRunner,
+) -> anyhow::Result<Vec<PathBuf>> {
+    let mut tree = Vec::new();
+    if let Some(templates_root) = template_dir.parent() {
+        let base_dir = templates_root.join("_base");
+        if base_dir.is_dir() {
+            collect_tree(&base_dir, &[], &mut tree)?;
+        }
+    }
+    let tree_entries = TemplateManifest::load(template_dir)
+        .map(|manifest| manifest.tree_entries)
+        .unwrap_or_default();
+    collect_tree(template_dir, &tree_entries, &mut tree)?;
+    for overlay_dir in kind.overlay_dirs().iter().rev() {
+        let overlay_dir = template_dir.join(overlay_dir);
+        if overlay_dir.is_dir() {
+            collect_tree(&overlay_dir, &[], &mut tree)?;
+        }
+    }
+
+    let set_name = template_dir
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    if entry_point(&set_name, kind).is_none() {
+        if let Some(entry_point) = entry_point(&set_name, ProjectKind::Executable) {
+            tree.retain(|path| path != &entry_point);
+        }
+    }
+    for other in [TaskRunner::Make, TaskRunner::Just, TaskRunner::Task] {
+        if other != task_runner {
+            tree.retain(|path| path != Path::new(other.file_name()));
+        }
+    }
+    tree.extend(kind_files(template_dir, &set_name, kind)?);
+    tree.sort();
+    tree.dedup();
+    Ok(tree)
+}
+
+// The files a kind gets from template directories that aren't copied as they are
+fn kind_files(
+    template_dir: &Path,
+    set_name: &str,
+    kind: ProjectKind,
+) -> anyhow::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    match kind {
+        ProjectKind::Daemon => {
+            for (template, extension) in UNIT_FILES {
+                let template = Path::new("systemd").join(template);
+                if resolve(template_dir, kind, &template).is_some() {
+                    files.push(PathBuf::from(format!("{{{{ bin_name }}}}.{}", extension)));
+                }
+            }
+        }
+        ProjectKind::Grpc => {
+            if let Ok(lang) = Lang::from_str(set_name) {
+                if template_dir.join("components").join("grpc").is_dir() {
+                    let component = grpc_component(template_dir, Path::new(""), lang, kind)?;
+                    for (_, path) in component.templates()? {
+                        files.push(PathBuf::from(path));
+                    }
+                }
+            }
+        }
+        ProjectKind::Openapi => {
+            if let Ok(lang) = Lang::from_str(set_name) {
+                let source_dir = Path::new("src")
+                    .join("main")
+                    .join("java")
+                    .join("{{ package_path }}");
+                files.extend(openapi_files(lang, &source_dir));
+            }
+        }
+        _ => {}
+    }
+    Ok(files)
+}
+
+fn collect_tree(
+    template_dir: &Path,
+    tree_entries: &[String],
+    tree: &mut Vec<PathBuf>,
+) -> anyhow::Result<()> {
+    for entry in fs::read_dir(template_dir)? {
+        let path = entry?.path();
+        let name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+        if !TEMPLATE_ENTRIES.contains(&name.as_str()) || tree_entries.contains(&name) {
+            let mut files = Vec::new();
+            if path.is_dir() {
+                collect_files(&path, &[], &mut files)?;
+            } else {
+                files.push(path);
+            }
+            for file in files {
+                tree.push(file.strip_prefix(template_dir)?.to_path_buf());
+            }
+        } else if name == "format" {
+            let mut files = Vec::new();
+            collect_files(&path, &[], &mut files)?;
+            for file in files {
+                tree.push(file.strip_prefix(&path)?.to_path_buf());
+            }
+        }
+    }
+    Ok(())
+}
+
+fn resolve(template_dir: &Path, kind: ProjectKind, path: &Path) -> Option<PathBuf> {
+    let mut candidates = Vec::new();
+    for overlay_dir in kind.overlay_dirs() {
+        candidates.push(template_dir.join(overlay_dir).join(path));
+    }
+    candidates.push(legacy_template_path(template_dir, kind, path));
+    candidates.push(template_dir.join(path));
+    if let Some(templates_root) = template_dir.parent() {
+        candidates.push(templates_root.join("_base").join(path));
+    }
+    candidates.into_iter().find(|candidate| candidate.is_file())
+}
+
+fn collect_variables(template: &Template, variables: &mut Vec<(String, Option<(usize, usize)>)>) {
+    for (index, element) in template.elements.iter().enumerate() {
+        let position = template
+            .mapping
+            .get(index)
+            .map(|mapping| (mapping.0, mapping.1));
+        collect_element_variables(element, position, variables);
+    }
+}
+
+fn collect_element_variables(
+    element: &TemplateElement,
+    position: Option<(usize, usize)>,
+    variables: &mut Vec<(String, Option<(usize, usize)>)>,
+) {
+    let helper = match element {
+        TemplateElement::Expression(helper)
+        | TemplateElement::HtmlExpression(helper)
+        | TemplateElement::HelperBlock(helper) => helper,
+        _ => return,
+    };
+
+    let mut parameters: Vec<&Parameter> = helper.params.iter().collect();
+    parameters.push(&helper.name);
+    parameters.extend(helper.hash.values());
+    for parameter in parameters {
+        match parameter {
+            Parameter::Path(JsonPath::Relative((_, raw))) => {
+                if let Some(variable) = root_variable(raw) {
+                    variables.push((variable, position));
+                }
+            }
+            Parameter::Subexpression(subexpression) => {
+                collect_element_variables(subexpression.as_element(), position, variables)
+            }
+            _ => {}
+        }
+    }
+
+    let changes_context =
+        matches!(&helper.name, Parameter::Name(name) if name == "each" || name == "with");
+    if !changes_context {
+        for block in [&helper.template, &helper.inverse].into_iter().flatten() {
+            collect_variables(block, variables);
+        }
+    }
+}
+
+fn root_variable(raw: &str) -> Option<String> {
+    let root = raw.split(['.', '/']).next()?;
+    if root.is_empty() || root == "this" || root.starts_with('@') {
+        None
+    } else {
+        Some(root.to_string())
+    }
+}
+
+pub fn test(template_dir: &Path) -> anyhow::Result<usize> {
+    let tests_dir = template_dir.join("tests");
+    if !tests_dir.is_dir() {
+        return Err(anyhow!("{} has no fixtures", tests_dir.display()));
+    }
+
+    let mut fixtures: Vec<PathBuf> = fs::read_dir(&tests_dir)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<_, _>>()?;
+    fixtures.retain(|fixture| fixture.join("fixture.toml").is_file());
+    fixtures.sort();
+
+    let failed = fixtures.iter().try_fold(0, |failed, fixture| {
+        let passed = test_fixture(template_dir, fixture)?;
+        Ok::<usize, anyhow::Error>(if passed { failed } else { failed + 1 })
+    })?;
+    println!(
+        "{} of {} fixtures passed",
+        fixtures.len() - failed,
+        fixtures.len()
+    );
+    Ok(failed)
+}
+
+fn test_fixture(template_dir: &Path, fixture: &Path) -> anyhow::Result<bool> {
+    let fixture_name = fixture
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let fixture_path = fixture.join("fixture.toml");
+    let overrides: toml::Table = match toml::from_str(&fs::read_to_string(&fixture_path)?) {
+        Ok(overrides) => overrides,
+        Err(error) => {
+            return Err(anyhow!(
+                "Could not parse {}: {}",
+                fixture_path.display(),
+                error
+            ))
+        }
+    };
+
+    let name = match overrides.get("name").and_then(|name| name.as_str()) {
+        Some(name) => name.to_string(),
+        None => return Err(anyhow!("{} sets no name", fixture_path.display())),
+    };
+    let project = Project::from_variables(overrides, template_dir.to_path_buf(), name.into())?;
+    let rendered: HashMap<PathBuf, Vec<u8>> = project.render_in_memory()?.into_iter().collect();
+
+    let expected_dir = fixture.join("expected");
+    let mut expected_files = Vec::new();
+    collect_files(&expected_dir, &[], &mut expected_files)?;
+    expected_files.sort();
+
+    let mut passed = true;
+    for expected_file in expected_files {
+        let path = expected_file.strip_prefix(&expected_dir)?.to_path_buf();
+        let expected = fs::read(&expected_file)?;
+        match rendered.get(&path) {
+            Some(rendered) if rendered == &expected => {}
+            Some(rendered) => {
+                passed = false;
+                print_diff(&FileChange {
+                    path,
+                    current: Some(expected),
+                    rendered: rendered.clone(),
+                });
+            }
+            None => {
+                passed = false;
+                println!("Missing file {}", path.display());
+            }
+        }
+    }
+
+    if passed {
+        println!("Fixture {} passed", fixture_name);
+    } else {
+        println!("Fixture {} failed", fixture_name);
+    }
+    Ok(passed)
+}
+
+pub fn from_dir(
+    project_dir: &Path,
+    template_dir: &Path,
+    name: Option<&str>,
+    domain: Option<&str>,
+) -> anyhow::Result<()> {
+    if template_dir.exists() {
+        return Err(anyhow!(
+            "Template directory {} already exists!",
+            template_dir.display()
+        ));
+    }
+
+    let inspection = inspect(project_dir)?;
+    let recorded_name = ProjectRecord::load(project_dir).ok().and_then(|record| {
+        record
+            .variables
+            .get("name")
+            .and_then(|name| name.as_str())
+            .map(String::from)
+    });
+    let name = match name.map(String::from).or(recorded_name).or_else(|| {
+        fs::canonicalize(project_dir).ok().and_then(|dir| {
+            dir.file_name()
+                .map(|name| name.to_string_lossy().to_string())
+        })
+    }) {
+        Some(name) => name,
+        None => return Err(anyhow!("Could not determine the project name, use --name")),
+    };
+    let domain = domain.map(String::from).or(inspection.domain);
+    let domain = domain.as_deref();
+
+    let mut files = Vec::new();
+    collect_files(project_dir, VCS_DIRS, &mut files)?;
+    files.sort();
+    let mut tree_entries: Vec<String> = Vec::new();
+    for file in files {
+        let path = file.strip_prefix(project_dir)?;
+        if path == Path::new(RECORD_FILE) {
+            continue;
+        }
+
+        // gen reserves some top-level names for its own template layout, so
+        // the manifest lists the ones this project uses for its files
+        if let Some(entry) = path.iter().next().and_then(|entry| entry.to_str()) {
+            if TEMPLATE_ENTRIES.contains(&entry)
+                && entry != "template.toml"
+                && !tree_entries.iter().any(|listed| listed == entry)
+            {
+                tree_entries.push(entry.to_string());
+            }
+        }
+
+        let to_path = template_dir.join(
+            path.iter()
+                .map(|component| placeholders(&component.to_string_lossy(), &name, domain))
+                .collect::<PathBuf>(),
+        );
+        if let Some(parent) = to_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let contents = fs::read(&file)?;
+        match String::from_utf8(contents) {
+            Ok(source) => fs::write(
+                &to_path,
+                placeholders(&source.replace("{{", "\\{{"), &name, domain),
+            )?,
+            Err(error) => fs::write(&to_path, error.into_bytes())?,
+        }
+        copy_executable_bits(&file, &to_path)?;
+        println!("Created file {}", to_path.display());
+    }
+
+    let manifest_path = template_dir.join("template.toml");
+    fs::write(
+        &manifest_path,
+        format!(
+            "kinds = [\"{}\"]\nrender_tree = true\nsnapshot = true\ntree_entries = {:?}\n",
+            inspection.kind.overlay_dirs().first().unwrap_or(&"bin"),
+            tree_entries
+        ),
+    )?;
+    println!("Created file {}", manifest_path.display());
+    Ok(())
+}
+
+fn placeholders(text: &str, name: &str, domain: Option<&str>) -> String {
+    let text = match domain {
+        Some(domain) => replace_word(text, domain, "{{ domain }}"),
+        None => text.to_string(),
+    };
+    replace_word(&text, name, "{{ name }}")
+}
+
+fn replace_word(text: &str, word: &str, replacement: &str) -> String {
+    let is_word = |c: char| c.is_alphanumeric() || c == '_';
+    let mut replaced = String::new();
+    let mut rest = text;
+    while let Some(index) = rest.find(word) {
+        let before = rest[..index]
+            .chars()
+            .last()
+            .or_else(|| replaced.chars().last());
+        let after = rest[index + word.len()..].chars().next();
+        replaced.push_str(&rest[..index]);
+        if before.is_some_and(is_word) || after.is_some_and(is_word) {
+            replaced.push_str(word);
+        } else if before == Some('{') {
+            // `{{{` would open a triple-stash, so pad the brace with a space
+            // that the placeholder trims again
+            replaced.push(' ');
+            replaced.push_str(&replacement.replacen("{{", "{{~", 1));
+        } else {
+            replaced.push_str(replacement);
+        }
+        rest = &rest[index + word.len()..];
+    }
+    replaced.push_str(rest);
+    replaced
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{render_file, render_file_as, translate_delimiters};
+    use crate::manifest::Syntax;
+    use std::fs;
+    use std::time::{Duration, SystemTime};
+    use tempfile::TempDir;
+
+    #[test]
+    fn translates_delimiters_to_handlebars() {
+        assert_eq!(
+            translate_delimiters("Hello <% name %>, <%#if x%>!<%/if%>", "<%", "%>"),
+            "Hello {{ name }}, {{#if x}}!{{/if}}"
+        );
+    }
+
+    #[test]
+    fn escapes_literal_handlebars() {
+        assert_eq!(
+            translate_delimiters("{{ raw }} [[ name ]] {{", "[[", "]]"),
+            "\\{{ raw }} {{ name }} \\{{"
+        );
+    }
+
+    #[test]
+    fn leaves_unclosed_delimiters() {
+        assert_eq!(
+            translate_delimiters("<% name %> <% open", "<%", "%>"),
+            "{{ name }} <% open"
+        );
+    }
+
+    #[test]
+    fn caches_each_syntax_separately() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("template");
+        fs::write(&path, "{{ name }} <% name %>").unwrap();
+        let data = serde_json::json!({ "name": "demo" });
+        let delimiters = Syntax::Delimiters {
+            open: String::from("<%"),
+            close: String::from("%>"),
+        };
+
+        assert_eq!(render_file(&path, &data).unwrap(), "demo <% name %>");
+        assert_eq!(
+            render_file_as(&path, &delimiters, &data).unwrap(),
+            "{{ name }} demo"
+        );
+    }
+
+    #[test]
+    fn recompiles_changed_templates() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("template");
+        let data = serde_json::json!({ "name": "demo" });
+        fs::write(&path, "before {{ name }}").unwrap();
+        assert_eq!(render_file(&path, &data).unwrap(), "before demo");
+
+        fs::write(&path, "after {{ name }}").unwrap();
+        let file = fs::File::options().write(true).open(&path).unwrap();
+        file.set_modified(SystemTime::now() + Duration::from_secs(1))
+            .unwrap();
+        assert_eq!(render_file(&path, &data).unwrap(), "after demo");
+    }
+}
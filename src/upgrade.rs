@@ -0,0 +1,258 @@
+use crate::diff::{changes, print_diff, FileChange};
+use crate::git;
+use crate::project::Project;
+use crate::record::{template_hash, ProjectRecord, RECORD_FILE};
+use anyhow::anyhow;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use tempfile::TempDir;
+
+// Keeps the tree recorded as base_tree reachable, so git gc does not prune it
+const BASE_REF: &str = "refs/gen/base";
+
+pub fn upgrade(project_dir: &Path, templates_root: &Path, apply: bool) -> anyhow::Result<()> {
+    let mut record = ProjectRecord::load(project_dir)?;
+    let rendered = render(&record, templates_root, project_dir)?;
+    let changes = changes(project_dir, rendered.clone());
+    if changes.is_empty() {
+        println!("{} is up to date", project_dir.display());
+    }
+    for change in &changes {
+        if apply {
+            apply_change(project_dir, record.base_tree.as_deref(), change)?;
+        } else {
+            print_diff(change);
+        }
+    }
+
+    if apply {
+        let project = Project::from_record(&record, templates_root, project_dir.to_path_buf())?;
+        if let Some(template_dir) = project.template_dir() {
+            record.template_hash = template_hash(template_dir)?;
+            record.template_commit = git::run(template_dir, &["rev-parse", "HEAD"]).ok();
+        }
+        record.gen_version = String::from(env!("CARGO_PKG_VERSION"));
+        match save_base(project_dir, &rendered) {
+            Ok(tree) => record.base_tree = Some(tree),
+            Err(error) => println!("Could not save the templates as applied: {}", error),
+        }
+        record.save(project_dir)?;
+    }
+    Ok(())
+}
+
+pub fn render(
+    record: &ProjectRecord,
+    templates_root: &Path,
+    project_dir: &Path,
+) -> anyhow::Result<Vec<(PathBuf, Vec<u8>)>> {
+    if record
+        .variables
+        .get("name")
+        .and_then(|name| name.as_str())
+        .is_none()
+    {
+        return Err(anyhow!("{} does not record a project name", RECORD_FILE));
+    }
+
+    let project = Project::from_record(record, templates_root, project_dir.to_path_buf())?;
+    project.render_in_memory()
+}
+
+fn apply_change(
+    project_dir: &Path,
+    base_tree: Option<&str>,
+    change: &FileChange,
+) -> anyhow::Result<()> {
+    let path = project_dir.join(&change.path);
+    if path.is_symlink() {
+        println!("Skipped symlink {}, update it by hand", path.display());
+        return Ok(());
+    }
+    let current = match &change.current {
+        Some(current) => current,
+        None => {
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(&path, &change.rendered)?;
+            println!("Created file {}", path.display());
+            return Ok(());
+        }
+    };
+
+    match original(project_dir, base_tree, &change.path) {
+        Some(base) if &base == current => {
+            fs::write(&path, &change.rendered)?;
+            println!("Updated file {}", path.display());
+        }
+        Some(base) if base == change.rendered => {}
+        Some(base) => {
+            let conflicts = merge(&path, &base, &change.rendered)?;
+            if conflicts > 0 {
+                println!(
+                    "Merged file {} with {} conflict(s)",
+                    path.display(),
+                    conflicts
+                );
+            } else {
+                println!("Merged file {}", path.display());
+            }
+        }
+        None => println!(
+            "Skipped file {}: it changed since generation and there is no earlier render to merge from",
+            path.display()
+        ),
+    }
+    Ok(())
+}
+
+// The file as gen last wrote it: the tree saved by the last upgrade --apply, or the project's
+// initial commit when it has never been upgraded
+fn original(project_dir: &Path, base_tree: Option<&str>, path: &Path) -> Option<Vec<u8>> {
+    let base = match base_tree {
+        Some(base_tree) => String::from(base_tree),
+        None => {
+            let roots = git::run(project_dir, &["rev-list", "--max-parents=0", "HEAD"]).ok()?;
+            String::from(roots.lines().last()?)
+        }
+    };
+    let output = Command::new("git")
+        .arg("show")
+        .arg(format!("{}:{}", base, path.display()))
+        .current_dir(project_dir)
+        .output()
+        .ok()?;
+    if output.status.success() {
+        Some(output.stdout)
+    } else {
+        None
+    }
+}
+
+// Writes the render to a git tree through a scratch index, leaving the project's index and
+// working tree alone, and points BASE_REF at it
+fn save_base(project_dir: &Path, rendered: &[(PathBuf, Vec<u8>)]) -> anyhow::Result<String> {
+    let scratch = TempDir::new()?;
+    let index_path = scratch.path().join("index");
+    let blob_path = scratch.path().join("blob");
+    for (path, contents) in rendered {
+        if path == Path::new(RECORD_FILE) {
+            continue;
+        }
+
+        fs::write(&blob_path, contents)?;
+        let blob = git::run(
+            project_dir,
+            &[
+                "hash-object",
+                "-w",
+                "--no-filters",
+                &blob_path.to_string_lossy(),
+            ],
+        )?;
+        git_with_index(
+            project_dir,
+            &index_path,
+            &[
+                "update-index",
+                "--add",
+                "--cacheinfo",
+                &format!("100644,{},{}", blob, path.display()),
+            ],
+        )?;
+    }
+
+    let tree = git_with_index(project_dir, &index_path, &["write-tree"])?;
+    git::run(project_dir, &["update-ref", BASE_REF, &tree])?;
+    Ok(tree)
+}
+
+fn git_with_index(project_dir: &Path, index_path: &Path, args: &[&str]) -> anyhow::Result<String> {
+    let output = Command::new("git")
+        .args(args)
+        .env("GIT_INDEX_FILE", index_path)
+        .current_dir(project_dir)
+        .output()?;
+    if !output.status.success() {
+        return Err(anyhow!(
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+fn merge(path: &Path, base: &[u8], rendered: &[u8]) -> anyhow::Result<i32> {
+    // Outside the project, so the scratch files can never collide with the user's own
+    let scratch = TempDir::new()?;
+    let base_path = scratch.path().join("base");
+    let rendered_path = scratch.path().join("rendered");
+    fs::write(&base_path, base)?;
+    fs::write(&rendered_path, rendered)?;
+
+    let status = Command::new("git")
+        .arg("merge-file")
+        .arg("-L")
+        .arg("current")
+        .arg("-L")
+        .arg("original")
+        .arg("-L")
+        .arg("template")
+        .arg(path)
+        .arg(&base_path)
+        .arg(&rendered_path)
+        .status()?;
+
+    match status.code() {
+        Some(conflicts) if conflicts >= 0 => Ok(conflicts),
+        _ => Err(anyhow!("git merge-file failed for {}", path.display())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::merge;
+    use std::fs;
+    use tempfile::TempDir;
+
+    const ORIGINAL: &str = "build:\n\tcargo build\n\ntest:\n\tcargo test\n";
+
+    #[test]
+    fn merges_template_changes_into_edited_files() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("Makefile");
+        fs::write(&path, ORIGINAL.replace("cargo test", "cargo test --all")).unwrap();
+        let rendered = ORIGINAL.replace("cargo build", "cargo build --locked");
+
+        assert_eq!(
+            merge(&path, ORIGINAL.as_bytes(), rendered.as_bytes()).unwrap(),
+            0
+        );
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "build:\n\tcargo build --locked\n\ntest:\n\tcargo test --all\n"
+        );
+    }
+
+    #[test]
+    fn leaves_conflict_markers_when_both_sides_change_a_line() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("Makefile");
+        fs::write(&path, ORIGINAL.replace("cargo test", "cargo nextest run")).unwrap();
+        let rendered = ORIGINAL.replace("cargo test", "cargo test --locked");
+
+        assert_eq!(
+            merge(&path, ORIGINAL.as_bytes(), rendered.as_bytes()).unwrap(),
+            1
+        );
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "build:\n\tcargo build\n\ntest:\n<<<<<<< current\n\tcargo nextest run\n=======\n\
+             \tcargo test --locked\n>>>>>>> template\n"
+        );
+    }
+}
@@ -0,0 +1,306 @@
+use crate::git::{self, GitSettings};
+use anyhow::anyhow;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use std::str::FromStr;
+
+#[derive(Debug, Deserialize, Serialize, Eq, PartialEq, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum VcsKind {
+    Git,
+    Jj,
+    Hg,
+    None,
+}
+
+impl FromStr for VcsKind {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "git" => Ok(VcsKind::Git),
+            "jj" | "jujutsu" => Ok(VcsKind::Jj),
+            "hg" | "mercurial" => Ok(VcsKind::Hg),
+            "none" => Ok(VcsKind::None),
+            _ => Err(anyhow!("Unknown VCS {}", s)),
+        }
+    }
+}
+
+pub trait Vcs {
+    fn name(&self) -> &str;
+    fn ignore_file(&self) -> &str;
+    fn init(&self, dir: &Path) -> anyhow::Result<()>;
+    fn commit_all(&self, dir: &Path, message: &str) -> anyhow::Result<()>;
+    fn add_remote(&self, dir: &Path, name: &str, url: &str) -> anyhow::Result<()>;
+    fn push(&self, dir: &Path, remote: &str) -> anyhow::Result<()>;
+
+    fn set_upstream(&self, _dir: &Path, _remote: &str) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+pub fn backend(kind: VcsKind, settings: &GitSettings) -> Box<dyn Vcs> {
+    match kind {
+        VcsKind::Git => Box::new(Git {
+            settings: settings.clone(),
+        }),
+        VcsKind::Jj => Box::new(Jujutsu {
+            branch: settings
+                .default_branch
+                .clone()
+                .unwrap_or_else(|| String::from("main")),
+        }),
+        VcsKind::Hg => Box::new(Mercurial),
+        VcsKind::None => Box::new(NoVcs),
+    }
+}
+
+pub struct Git {
+    settings: GitSettings,
+}
+
+impl Vcs for Git {
+    fn name(&self) -> &str {
+        "git"
+    }
+
+    fn ignore_file(&self) -> &str {
+        ".gitignore"
+    }
+
+    fn init(&self, dir: &Path) -> anyhow::Result<()> {
+        git::init(dir, &self.settings)
+    }
+
+    fn commit_all(&self, dir: &Path, message: &str) -> anyhow::Result<()> {
+        git::commit_all(dir, message, &self.settings)
+    }
+
+    fn add_remote(&self, dir: &Path, name: &str, url: &str) -> anyhow::Result<()> {
+        git::add_remote(dir, name, url)
+    }
+
+    fn push(&self, dir: &Path, remote: &str) -> anyhow::Result<()> {
+        git::push(dir, remote)
+    }
+
+    fn set_upstream(&self, dir: &Path, remote: &str) -> anyhow::Result<()> {
+        git::set_upstream(dir, remote)
+    }
+}
+
+pub struct Jujutsu {
+    branch: String,
+}
+
+impl Vcs for Jujutsu {
+    fn name(&self) -> &str {
+        "jj"
+    }
+
+    fn ignore_file(&self) -> &str {
+        ".gitignore"
+    }
+
+    fn init(&self, dir: &Path) -> anyhow::Result<()> {
+        if dir.join(".jj").is_dir() {
+            return Ok(());
+        }
+
+        run("jj", dir, &["git", "init", "--colocate"])?;
+        println!("Initialized jj repository in {}", dir.display());
+        Ok(())
+    }
+
+    fn commit_all(&self, dir: &Path, message: &str) -> anyhow::Result<()> {
+        run("jj", dir, &["commit", "-m", message])?;
+        run("jj", dir, &["bookmark", "create", &self.branch, "-r", "@-"])?;
+        println!("Created commit \"{}\" on bookmark {}", message, self.branch);
+        Ok(())
+    }
+
+    fn add_remote(&self, dir: &Path, name: &str, url: &str) -> anyhow::Result<()> {
+        run("jj", dir, &["git", "remote", "add", name, url])?;
+        println!("Added remote {} {}", name, url);
+        Ok(())
+    }
+
+    fn push(&self, dir: &Path, remote: &str) -> anyhow::Result<()> {
+        run(
+            "jj",
+            dir,
+            &[
+                "git",
+                "push",
+                "--remote",
+                remote,
+                "--bookmark",
+                &self.branch,
+            ],
+        )?;
+        println!("Pushed to {}", remote);
+        Ok(())
+    }
+}
+
+pub struct Mercurial;
+
+impl Vcs for Mercurial {
+    fn name(&self) -> &str {
+        "hg"
+    }
+
+    fn ignore_file(&self) -> &str {
+        ".hgignore"
+    }
+
+    fn init(&self, dir: &Path) -> anyhow::Result<()> {
+        if dir.join(".hg").is_dir() {
+            return Ok(());
+        }
+
+        run("hg", dir, &["init"])?;
+        println!("Initialized hg repository in {}", dir.display());
+        Ok(())
+    }
+
+    fn commit_all(&self, dir: &Path, message: &str) -> anyhow::Result<()> {
+        run("hg", dir, &["addremove"])?;
+        run("hg", dir, &["commit", "-m", message])?;
+        println!("Created commit \"{}\"", message);
+        Ok(())
+    }
+
+    fn add_remote(&self, dir: &Path, name: &str, url: &str) -> anyhow::Result<()> {
+        let name = if name == "origin" { "default" } else { name };
+        let hgrc_path = dir.join(".hg").join("hgrc");
+        let hgrc = fs::read_to_string(&hgrc_path).unwrap_or_default();
+        fs::write(&hgrc_path, add_path(&hgrc, name, url)?)?;
+        println!("Added path {} {}", name, url);
+        Ok(())
+    }
+
+    fn push(&self, dir: &Path, _remote: &str) -> anyhow::Result<()> {
+        run("hg", dir, &["push"])?;
+        println!("Pushed to default");
+        Ok(())
+    }
+}
+
+pub struct NoVcs;
+
+impl Vcs for NoVcs {
+    fn name(&self) -> &str {
+        "none"
+    }
+
+    fn ignore_file(&self) -> &str {
+        ".gitignore"
+    }
+
+    fn init(&self, _dir: &Path) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn commit_all(&self, _dir: &Path, _message: &str) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn add_remote(&self, _dir: &Path, _name: &str, _url: &str) -> anyhow::Result<()> {
+        Err(anyhow!("Cannot add a remote without a VCS"))
+    }
+
+    fn push(&self, _dir: &Path, _remote: &str) -> anyhow::Result<()> {
+        Err(anyhow!("Cannot push without a VCS"))
+    }
+}
+
+// Adds name to the hgrc's [paths] section, creating the section if there is none. Like
+// `git remote add`, an existing path of the same name is an error rather than shadowed.
+fn add_path(hgrc: &str, name: &str, url: &str) -> anyhow::Result<String> {
+    let mut lines: Vec<String> = hgrc.lines().map(String::from).collect();
+    let mut in_paths = false;
+    let mut insert_at = None;
+    for (index, line) in lines.iter().enumerate() {
+        let line = line.trim();
+        if line.starts_with('[') {
+            in_paths = line == "[paths]";
+            if in_paths {
+                insert_at = Some(index + 1);
+            }
+            continue;
+        }
+        if !in_paths || line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+            continue;
+        }
+
+        if line.split('=').next().map(str::trim) == Some(name) {
+            return Err(anyhow!("Path {} already exists in .hg/hgrc", name));
+        }
+        insert_at = Some(index + 1);
+    }
+
+    let path = format!("{} = {}", name, url);
+    match insert_at {
+        Some(index) => lines.insert(index, path),
+        None => {
+            if lines.last().is_some_and(|line| !line.trim().is_empty()) {
+                lines.push(String::new());
+            }
+            lines.push(String::from("[paths]"));
+            lines.push(path);
+        }
+    }
+    Ok(lines.join("\n") + "\n")
+}
+
+fn run(program: &str, dir: &Path, args: &[&str]) -> anyhow::Result<String> {
+    let output = match Command::new(program).args(args).current_dir(dir).output() {
+        Ok(output) => output,
+        Err(error) => return Err(anyhow!("Could not run {}: {}", program, error)),
+    };
+    if !output.status.success() {
+        return Err(anyhow!(
+            "{} {} failed: {}",
+            program,
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::add_path;
+
+    #[test]
+    fn add_path_creates_the_paths_section() {
+        assert_eq!(
+            add_path("", "default", "ssh://hg@example.com/demo").unwrap(),
+            "[paths]\ndefault = ssh://hg@example.com/demo\n"
+        );
+        assert_eq!(
+            add_path("[ui]\nusername = me\n", "default", "../demo").unwrap(),
+            "[ui]\nusername = me\n\n[paths]\ndefault = ../demo\n"
+        );
+    }
+
+    #[test]
+    fn add_path_extends_an_existing_paths_section() {
+        let hgrc = "[paths]\nupstream = ../upstream\n\n[ui]\nusername = me\n";
+        assert_eq!(
+            add_path(hgrc, "default", "../demo").unwrap(),
+            "[paths]\nupstream = ../upstream\ndefault = ../demo\n\n[ui]\nusername = me\n"
+        );
+    }
+
+    #[test]
+    fn add_path_rejects_an_existing_path() {
+        assert!(add_path("[paths]\ndefault = ../other\n", "default", "../demo").is_err());
+    }
+}
@@ -0,0 +1,79 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const VCS_DIRS: &[&str] = &[".git", ".hg", ".jj"];
+
+// Collects every file below dir, skipping directories whose name is in skipped. Symlinks are
+// collected as files and never followed
+pub fn collect_files(dir: &Path, skipped: &[&str], files: &mut Vec<PathBuf>) -> anyhow::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() && !path.is_symlink() {
+            let skip = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| skipped.contains(&name));
+            if !skip {
+                collect_files(&path, skipped, files)?;
+            }
+        } else {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{collect_files, VCS_DIRS};
+    use std::fs;
+    use std::path::PathBuf;
+    use tempfile::TempDir;
+
+    #[test]
+    fn collects_nested_files_and_skips_listed_dirs() {
+        let scratch_dir = TempDir::new().unwrap();
+        let dir = scratch_dir.path();
+        fs::create_dir_all(dir.join("src").join("bin")).unwrap();
+        fs::create_dir_all(dir.join(".git")).unwrap();
+        fs::create_dir_all(dir.join("empty")).unwrap();
+        fs::write(dir.join("README.md"), "").unwrap();
+        fs::write(dir.join("src").join("bin").join("main.rs"), "").unwrap();
+        fs::write(dir.join(".git").join("HEAD"), "").unwrap();
+
+        let mut files = Vec::new();
+        collect_files(dir, VCS_DIRS, &mut files).unwrap();
+        files.sort();
+        let files: Vec<PathBuf> = files
+            .iter()
+            .map(|file| file.strip_prefix(dir).unwrap().to_path_buf())
+            .collect();
+
+        assert_eq!(
+            files,
+            vec![
+                PathBuf::from("README.md"),
+                PathBuf::from("src").join("bin").join("main.rs"),
+            ]
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn collects_symlinks_without_following_them() {
+        let scratch_dir = TempDir::new().unwrap();
+        let dir = scratch_dir.path();
+        fs::create_dir_all(dir.join("target")).unwrap();
+        fs::write(dir.join("target").join("file"), "").unwrap();
+        std::os::unix::fs::symlink("target", dir.join("link")).unwrap();
+
+        let mut files = Vec::new();
+        collect_files(dir, &[], &mut files).unwrap();
+        files.sort();
+
+        assert_eq!(
+            files,
+            vec![dir.join("link"), dir.join("target").join("file")]
+        );
+    }
+}
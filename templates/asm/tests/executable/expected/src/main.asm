@@ -0,0 +1,18 @@
+        global _start
+
+        section .data
+message:
+        db "Hello, world!", 10
+message_len equ $ - message
+
+        section .text
+_start:
+        mov rax, 1              ; write(
+        mov rdi, 1              ;   stdout,
+        mov rsi, message        ;   message,
+        mov rdx, message_len    ;   message_len)
+        syscall
+
+        mov rax, 60             ; exit(
+        xor rdi, rdi            ;   0)
+        syscall
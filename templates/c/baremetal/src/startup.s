@@ -0,0 +1,50 @@
+    .syntax unified
+    .thumb
+
+    .section .isr_vector, "a", %progbits
+    .type vector_table, %object
+vector_table:
+    .word _estack
+    .word Reset_Handler
+    .word Default_Handler /* NMI */
+    .word Default_Handler /* HardFault */
+    .size vector_table, . - vector_table
+
+    .section .text.Reset_Handler, "ax", %progbits
+    .global Reset_Handler
+    .type Reset_Handler, %function
+Reset_Handler:
+    /* Copy .data from flash to RAM */
+    ldr r0, =_sdata
+    ldr r1, =_edata
+    ldr r2, =_sidata
+copy_data:
+    cmp r0, r1
+    bhs zero_bss_start
+    ldr r3, [r2], #4
+    str r3, [r0], #4
+    b copy_data
+
+    /* Zero .bss */
+zero_bss_start:
+    ldr r0, =_sbss
+    ldr r1, =_ebss
+    movs r2, #0
+zero_bss:
+    cmp r0, r1
+    bhs call_main
+    str r2, [r0], #4
+    b zero_bss
+
+call_main:
+    bl main
+hang:
+    b hang
+    .size Reset_Handler, . - Reset_Handler
+
+    .section .text.Default_Handler, "ax", %progbits
+    .weak Default_Handler
+    .type Default_Handler, %function
+Default_Handler:
+    b Default_Handler
+    .size Default_Handler, . - Default_Handler
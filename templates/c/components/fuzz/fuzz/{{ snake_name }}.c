@@ -0,0 +1,9 @@
+#include <stddef.h>
+#include <stdint.h>
+
+int LLVMFuzzerTestOneInput(const uint8_t *data, size_t size) {
+    /* Feed data to the code under test here. */
+    (void)data;
+    (void)size;
+    return 0;
+}
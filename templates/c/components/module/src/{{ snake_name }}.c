@@ -0,0 +1,3 @@
+#include "{{ snake_name }}.h"
+
+void {{ snake_name }}(void) {}
@@ -0,0 +1,6 @@
+#ifndef {{ upper_name }}_H
+#define {{ upper_name }}_H
+
+void {{ snake_name }}(void);
+
+#endif
@@ -0,0 +1,8 @@
+#include <assert.h>
+#include <stdio.h>
+
+int main(void) {
+  assert(2 + 2 == 4);
+  printf("{{ snake_name }}: ok\n");
+  return 0;
+}
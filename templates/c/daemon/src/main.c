@@ -0,0 +1,34 @@
+#include <signal.h>
+#include <stdio.h>
+#include <stdlib.h>
+#include <unistd.h>
+
+static volatile sig_atomic_t running = 1;
+
+static void stop(int sig) {
+  (void)sig;
+  running = 0;
+}
+
+static void run(void) { fprintf(stderr, "Running\n"); }
+
+int main(void) {
+  const char* interval_env = getenv("INTERVAL");
+  int interval = interval_env ? atoi(interval_env) : 0;
+  if (interval <= 0) {
+    interval = 60;
+  }
+
+  struct sigaction action = {0};
+  action.sa_handler = stop;
+  sigaction(SIGINT, &action, NULL);
+  sigaction(SIGTERM, &action, NULL);
+
+  fprintf(stderr, "Starting, running every %ds\n", interval);
+  while (running) {
+    run();
+    sleep(interval);
+  }
+  fprintf(stderr, "Stopping\n");
+  return 0;
+}
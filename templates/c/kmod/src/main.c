@@ -0,0 +1,25 @@
+#define pr_fmt(fmt) KBUILD_MODNAME ": " fmt
+
+#include <linux/init.h>
+#include <linux/kernel.h>
+#include <linux/module.h>
+
+static int __init mod_init(void)
+{
+	pr_info("loaded\n");
+	return 0;
+}
+
+static void __exit mod_exit(void)
+{
+	pr_info("unloaded\n");
+}
+
+module_init(mod_init);
+module_exit(mod_exit);
+
+MODULE_LICENSE("GPL");
+{{#if author}}
+MODULE_AUTHOR("{{ author }}");
+{{/if}}
+MODULE_DESCRIPTION("{{#if description}}{{ description }}{{else}}{{ name }}{{/if}}");
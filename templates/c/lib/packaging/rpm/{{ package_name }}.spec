@@ -0,0 +1,50 @@
+%global sover 0
+
+Name:           {{ package_name }}
+Version:        0.1.0
+Release:        1%{?dist}
+Summary:        {{#if description}}{{ description }}{{else}}The {{ package_name }} library{{/if}}
+License:        {{#if license}}{{ license }}{{else}}Proprietary{{/if}}
+{{#if repository}}
+URL:            {{ repository }}
+{{/if}}
+Source0:        %{name}-%{version}.tar.gz
+BuildRequires:  clang
+
+%description
+{{#if description}}{{ description }}{{else}}The {{ package_name }} library{{/if}}
+
+%package devel
+Summary:        Development files for %{name}
+Requires:       %{name}%{?_isa} = %{version}-%{release}
+
+%description devel
+Headers, the shared object symlink and the pkg-config file for developing against %{name}.
+
+%prep
+%autosetup
+
+%build
+mkdir -p bin
+clang %{optflags} -fPIC -shared %{build_ldflags} -Wl,-soname,lib%{name}.so.%{sover} -o bin/lib%{name}.so.%{version} src/*.c
+
+%install
+install -Dm755 bin/lib%{name}.so.%{version} %{buildroot}%{_libdir}/lib%{name}.so.%{version}
+ln -s lib%{name}.so.%{version} %{buildroot}%{_libdir}/lib%{name}.so.%{sover}
+ln -s lib%{name}.so.%{sover} %{buildroot}%{_libdir}/lib%{name}.so
+install -Dm644 -t %{buildroot}%{_includedir} src/*.h
+mkdir -p %{buildroot}%{_libdir}/pkgconfig
+sed -e 's|^prefix=.*|prefix=%{_prefix}|' -e 's|^libdir=.*|libdir=%{_libdir}|' %{name}.pc > %{buildroot}%{_libdir}/pkgconfig/%{name}.pc
+
+%files
+%{_libdir}/lib%{name}.so.%{sover}
+%{_libdir}/lib%{name}.so.%{version}
+
+%files devel
+%{_libdir}/lib%{name}.so
+%{_includedir}/*
+%{_libdir}/pkgconfig/%{name}.pc
+
+%changelog
+* {{ short_date }} {{ maintainer }} - 0.1.0-1
+- Initial release
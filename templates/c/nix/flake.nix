@@ -0,0 +1,32 @@
+{
+  description = "{{#if description}}{{ description }}{{else}}{{ name }}{{/if}}";
+
+  inputs = {
+    nixpkgs.url = "github:NixOS/nixpkgs/nixos-unstable";
+    flake-utils.url = "github:numtide/flake-utils";
+  };
+
+  outputs = { self, nixpkgs, flake-utils }:
+    flake-utils.lib.eachDefaultSystem (system:
+      let
+        pkgs = nixpkgs.legacyPackages.${system};
+      in
+      {
+{{#if (eq kind "Executable")}}
+        packages.default = pkgs.clangStdenv.mkDerivation {
+          pname = "{{ bin_name }}";
+          version = "0.1.0";
+          src = ./.;
+          nativeBuildInputs = [ pkgs.lld ];
+          installPhase = ''
+            mkdir -p $out/bin
+            cp bin/{{ bin_name }} $out/bin/
+          '';
+        };
+
+{{/if}}
+        devShells.default = pkgs.mkShell.override { stdenv = pkgs.clangStdenv; } {
+          packages = with pkgs; [ clang-tools lld gnumake valgrind ];
+        };
+      });
+}
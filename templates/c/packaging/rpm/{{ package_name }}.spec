@@ -0,0 +1,30 @@
+Name:           {{ package_name }}
+Version:        0.1.0
+Release:        1%{?dist}
+Summary:        {{#if description}}{{ description }}{{else}}The {{ bin_name }} command{{/if}}
+License:        {{#if license}}{{ license }}{{else}}Proprietary{{/if}}
+{{#if repository}}
+URL:            {{ repository }}
+{{/if}}
+Source0:        %{name}-%{version}.tar.gz
+BuildRequires:  clang
+
+%description
+{{#if description}}{{ description }}{{else}}The {{ bin_name }} command{{/if}}
+
+%prep
+%autosetup
+
+%build
+mkdir -p bin
+clang %{optflags} %{build_ldflags} -o bin/{{ bin_name }} src/*.c
+
+%install
+install -Dm755 bin/{{ bin_name }} %{buildroot}%{_bindir}/{{ bin_name }}
+
+%files
+%{_bindir}/{{ bin_name }}
+
+%changelog
+* {{ short_date }} {{ maintainer }} - 0.1.0-1
+- Initial release
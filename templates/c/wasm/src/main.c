@@ -0,0 +1,10 @@
+#include <emscripten/emscripten.h>
+#include <stdio.h>
+
+EMSCRIPTEN_KEEPALIVE
+int add(int a, int b) { return a + b; }
+
+int main(void) {
+    printf("Hello from WebAssembly!\n");
+    return 0;
+}
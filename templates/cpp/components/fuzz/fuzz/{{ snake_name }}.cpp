@@ -0,0 +1,9 @@
+#include <cstddef>
+#include <cstdint>
+
+extern "C" int LLVMFuzzerTestOneInput(const uint8_t *data, size_t size) {
+    // Feed data to the code under test here.
+    static_cast<void>(data);
+    static_cast<void>(size);
+    return 0;
+}
@@ -0,0 +1,3 @@
+#include "{{ snake_name }}.h"
+
+{{ pascal_name }}::{{ pascal_name }}() {}
@@ -0,0 +1,9 @@
+#ifndef {{ upper_name }}_H
+#define {{ upper_name }}_H
+
+class {{ pascal_name }} {
+ public:
+  {{ pascal_name }}();
+};
+
+#endif
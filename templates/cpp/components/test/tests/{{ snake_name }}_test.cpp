@@ -0,0 +1,8 @@
+#include <cassert>
+#include <iostream>
+
+int main() {
+  assert(2 + 2 == 4);
+  std::cout << "{{ snake_name }}: ok" << std::endl;
+  return 0;
+}
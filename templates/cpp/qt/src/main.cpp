@@ -0,0 +1,25 @@
+#include <QApplication>
+#include <QLabel>
+#include <QPushButton>
+#include <QVBoxLayout>
+#include <QWidget>
+
+int main(int argc, char* argv[]) {
+  QApplication app(argc, argv);
+
+  QWidget window;
+  window.setWindowTitle("{{ name }}");
+  window.resize(400, 200);
+
+  auto* label = new QLabel("Hello from {{ name }}");
+  label->setAlignment(Qt::AlignCenter);
+  auto* button = new QPushButton("Quit");
+  QObject::connect(button, &QPushButton::clicked, &app, &QApplication::quit);
+
+  auto* layout = new QVBoxLayout(&window);
+  layout->addWidget(label);
+  layout->addWidget(button);
+
+  window.show();
+  return app.exec();
+}
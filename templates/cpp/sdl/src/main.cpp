@@ -0,0 +1,76 @@
+#include <SDL.h>
+
+#include <cstdio>
+
+namespace {
+
+constexpr int kWidth = 800;
+constexpr int kHeight = 600;
+
+struct Player {
+  float x = kWidth / 2.0f;
+  float y = kHeight / 2.0f;
+  float speed = 300.0f;
+};
+
+void Update(Player& player, const Uint8* keys, float delta) {
+  if (keys[SDL_SCANCODE_LEFT] || keys[SDL_SCANCODE_A]) player.x -= player.speed * delta;
+  if (keys[SDL_SCANCODE_RIGHT] || keys[SDL_SCANCODE_D]) player.x += player.speed * delta;
+  if (keys[SDL_SCANCODE_UP] || keys[SDL_SCANCODE_W]) player.y -= player.speed * delta;
+  if (keys[SDL_SCANCODE_DOWN] || keys[SDL_SCANCODE_S]) player.y += player.speed * delta;
+}
+
+void Render(SDL_Renderer* renderer, const Player& player) {
+  SDL_SetRenderDrawColor(renderer, 30, 30, 40, 255);
+  SDL_RenderClear(renderer);
+
+  SDL_FRect rect = {player.x - 16.0f, player.y - 16.0f, 32.0f, 32.0f};
+  SDL_SetRenderDrawColor(renderer, 240, 200, 80, 255);
+  SDL_RenderFillRectF(renderer, &rect);
+
+  SDL_RenderPresent(renderer);
+}
+
+}  // namespace
+
+int main(int, char*[]) {
+  if (SDL_Init(SDL_INIT_VIDEO) != 0) {
+    std::fprintf(stderr, "SDL_Init: %s\n", SDL_GetError());
+    return 1;
+  }
+
+  SDL_Window* window = SDL_CreateWindow("{{ name }}", SDL_WINDOWPOS_CENTERED,
+                                        SDL_WINDOWPOS_CENTERED, kWidth, kHeight, 0);
+  SDL_Renderer* renderer = SDL_CreateRenderer(
+      window, -1, SDL_RENDERER_ACCELERATED | SDL_RENDERER_PRESENTVSYNC);
+  if (window == nullptr || renderer == nullptr) {
+    std::fprintf(stderr, "SDL: %s\n", SDL_GetError());
+    SDL_Quit();
+    return 1;
+  }
+
+  Player player;
+  Uint64 last = SDL_GetPerformanceCounter();
+  bool running = true;
+  while (running) {
+    SDL_Event event;
+    while (SDL_PollEvent(&event)) {
+      if (event.type == SDL_QUIT ||
+          (event.type == SDL_KEYDOWN && event.key.keysym.sym == SDLK_ESCAPE)) {
+        running = false;
+      }
+    }
+
+    Uint64 now = SDL_GetPerformanceCounter();
+    float delta = static_cast<float>(now - last) / SDL_GetPerformanceFrequency();
+    last = now;
+
+    Update(player, SDL_GetKeyboardState(nullptr), delta);
+    Render(renderer, player);
+  }
+
+  SDL_DestroyRenderer(renderer);
+  SDL_DestroyWindow(window);
+  SDL_Quit();
+  return 0;
+}
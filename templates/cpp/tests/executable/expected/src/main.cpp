@@ -0,0 +1,3 @@
+using namespace "demo";
+
+int main(int argc, char* argv[]) {}
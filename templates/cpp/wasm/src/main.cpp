@@ -0,0 +1,15 @@
+#include <emscripten/emscripten.h>
+
+#include <iostream>
+
+extern "C" {
+
+EMSCRIPTEN_KEEPALIVE
+int add(int a, int b) { return a + b; }
+
+}
+
+int main() {
+  std::cout << "Hello from {{ name }}!" << std::endl;
+  return 0;
+}
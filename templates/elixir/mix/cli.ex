@@ -0,0 +1,10 @@
+defmodule {{ elixir_module }}.CLI do
+  @moduledoc """
+  Entry point for the `{{ bin_name }}` escript.
+  """
+
+  def main(args) do
+    name = List.first(args, "world")
+    IO.puts("Hello, #{name}!")
+  end
+end
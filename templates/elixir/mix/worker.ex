@@ -0,0 +1,32 @@
+defmodule {{ elixir_module }}.Worker do
+  @moduledoc """
+  Does the periodic work of the {{ name }} service.
+  """
+
+  use GenServer
+  require Logger
+
+  @interval :timer.seconds(5)
+
+  def start_link(opts) do
+    GenServer.start_link(__MODULE__, opts, name: __MODULE__)
+  end
+
+  @impl true
+  def init(_opts) do
+    schedule_tick()
+    {:ok, %{ticks: 0}}
+  end
+
+  @impl true
+  def handle_info(:tick, state) do
+    ticks = state.ticks + 1
+    Logger.info("tick #{ticks}")
+    schedule_tick()
+    {:noreply, %{state | ticks: ticks}}
+  end
+
+  defp schedule_tick do
+    Process.send_after(self(), :tick, @interval)
+  end
+end
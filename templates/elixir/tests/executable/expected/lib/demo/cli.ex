@@ -0,0 +1,10 @@
+defmodule Demo.CLI do
+  @moduledoc """
+  Entry point for the `demo` escript.
+  """
+
+  def main(args) do
+    name = List.first(args, "world")
+    IO.puts("Hello, #{name}!")
+  end
+end
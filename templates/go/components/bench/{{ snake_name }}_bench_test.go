@@ -0,0 +1,9 @@
+package {{ go_package }}
+
+import "testing"
+
+func Benchmark{{ pascal_name }}(b *testing.B) {
+	for i := 0; i < b.N; i++ {
+		_ = i * i
+	}
+}
@@ -0,0 +1,21 @@
+package {{ go_package }}
+
+import (
+	"context"
+	"fmt"
+
+	{{ lower_name }}v1 "{{ module_path }}/gen/{{ snake_name }}/v1"
+	"google.golang.org/grpc"
+)
+
+type {{ camel_name }}Server struct {
+	{{ lower_name }}v1.Unimplemented{{ pascal_name }}ServiceServer
+}
+
+func (s *{{ camel_name }}Server) SayHello(ctx context.Context, request *{{ lower_name }}v1.SayHelloRequest) (*{{ lower_name }}v1.SayHelloResponse, error) {
+	return &{{ lower_name }}v1.SayHelloResponse{Message: fmt.Sprintf("Hello, %s!", request.GetName())}, nil
+}
+
+func register{{ pascal_name }}(server *grpc.Server) {
+	{{ lower_name }}v1.Register{{ pascal_name }}ServiceServer(server, &{{ camel_name }}Server{})
+}
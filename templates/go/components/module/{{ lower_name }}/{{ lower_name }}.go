@@ -0,0 +1 @@
+package {{ lower_name }}
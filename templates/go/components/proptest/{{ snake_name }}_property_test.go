@@ -0,0 +1,20 @@
+package {{ go_package }}
+
+import (
+	"slices"
+	"testing"
+
+	"pgregory.net/rapid"
+)
+
+func Test{{ pascal_name }}ReverseTwice(t *testing.T) {
+	rapid.Check(t, func(t *rapid.T) {
+		input := rapid.SliceOf(rapid.Byte()).Draw(t, "input")
+		reversed := slices.Clone(input)
+		slices.Reverse(reversed)
+		slices.Reverse(reversed)
+		if !slices.Equal(reversed, input) {
+			t.Fatalf("reversing %v twice gave %v", input, reversed)
+		}
+	})
+}
@@ -0,0 +1,9 @@
+package {{ go_package }}
+
+import "testing"
+
+func Test{{ pascal_name }}(t *testing.T) {
+	if 2+2 != 4 {
+		t.Fatal("expected 2+2 to equal 4")
+	}
+}
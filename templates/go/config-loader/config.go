@@ -0,0 +1,45 @@
+package main
+
+import (
+	"errors"
+	"os"
+
+	"github.com/spf13/viper"
+)
+
+type Config struct {
+{{#if (eq kind "Grpc")}}
+	Addr string `mapstructure:"addr"`
+{{else}}
+	Interval int `mapstructure:"interval"`
+{{/if}}
+}
+
+// loadConfig layers config.yaml (or the file named by CONFIG) and then the
+// environment over the defaults.
+func loadConfig() (Config, error) {
+	v := viper.New()
+{{#if (eq kind "Grpc")}}
+	v.SetDefault("addr", ":50051")
+{{else}}
+	v.SetDefault("interval", 60)
+{{/if}}
+	v.AutomaticEnv()
+	if path := os.Getenv("CONFIG"); path != "" {
+		v.SetConfigFile(path)
+	} else {
+		v.SetConfigName("config")
+		v.SetConfigType("yaml")
+		v.AddConfigPath(".")
+	}
+
+	var config Config
+	if err := v.ReadInConfig(); err != nil {
+		var notFound viper.ConfigFileNotFoundError
+		if !errors.As(err, &notFound) {
+			return config, err
+		}
+	}
+	err := v.Unmarshal(&config)
+	return config, err
+}
@@ -0,0 +1,102 @@
+package main
+
+import (
+	"context"
+{{#if logging}}
+	"log/slog"
+{{else}}
+	"log"
+{{/if}}
+{{#if (or logging (not config_loader))}}
+	"os"
+{{/if}}
+	"os/signal"
+{{#unless config_loader}}
+	"strconv"
+{{/unless}}
+	"syscall"
+	"time"
+{{#if db}}
+
+	"github.com/jackc/pgx/v5/pgxpool"
+{{/if}}
+)
+
+func main() {
+{{#if logging}}
+	slog.SetDefault(slog.New(slog.NewJSONHandler(os.Stderr, nil)))
+
+{{/if}}
+{{#if config_loader}}
+	config, err := loadConfig()
+	if err != nil {
+{{#if logging}}
+		slog.Error("Invalid configuration", "error", err)
+		os.Exit(1)
+{{else}}
+		log.Fatal(err)
+{{/if}}
+	}
+	interval := config.Interval
+{{else}}
+	interval, err := strconv.Atoi(os.Getenv("INTERVAL"))
+	if err != nil || interval <= 0 {
+		interval = 60
+	}
+{{/if}}
+
+	ctx, stop := signal.NotifyContext(context.Background(), syscall.SIGINT, syscall.SIGTERM)
+	defer stop()
+{{#if db}}
+
+	pool, err := connectDB(ctx)
+	if err != nil {
+{{#if logging}}
+		slog.Error("Could not connect to the database", "error", err)
+		os.Exit(1)
+{{else}}
+		log.Fatal(err)
+{{/if}}
+	}
+	defer pool.Close()
+{{/if}}
+
+{{#if logging}}
+	slog.Info("Starting", "interval", interval)
+{{else}}
+	log.Printf("Starting, running every %ds", interval)
+{{/if}}
+	ticker := time.NewTicker(time.Duration(interval) * time.Second)
+	defer ticker.Stop()
+	for {
+		run({{#if db}}ctx, pool{{/if}})
+		select {
+		case <-ctx.Done():
+{{#if logging}}
+			slog.Info("Stopping")
+{{else}}
+			log.Print("Stopping")
+{{/if}}
+			return
+		case <-ticker.C:
+		}
+	}
+}
+
+func run({{#if db}}ctx context.Context, pool *pgxpool.Pool{{/if}}) {
+{{#if db}}
+	if err := pool.Ping(ctx); err != nil {
+{{#if logging}}
+		slog.Warn("Database is unhealthy", "error", err)
+{{else}}
+		log.Printf("Database is unhealthy: %v", err)
+{{/if}}
+		return
+	}
+{{/if}}
+{{#if logging}}
+	slog.Info("Running")
+{{else}}
+	log.Print("Running")
+{{/if}}
+}
@@ -0,0 +1,81 @@
+package main
+
+import (
+{{#if db}}
+	"context"
+{{/if}}
+{{#if logging}}
+	"log/slog"
+{{else}}
+	"log"
+{{/if}}
+	"net"
+{{#if (or logging (not config_loader))}}
+	"os"
+{{/if}}
+
+	"google.golang.org/grpc"
+)
+
+func main() {
+{{#if logging}}
+	slog.SetDefault(slog.New(slog.NewJSONHandler(os.Stderr, nil)))
+
+{{/if}}
+{{#if config_loader}}
+	config, err := loadConfig()
+	if err != nil {
+{{#if logging}}
+		slog.Error("Invalid configuration", "error", err)
+		os.Exit(1)
+{{else}}
+		log.Fatal(err)
+{{/if}}
+	}
+	addr := config.Addr
+{{else}}
+	addr := os.Getenv("ADDR")
+	if addr == "" {
+		addr = ":50051"
+	}
+{{/if}}
+{{#if db}}
+
+	pool, err := connectDB(context.Background())
+	if err != nil {
+{{#if logging}}
+		slog.Error("Could not connect to the database", "error", err)
+		os.Exit(1)
+{{else}}
+		log.Fatal(err)
+{{/if}}
+	}
+	defer pool.Close()
+{{/if}}
+
+	listener, err := net.Listen("tcp", addr)
+	if err != nil {
+{{#if logging}}
+		slog.Error("Listening failed", "error", err)
+		os.Exit(1)
+{{else}}
+		log.Fatal(err)
+{{/if}}
+	}
+
+	server := grpc.NewServer()
+	registerGreeter(server)
+
+{{#if logging}}
+	slog.Info("Listening", "addr", addr)
+	if err := server.Serve(listener); err != nil {
+		slog.Error("Serving failed", "error", err)
+		os.Exit(1)
+	}
+{{else}}
+	log.Printf("Listening on %s", addr)
+	if err := server.Serve(listener); err != nil {
+		log.Fatal(err)
+	}
+{{/if}}
+}
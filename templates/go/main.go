@@ -1,5 +1,18 @@
 package main
+{{#if logging}}
+
+import (
+	"log/slog"
+	"os"
+)
+
+func main() {
+	slog.SetDefault(slog.New(slog.NewJSONHandler(os.Stderr, nil)))
+	slog.Info("Starting")
+}
+{{else}}
 
 func main() {
 
 }
+{{/if}}
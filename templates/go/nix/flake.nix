@@ -0,0 +1,26 @@
+{
+  description = "{{#if description}}{{ description }}{{else}}{{ name }}{{/if}}";
+
+  inputs = {
+    nixpkgs.url = "github:NixOS/nixpkgs/nixos-unstable";
+    flake-utils.url = "github:numtide/flake-utils";
+  };
+
+  outputs = { self, nixpkgs, flake-utils }:
+    flake-utils.lib.eachDefaultSystem (system:
+      let
+        pkgs = nixpkgs.legacyPackages.${system};
+      in
+      {
+        packages.default = pkgs.buildGoModule {
+          pname = "{{ package_name }}";
+          version = "0.1.0";
+          src = ./.;
+          vendorHash = null;
+        };
+
+        devShells.default = pkgs.mkShell {
+          packages = with pkgs; [ go gopls gotools golangci-lint gnumake ];
+        };
+      });
+}
@@ -0,0 +1,20 @@
+package main
+
+import (
+	"log"
+	"os"
+
+	"github.com/labstack/echo/v4"
+)
+
+func main() {
+	addr := os.Getenv("ADDR")
+	if addr == "" {
+		addr = ":8080"
+	}
+
+	e := echo.New()
+	e.HideBanner = true
+	registerRoutes(e, &Handlers{})
+	log.Fatal(e.Start(addr))
+}
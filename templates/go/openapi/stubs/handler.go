@@ -0,0 +1,7 @@
+{{#with operation}}
+
+// {{ pascal_name }} handles {{ upper_method }} {{ path }}.
+func (h *Handlers) {{ pascal_name }}(c echo.Context) error {
+	return c.NoContent(http.StatusNotImplemented)
+}
+{{/with}}
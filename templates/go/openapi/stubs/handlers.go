@@ -0,0 +1,11 @@
+package main
+
+import (
+	"net/http"
+
+	"github.com/labstack/echo/v4"
+)
+
+// Handlers implements the operations in {{ spec }}. `gen openapi` adds a stub
+// here for each new operation and leaves existing handlers alone.
+type Handlers struct{}
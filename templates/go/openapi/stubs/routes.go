@@ -0,0 +1,11 @@
+// Code generated by `gen openapi` from {{ spec }}. DO NOT EDIT.
+
+package main
+
+import "github.com/labstack/echo/v4"
+
+func registerRoutes(e *echo.Echo, h *Handlers) {
+{{#each operations}}
+	e.{{ upper_method }}("{{ colon_path }}", h.{{ pascal_name }})
+{{/each}}
+}
@@ -0,0 +1,24 @@
+class {{ formula_class }} < Formula
+  desc "{{#if description}}{{ description }}{{else}}The {{ bin_name }} command{{/if}}"
+{{#if repository}}
+  homepage "{{ repository }}"
+  url "{{ repository }}.git", tag: "v0.1.0"
+  head "{{ repository }}.git", branch: "main"
+{{else}}
+  homepage "https://example.com/{{ package_name }}"
+  url "https://example.com/{{ package_name }}.git", tag: "v0.1.0"
+{{/if}}
+{{#if license}}
+  license "{{ license }}"
+{{/if}}
+
+  depends_on "go" => :build
+
+  def install
+    system "go", "build", *std_go_args(output: bin/"{{ bin_name }}")
+  end
+
+  test do
+    assert_predicate bin/"{{ bin_name }}", :exist?
+  end
+end
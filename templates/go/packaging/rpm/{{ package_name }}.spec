@@ -0,0 +1,34 @@
+%global debug_package %{nil}
+
+Name:           {{ package_name }}
+Version:        0.1.0
+Release:        1%{?dist}
+Summary:        {{#if description}}{{ description }}{{else}}The {{ bin_name }} command{{/if}}
+License:        {{#if license}}{{ license }}{{else}}Proprietary{{/if}}
+{{#if repository}}
+URL:            {{ repository }}
+{{/if}}
+Source0:        %{name}-%{version}.tar.gz
+BuildRequires:  golang
+
+%description
+{{#if description}}{{ description }}{{else}}The {{ bin_name }} command{{/if}}
+
+%prep
+%autosetup
+
+%build
+go build -o bin/{{ bin_name }}
+
+%check
+go test ./...
+
+%install
+install -Dm755 bin/{{ bin_name }} %{buildroot}%{_bindir}/{{ bin_name }}
+
+%files
+%{_bindir}/{{ bin_name }}
+
+%changelog
+* {{ short_date }} {{ maintainer }} - 0.1.0-1
+- Initial release
@@ -0,0 +1,32 @@
+package {{ package }};
+
+import {{ package }}.{{ snake_name }}.v1.{{ pascal_name }}ServiceGrpc;
+import {{ package }}.{{ snake_name }}.v1.SayHelloRequest;
+import {{ package }}.{{ snake_name }}.v1.SayHelloResponse;
+import io.grpc.Server;
+import io.grpc.ServerBuilder;
+import io.grpc.stub.StreamObserver;
+
+public class {{ pascal_name }}Server {
+    static class {{ pascal_name }}Service extends {{ pascal_name }}ServiceGrpc.{{ pascal_name }}ServiceImplBase {
+        @Override
+        public void sayHello(SayHelloRequest request, StreamObserver<SayHelloResponse> responseObserver) {
+            SayHelloResponse response = SayHelloResponse.newBuilder()
+                .setMessage("Hello, " + request.getName() + "!")
+                .build();
+            responseObserver.onNext(response);
+            responseObserver.onCompleted();
+        }
+    }
+
+    public static void main(String[] args) throws Exception {
+        int port = Integer.parseInt(System.getenv().getOrDefault("PORT", "50051"));
+        Server server = ServerBuilder.forPort(port)
+            .addService(new {{ pascal_name }}Service())
+            .build()
+            .start();
+        System.out.println("Listening on " + port);
+        Runtime.getRuntime().addShutdownHook(new Thread(server::shutdown));
+        server.awaitTermination();
+    }
+}
@@ -0,0 +1,4 @@
+package {{ package }};
+
+public class {{ pascal_name }} {
+}
@@ -0,0 +1,18 @@
+package {{ package }};
+
+import java.util.ArrayList;
+import java.util.Collections;
+import java.util.List;
+
+import net.jqwik.api.ForAll;
+import net.jqwik.api.Property;
+
+class {{ pascal_name }}Properties {
+    @Property
+    boolean reversingTwiceIsIdentity(@ForAll List<Integer> input) {
+        List<Integer> reversed = new ArrayList<>(input);
+        Collections.reverse(reversed);
+        Collections.reverse(reversed);
+        return reversed.equals(input);
+    }
+}
@@ -0,0 +1,12 @@
+package {{ package }};
+
+import static org.junit.Assert.assertEquals;
+
+import org.junit.Test;
+
+public class {{ pascal_name }}Test {
+    @Test
+    public void {{ camel_name }}() {
+        assertEquals(4, 2 + 2);
+    }
+}
@@ -0,0 +1,39 @@
+package {{ package }};
+
+import java.io.IOException;
+import org.springframework.boot.context.properties.bind.Binder;
+import org.springframework.boot.env.YamlPropertySourceLoader;
+import org.springframework.core.env.StandardEnvironment;
+import org.springframework.core.io.ClassPathResource;
+import org.springframework.core.io.FileSystemResource;
+import org.springframework.core.io.Resource;
+
+/**
+ * Settings under the {@code app} prefix of application.yaml (or the file named by CONFIG),
+ * overridden by environment variables such as APP_PORT.
+ */
+public class AppConfig {
+    private int port = 50051;
+
+    public int getPort() {
+        return port;
+    }
+
+    public void setPort(int port) {
+        this.port = port;
+    }
+
+    public static AppConfig load() throws IOException {
+        StandardEnvironment environment = new StandardEnvironment();
+        String path = System.getenv("CONFIG");
+        Resource resource = path != null
+            ? new FileSystemResource(path)
+            : new ClassPathResource("application.yaml");
+        if (resource.exists()) {
+            new YamlPropertySourceLoader()
+                .load("application", resource)
+                .forEach(environment.getPropertySources()::addLast);
+        }
+        return Binder.get(environment).bindOrCreate("app", AppConfig.class);
+    }
+}
@@ -0,0 +1,32 @@
+package {{ package }};
+
+import com.zaxxer.hikari.HikariConfig;
+import com.zaxxer.hikari.HikariDataSource;
+import java.sql.Connection;
+import java.sql.SQLException;
+import javax.sql.DataSource;
+import org.flywaydb.core.Flyway;
+
+public final class Database {
+    private Database() {
+    }
+
+    /** Connects to JDBC_DATABASE_URL and applies the migrations in db/migration. */
+    public static HikariDataSource connect() {
+        HikariConfig config = new HikariConfig();
+        config.setJdbcUrl(System.getenv().getOrDefault(
+            "JDBC_DATABASE_URL", "jdbc:postgresql://localhost:5432/app?user=app&password=app"));
+        config.setMaximumPoolSize(5);
+        HikariDataSource dataSource = new HikariDataSource(config);
+        Flyway.configure().dataSource(dataSource).load().migrate();
+        return dataSource;
+    }
+
+    public static boolean isHealthy(DataSource dataSource) {
+        try (Connection connection = dataSource.getConnection()) {
+            return connection.isValid(5);
+        } catch (SQLException e) {
+            return false;
+        }
+    }
+}
@@ -0,0 +1,36 @@
+// Code generated by `gen openapi` from {{ spec }}. DO NOT EDIT.
+
+package {{ package }};
+
+{{#if request_bodies}}
+import com.fasterxml.jackson.databind.JsonNode;
+{{/if}}
+import org.springframework.http.HttpStatus;
+import org.springframework.http.ResponseEntity;
+{{#if path_params}}
+import org.springframework.web.bind.annotation.PathVariable;
+{{/if}}
+{{#if request_bodies}}
+import org.springframework.web.bind.annotation.RequestBody;
+{{/if}}
+import org.springframework.web.bind.annotation.RequestMapping;
+import org.springframework.web.bind.annotation.RequestMethod;
+
+/**
+ * The operations in {{ spec }}. Each one answers 501 Not Implemented until
+ * {@link ApiController} overrides it.
+ */
+public interface Api {
+{{#each operations}}
+{{#unless @first}}
+
+{{/unless}}
+    /** {{ upper_method }} {{ path }} */
+    @RequestMapping(method = RequestMethod.{{ upper_method }}, path = "{{ path }}")
+    default ResponseEntity<?> {{ camel_name }}({{#each params}}
+            @PathVariable("{{ name }}") String {{ camel_name }}{{#if (or (not @last) ../has_body)}},{{/if}}{{/each}}{{#if has_body}}
+            @RequestBody JsonNode body{{/if}}) {
+        return ResponseEntity.status(HttpStatus.NOT_IMPLEMENTED).build();
+    }
+{{/each}}
+}
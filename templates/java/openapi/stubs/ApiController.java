@@ -0,0 +1,11 @@
+package {{ package }};
+
+import org.springframework.web.bind.annotation.RestController;
+
+/**
+ * Implements the operations in {@link Api}. `gen openapi` keeps Api in sync
+ * with {{ spec }}, so override its methods here.
+ */
+@RestController
+public class ApiController implements Api {
+}
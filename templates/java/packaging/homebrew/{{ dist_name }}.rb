@@ -0,0 +1,28 @@
+class {{ formula_class }} < Formula
+  desc "{{#if description}}{{ description }}{{else}}The {{ bin_name }} command{{/if}}"
+{{#if repository}}
+  homepage "{{ repository }}"
+  url "{{ repository }}.git", tag: "v0.1.0"
+  head "{{ repository }}.git", branch: "main"
+{{else}}
+  homepage "https://example.com/{{ package_name }}"
+  url "https://example.com/{{ package_name }}.git", tag: "v0.1.0"
+{{/if}}
+{{#if license}}
+  license "{{ license }}"
+{{/if}}
+
+  depends_on "maven" => :build
+  depends_on "openjdk"
+
+  def install
+    system "mvn", "-B", "-q", "compile"
+    system "jar", "cfm", "target/{{ package_name }}.jar", "manifest.txt", "-C", "target/classes/", "."
+    libexec.install "target/{{ package_name }}.jar"
+    bin.write_jar_script libexec/"{{ package_name }}.jar", "{{ bin_name }}"
+  end
+
+  test do
+    assert_predicate bin/"{{ bin_name }}", :exist?
+  end
+end
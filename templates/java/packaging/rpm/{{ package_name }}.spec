@@ -0,0 +1,40 @@
+Name:           {{ package_name }}
+Version:        0.1.0
+Release:        1%{?dist}
+Summary:        {{#if description}}{{ description }}{{else}}The {{ bin_name }} command{{/if}}
+License:        {{#if license}}{{ license }}{{else}}Proprietary{{/if}}
+{{#if repository}}
+URL:            {{ repository }}
+{{/if}}
+Source0:        %{name}-%{version}.tar.gz
+BuildArch:      noarch
+BuildRequires:  maven
+BuildRequires:  java-devel
+Requires:       java-headless
+
+%description
+{{#if description}}{{ description }}{{else}}The {{ bin_name }} command{{/if}}
+
+%prep
+%autosetup
+
+%build
+mvn -B -q compile
+jar cfm target/{{ package_name }}.jar manifest.txt -C target/classes/ .
+
+%check
+mvn -B -q test
+
+%install
+install -Dm644 target/{{ package_name }}.jar %{buildroot}%{_javadir}/{{ package_name }}.jar
+mkdir -p %{buildroot}%{_bindir}
+printf '#!/bin/sh\nexec java -jar %{_javadir}/{{ package_name }}.jar "$@"\n' > %{buildroot}%{_bindir}/{{ bin_name }}
+chmod 755 %{buildroot}%{_bindir}/{{ bin_name }}
+
+%files
+%{_javadir}/{{ package_name }}.jar
+%{_bindir}/{{ bin_name }}
+
+%changelog
+* {{ short_date }} {{ maintainer }} - 0.1.0-1
+- Initial release
@@ -0,0 +1,7 @@
+function love.conf(t)
+   t.identity = "{{ package_name }}"
+   t.version = "11.5"
+   t.window.title = "{{ name }}"
+   t.window.width = 800
+   t.window.height = 600
+end
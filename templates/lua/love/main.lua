@@ -0,0 +1,22 @@
+love.filesystem.setRequirePath("src/?.lua;src/?/init.lua;" .. love.filesystem.getRequirePath())
+
+local {{ module }} = require("{{ module }}")
+
+local message
+
+function love.load()
+   message = {{ module }}.greet("LÖVE")
+end
+
+function love.keypressed(key)
+   if key == "escape" then
+      love.event.quit()
+   end
+end
+
+function love.draw()
+   local font = love.graphics.getFont()
+   local x = (love.graphics.getWidth() - font:getWidth(message)) / 2
+   local y = (love.graphics.getHeight() - font:getHeight()) / 2
+   love.graphics.print(message, x, y)
+end
@@ -0,0 +1,5 @@
+#!/usr/bin/env lua
+
+local {{ module }} = require("{{ module }}")
+
+print({{ module }}.greet(arg[1] or "world"))
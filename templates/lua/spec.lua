@@ -0,0 +1,7 @@
+local {{ module }} = require("{{ module }}")
+
+describe("{{ module }}", function()
+   it("greets by name", function()
+      assert.are.equal("Hello, world!", {{ module }}.greet("world"))
+   end)
+end)
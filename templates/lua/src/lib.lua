@@ -0,0 +1,9 @@
+local {{ module }} = {}
+
+{{ module }}._VERSION = "0.1.0"
+
+function {{ module }}.greet(name)
+   return ("Hello, %s!"):format(name)
+end
+
+return {{ module }}
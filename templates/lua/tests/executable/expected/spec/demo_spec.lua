@@ -0,0 +1,7 @@
+local demo = require("demo")
+
+describe("demo", function()
+   it("greets by name", function()
+      assert.are.equal("Hello, world!", demo.greet("world"))
+   end)
+end)
@@ -0,0 +1,9 @@
+local demo = {}
+
+demo._VERSION = "0.1.0"
+
+function demo.greet(name)
+   return ("Hello, %s!"):format(name)
+end
+
+return demo
@@ -0,0 +1,16 @@
+<?php
+
+declare(strict_types=1);
+
+namespace {{ module }}\Tests;
+
+use {{ module }}\Greeter;
+use PHPUnit\Framework\TestCase;
+
+final class GreeterTest extends TestCase
+{
+    public function testGreetsByName(): void
+    {
+        $this->assertSame('Hello, world!', (new Greeter())->greet('world'));
+    }
+}
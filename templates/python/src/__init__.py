@@ -0,0 +1,7 @@
+"""{{ name }}."""
+
+__version__ = "0.1.0"
+
+
+def greet(name: str = "world") -> str:
+    return f"Hello, {name}!"
@@ -0,0 +1,12 @@
+import sys
+
+from {{ module }} import greet
+
+
+def main() -> int:
+    print(greet(*sys.argv[1:2]))
+    return 0
+
+
+if __name__ == "__main__":
+    sys.exit(main())
@@ -0,0 +1,9 @@
+from {{ module }} import greet
+
+
+def test_greet() -> None:
+    assert greet() == "Hello, world!"
+
+
+def test_greet_name() -> None:
+    assert greet("{{ module }}") == "Hello, {{ module }}!"
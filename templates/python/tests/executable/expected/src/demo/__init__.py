@@ -0,0 +1,7 @@
+"""demo."""
+
+__version__ = "0.1.0"
+
+
+def greet(name: str = "world") -> str:
+    return f"Hello, {name}!"
@@ -0,0 +1,9 @@
+from demo import greet
+
+
+def test_greet() -> None:
+    assert greet() == "Hello, world!"
+
+
+def test_greet_name() -> None:
+    assert greet("demo") == "Hello, demo!"
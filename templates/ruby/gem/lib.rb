@@ -0,0 +1,9 @@
+require_relative "{{ module }}/version"
+
+module {{ ruby_module }}
+  class Error < StandardError; end
+
+  def self.greet(name = "world")
+    "Hello, #{name}!"
+  end
+end
@@ -0,0 +1,13 @@
+RSpec.describe {{ ruby_module }} do
+  it "has a version number" do
+    expect({{ ruby_module }}::VERSION).not_to be_nil
+  end
+
+  it "greets the world by default" do
+    expect({{ ruby_module }}.greet).to eq("Hello, world!")
+  end
+
+  it "greets by name" do
+    expect({{ ruby_module }}.greet("{{ module }}")).to eq("Hello, {{ module }}!")
+  end
+end
@@ -0,0 +1,8 @@
+require "{{ module }}"
+
+RSpec.configure do |config|
+  config.disable_monkey_patching!
+  config.expect_with :rspec do |expectations|
+    expectations.syntax = :expect
+  end
+end
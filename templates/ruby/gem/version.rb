@@ -0,0 +1,3 @@
+module {{ ruby_module }}
+  VERSION = "0.1.0"
+end
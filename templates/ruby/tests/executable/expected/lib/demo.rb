@@ -0,0 +1,9 @@
+require_relative "demo/version"
+
+module Demo
+  class Error < StandardError; end
+
+  def self.greet(name = "world")
+    "Hello, #{name}!"
+  end
+end
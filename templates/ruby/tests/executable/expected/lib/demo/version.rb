@@ -0,0 +1,3 @@
+module Demo
+  VERSION = "0.1.0"
+end
@@ -0,0 +1,13 @@
+RSpec.describe Demo do
+  it "has a version number" do
+    expect(Demo::VERSION).not_to be_nil
+  end
+
+  it "greets the world by default" do
+    expect(Demo.greet).to eq("Hello, world!")
+  end
+
+  it "greets by name" do
+    expect(Demo.greet("demo")).to eq("Hello, demo!")
+  end
+end
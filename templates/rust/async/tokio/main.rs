@@ -0,0 +1,26 @@
+{{#if error_style}}
+mod error;
+
+{{/if}}
+{{#if logging}}
+use tracing_subscriber::EnvFilter;
+
+{{/if}}
+#[tokio::main]
+async fn main(){{#if error_style}} -> error::Result<()>{{/if}} {
+{{#if logging}}
+    tracing_subscriber::fmt()
+        .with_env_filter(
+            EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info")),
+        )
+        .with_writer(std::io::stderr)
+        .init();
+    tracing::info!(version = env!("CARGO_PKG_VERSION"), "Starting");
+
+{{/if}}
+    let greeting = tokio::spawn(async { "Hello, world!" });
+    println!("{}", greeting.await.unwrap());
+{{#if error_style}}
+    Ok(())
+{{/if}}
+}
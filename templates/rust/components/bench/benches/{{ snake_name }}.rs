@@ -0,0 +1,9 @@
+use criterion::Criterion;
+use criterion::{black_box, criterion_group, criterion_main};
+
+fn {{ snake_name }}(c: &mut Criterion) {
+    c.bench_function("{{ snake_name }}", |b| b.iter(|| black_box(2 + 2)));
+}
+
+criterion_group!(benches, {{ snake_name }});
+criterion_main!(benches);
@@ -0,0 +1,29 @@
+use tonic::{Request, Response, Status};
+
+pub mod proto {
+    tonic::include_proto!("{{ snake_name }}.v1");
+}
+
+use proto::{{ snake_name }}_service_server::{{ pascal_name }}Service;
+use proto::{{ snake_name }}_service_server::{{ pascal_name }}ServiceServer;
+use proto::{SayHelloRequest, SayHelloResponse};
+
+#[derive(Debug, Default)]
+pub struct {{ pascal_name }};
+
+#[tonic::async_trait]
+impl {{ pascal_name }}Service for {{ pascal_name }} {
+    async fn say_hello(
+        &self,
+        request: Request<SayHelloRequest>,
+    ) -> Result<Response<SayHelloResponse>, Status> {
+        let name = request.into_inner().name;
+        Ok(Response::new(SayHelloResponse {
+            message: format!("Hello, {}!", name),
+        }))
+    }
+}
+
+pub fn server() -> {{ pascal_name }}ServiceServer<{{ pascal_name }}> {
+    {{ pascal_name }}ServiceServer::new({{ pascal_name }})
+}
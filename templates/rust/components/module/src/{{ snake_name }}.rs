@@ -0,0 +1 @@
+pub fn {{ snake_name }}() {}
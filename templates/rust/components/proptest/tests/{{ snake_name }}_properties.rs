@@ -0,0 +1,11 @@
+use proptest::prelude::*;
+
+proptest! {
+    #[test]
+    fn reversing_twice_is_identity(input in any::<Vec<u8>>()) {
+        let mut reversed = input.clone();
+        reversed.reverse();
+        reversed.reverse();
+        prop_assert_eq!(reversed, input);
+    }
+}
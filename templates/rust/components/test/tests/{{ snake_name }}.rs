@@ -0,0 +1,4 @@
+#[test]
+fn {{ snake_name }}() {
+    assert_eq!(2 + 2, 4);
+}
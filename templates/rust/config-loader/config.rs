@@ -0,0 +1,42 @@
+use figment::Figment;
+use figment::providers::{Env, Format, Serialized, Toml};
+use serde::{Deserialize, Serialize};
+use std::env;
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Config {
+{{#if (eq kind "Grpc")}}
+    pub addr: String,
+{{else}}
+    pub interval: u64,
+{{/if}}
+}
+
+impl Default for Config {
+    fn default() -> Config {
+{{#if (eq kind "Grpc")}}
+        Config {
+            addr: String::from("[::1]:50051"),
+        }
+{{else}}
+        Config { interval: 60 }
+{{/if}}
+    }
+}
+
+impl Config {
+    /// Layers `config.toml` (or the file named by `CONFIG`) and then the environment over the
+    /// defaults.
+    pub fn load() -> Result<Config, Box<figment::Error>> {
+        let path = env::var("CONFIG").unwrap_or_else(|_| String::from("config.toml"));
+        Figment::from(Serialized::defaults(Config::default()))
+            .merge(Toml::file(path))
+{{#if (eq kind "Grpc")}}
+            .merge(Env::raw().only(&["ADDR"]))
+{{else}}
+            .merge(Env::raw().only(&["INTERVAL"]))
+{{/if}}
+            .extract()
+            .map_err(Box::new)
+    }
+}
@@ -0,0 +1,87 @@
+{{#if config_loader}}
+mod config;
+{{/if}}
+{{#if db}}
+mod db;
+{{/if}}
+{{#if (or config_loader db)}}
+
+{{/if}}
+{{#unless config_loader}}
+use std::env;
+{{/unless}}
+{{#if (or config_loader db)}}
+use std::process;
+{{/if}}
+use std::thread;
+use std::time::Duration;
+{{#if logging}}
+use tracing_subscriber::EnvFilter;
+{{/if}}
+
+fn main() {
+{{#if logging}}
+    tracing_subscriber::fmt()
+        .with_env_filter(
+            EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info")),
+        )
+        .with_writer(std::io::stderr)
+        .init();
+
+{{/if}}
+{{#if config_loader}}
+    let interval = match config::Config::load() {
+        Ok(config) => config.interval,
+        Err(error) => {
+            eprintln!("Invalid configuration: {}", error);
+            process::exit(1);
+        }
+    };
+{{else}}
+    let interval = env::var("INTERVAL")
+        .ok()
+        .and_then(|interval| interval.parse().ok())
+        .unwrap_or(60);
+{{/if}}
+{{#if db}}
+    let pool = match db::connect() {
+        Ok(pool) => pool,
+        Err(error) => {
+{{#if logging}}
+            tracing::error!(%error, "Could not connect to the database");
+{{else}}
+            eprintln!("Could not connect to the database: {}", error);
+{{/if}}
+            process::exit(1);
+        }
+    };
+{{/if}}
+{{#if logging}}
+    tracing::info!(interval, "Starting");
+{{else}}
+    eprintln!("Starting, running every {}s", interval);
+{{/if}}
+
+    loop {
+        run({{#if db}}&pool{{/if}});
+        thread::sleep(Duration::from_secs(interval));
+    }
+}
+
+fn run({{#if db}}pool: &db::DbPool{{/if}}) {
+{{#if db}}
+    if let Err(error) = db::health(pool) {
+{{#if logging}}
+        tracing::warn!(%error, "Database is unhealthy");
+{{else}}
+        eprintln!("Database is unhealthy: {}", error);
+{{/if}}
+        return;
+    }
+{{/if}}
+{{#if logging}}
+    tracing::info!("Running");
+{{else}}
+    eprintln!("Running");
+{{/if}}
+}
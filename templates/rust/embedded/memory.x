@@ -0,0 +1,6 @@
+/* Memory layout of the target chip, adjust ORIGIN and LENGTH to match its datasheet */
+MEMORY
+{
+  FLASH : ORIGIN = 0x08000000, LENGTH = 512K
+  RAM : ORIGIN = 0x20000000, LENGTH = 128K
+}
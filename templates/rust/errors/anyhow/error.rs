@@ -0,0 +1,5 @@
+{{#if (eq kind "Library")}}
+pub use anyhow::{Context, Error, Result};
+{{else}}
+pub use anyhow::Result;
+{{/if}}
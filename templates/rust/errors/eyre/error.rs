@@ -0,0 +1,5 @@
+{{#if (eq kind "Library")}}
+pub use eyre::{Report as Error, Result, WrapErr};
+{{else}}
+pub use eyre::Result;
+{{/if}}
@@ -0,0 +1,15 @@
+//! Only compiled with the `{{ feature }}` feature enabled.
+
+pub fn enabled() -> bool {
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn feature_is_enabled() {
+        assert!(enabled());
+    }
+}
@@ -0,0 +1,30 @@
+//! Async support, compiled with the `tokio` feature.
+
+use std::future::Future;
+use std::time::Duration;
+
+/// Runs `future`, giving up after `timeout`.
+pub async fn with_timeout<F: Future>(future: F, timeout: Duration) -> Option<F::Output> {
+    tokio::time::timeout(timeout, future).await.ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn completes_before_timeout() {
+        let result = with_timeout(async { 42 }, Duration::from_secs(1)).await;
+        assert_eq!(result, Some(42));
+    }
+
+    #[tokio::test]
+    async fn times_out() {
+        let result = with_timeout(
+            tokio::time::sleep(Duration::from_secs(1)),
+            Duration::from_millis(10),
+        )
+        .await;
+        assert_eq!(result, None);
+    }
+}
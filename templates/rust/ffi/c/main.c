@@ -0,0 +1,18 @@
+#include <stdio.h>
+
+#include "{{ lib_name }}.h"
+
+int main(int argc, char **argv) {
+    const char *name = argc > 1 ? argv[1] : "world";
+
+    char *greeting = {{ lib_name }}_greet(name);
+    if (greeting == NULL) {
+        fprintf(stderr, "could not greet %s\n", name);
+        return 1;
+    }
+    printf("%s\n", greeting);
+    {{ lib_name }}_string_free(greeting);
+
+    printf("2 + 3 = %d\n", {{ lib_name }}_add(2, 3));
+    return 0;
+}
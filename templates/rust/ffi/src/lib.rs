@@ -0,0 +1,55 @@
+use std::ffi::{c_char, CStr, CString};
+use std::ptr;
+
+/// Adds two numbers, wrapping on overflow.
+#[unsafe(no_mangle)]
+pub extern "C" fn {{ lib_name }}_add(left: i32, right: i32) -> i32 {
+    left.wrapping_add(right)
+}
+
+/// Returns a greeting for `name`, or NULL if `name` is NULL or not valid UTF-8.
+/// The result must be released with `{{ lib_name }}_string_free`.
+///
+/// # Safety
+///
+/// `name` must be NULL or point to a NUL-terminated string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn {{ lib_name }}_greet(name: *const c_char) -> *mut c_char {
+    if name.is_null() {
+        return ptr::null_mut();
+    }
+    let Ok(name) = unsafe { CStr::from_ptr(name) }.to_str() else {
+        return ptr::null_mut();
+    };
+    match CString::new(format!("Hello, {name}!")) {
+        Ok(greeting) => greeting.into_raw(),
+        Err(_) => ptr::null_mut(),
+    }
+}
+
+/// Releases a string returned by this library.
+///
+/// # Safety
+///
+/// `string` must be NULL or a pointer returned by this library that has not been freed yet.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn {{ lib_name }}_string_free(string: *mut c_char) {
+    if !string.is_null() {
+        drop(unsafe { CString::from_raw(string) });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn greets() {
+        let name = CString::new("FFI").unwrap();
+        unsafe {
+            let greeting = {{ lib_name }}_greet(name.as_ptr());
+            assert_eq!(CStr::from_ptr(greeting).to_str(), Ok("Hello, FFI!"));
+            {{ lib_name }}_string_free(greeting);
+        }
+    }
+}
@@ -0,0 +1,50 @@
+{{#if config_loader}}
+mod config;
+{{/if}}
+{{#if db}}
+mod db;
+{{/if}}
+mod greeter;
+
+{{#unless config_loader}}
+use std::env;
+{{/unless}}
+use tonic::transport::Server;
+{{#if logging}}
+use tracing_subscriber::EnvFilter;
+{{/if}}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+{{#if logging}}
+    tracing_subscriber::fmt()
+        .with_env_filter(
+            EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info")),
+        )
+        .with_writer(std::io::stderr)
+        .init();
+
+{{/if}}
+{{#if config_loader}}
+    let addr = config::Config::load()?.addr.parse()?;
+{{else}}
+    let addr = env::var("ADDR")
+        .unwrap_or_else(|_| String::from("[::1]:50051"))
+        .parse()?;
+{{/if}}
+{{#if db}}
+    let pool = db::connect().await?;
+    db::health(&pool).await?;
+{{/if}}
+{{#if logging}}
+    tracing::info!(%addr, "Listening");
+{{else}}
+    eprintln!("Listening on {}", addr);
+{{/if}}
+
+    Server::builder()
+        .add_service(greeter::server())
+        .serve(addr)
+        .await?;
+    Ok(())
+}
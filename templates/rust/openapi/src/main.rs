@@ -0,0 +1,14 @@
+mod handlers;
+mod routes;
+
+use std::env;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let addr = env::var("ADDR").unwrap_or_else(|_| String::from("0.0.0.0:8080"));
+    let listener = tokio::net::TcpListener::bind(&addr).await?;
+    eprintln!("Listening on {}", addr);
+
+    axum::serve(listener, routes::router()).await?;
+    Ok(())
+}
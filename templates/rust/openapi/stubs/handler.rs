@@ -0,0 +1,7 @@
+{{#with operation}}
+
+/// `{{ upper_method }} {{ path }}`{{#if summary}}: {{ summary }}{{/if}}
+pub async fn {{ name }}({{#if params.[1]}}Path(({{#each params}}{{#unless @first}}, {{/unless}}_{{ snake_name }}{{/each}})): Path<({{#each params}}{{#unless @first}}, {{/unless}}String{{/each}})>{{else}}{{#if params}}Path(_{{ params.[0].snake_name }}): Path<String>{{/if}}{{/if}}{{#if has_body}}{{#if params}}, {{/if}}Json(_body): Json<Value>{{/if}}) -> StatusCode {
+    StatusCode::NOT_IMPLEMENTED
+}
+{{/with}}
@@ -0,0 +1,12 @@
+// Code generated by `gen openapi` from {{ spec }}. DO NOT EDIT.
+
+use crate::handlers;
+use axum::routing;
+use axum::Router;
+
+pub fn router() -> Router {
+    Router::new()
+{{#each paths}}
+        .route("{{ path }}", {{#each operations}}{{#if @first}}routing::{{ method }}{{else}}.{{ method }}{{/if}}(handlers::{{ name }}){{/each}})
+{{/each}}
+}
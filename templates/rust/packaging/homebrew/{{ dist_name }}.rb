@@ -0,0 +1,24 @@
+class {{ formula_class }} < Formula
+  desc "{{#if description}}{{ description }}{{else}}The {{ bin_name }} command{{/if}}"
+{{#if repository}}
+  homepage "{{ repository }}"
+  url "{{ repository }}.git", tag: "v0.1.0"
+  head "{{ repository }}.git", branch: "main"
+{{else}}
+  homepage "https://example.com/{{ package_name }}"
+  url "https://example.com/{{ package_name }}.git", tag: "v0.1.0"
+{{/if}}
+{{#if license}}
+  license "{{ license }}"
+{{/if}}
+
+  depends_on "rust" => :build
+
+  def install
+    system "cargo", "install", *std_cargo_args
+  end
+
+  test do
+    assert_predicate bin/"{{ bin_name }}", :exist?
+  end
+end
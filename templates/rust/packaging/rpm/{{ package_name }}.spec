@@ -0,0 +1,35 @@
+%global debug_package %{nil}
+
+Name:           {{ package_name }}
+Version:        0.1.0
+Release:        1%{?dist}
+Summary:        {{#if description}}{{ description }}{{else}}The {{ bin_name }} command{{/if}}
+License:        {{#if license}}{{ license }}{{else}}Proprietary{{/if}}
+{{#if repository}}
+URL:            {{ repository }}
+{{/if}}
+Source0:        %{name}-%{version}.tar.gz
+BuildRequires:  cargo
+BuildRequires:  rust
+
+%description
+{{#if description}}{{ description }}{{else}}The {{ bin_name }} command{{/if}}
+
+%prep
+%autosetup
+
+%build
+cargo build --release
+
+%check
+cargo test --release
+
+%install
+install -Dm755 target/release/{{ bin_name }} %{buildroot}%{_bindir}/{{ bin_name }}
+
+%files
+%{_bindir}/{{ bin_name }}
+
+%changelog
+* {{ short_date }} {{ maintainer }} - 0.1.0-1
+- Initial release
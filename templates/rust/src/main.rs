@@ -1,3 +1,24 @@
-fn main() {
+{{#if error_style}}
+mod error;
+
+{{/if}}
+{{#if logging}}
+use tracing_subscriber::EnvFilter;
+
+{{/if}}
+fn main(){{#if error_style}} -> error::Result<()>{{/if}} {
+{{#if logging}}
+    tracing_subscriber::fmt()
+        .with_env_filter(
+            EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info")),
+        )
+        .with_writer(std::io::stderr)
+        .init();
+    tracing::info!(version = env!("CARGO_PKG_VERSION"), "Starting");
+
+{{/if}}
     println!("Hello, world!");
+{{#if error_style}}
+    Ok(())
+{{/if}}
 }
@@ -0,0 +1,9 @@
+use criterion::Criterion;
+use criterion::{black_box, criterion_group, criterion_main};
+
+fn demo(c: &mut Criterion) {
+    c.bench_function("demo", |b| b.iter(|| black_box(2 + 2)));
+}
+
+criterion_group!(benches, demo);
+criterion_main!(benches);
@@ -0,0 +1,3 @@
+package {{ module }}
+
+def greet(name: String): String = s"Hello, $name!"
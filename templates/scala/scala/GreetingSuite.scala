@@ -0,0 +1,6 @@
+package {{ module }}
+
+class GreetingSuite extends munit.FunSuite:
+  test("greet") {
+    assertEquals(greet("world"), "Hello, world!")
+  }
@@ -0,0 +1,5 @@
+package {{ module }}
+
+object Main:
+  def main(args: Array[String]): Unit =
+    println(greet(args.headOption.getOrElse("world")))
@@ -0,0 +1,3 @@
+package com.example.demo
+
+def greet(name: String): String = s"Hello, $name!"
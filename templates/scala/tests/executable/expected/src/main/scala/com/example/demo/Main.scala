@@ -0,0 +1,5 @@
+package com.example.demo
+
+object Main:
+  def main(args: Array[String]): Unit =
+    println(greet(args.headOption.getOrElse("world")))
@@ -0,0 +1,6 @@
+package com.example.demo
+
+class GreetingSuite extends munit.FunSuite:
+  test("greet") {
+    assertEquals(greet("world"), "Hello, world!")
+  }
@@ -0,0 +1,5 @@
+# Functions for {{ name }}, sourced by scripts and tests.
+
+greet() {
+    printf 'Hello, %s!\n' "${1:-world}"
+}
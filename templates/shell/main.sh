@@ -0,0 +1,21 @@
+#!/usr/bin/env bash
+set -euo pipefail
+IFS=$'\n\t'
+
+root="$(cd "$(dirname "$(readlink -f "${BASH_SOURCE[0]}")")/.." && pwd)"
+# shellcheck source=lib/{{ bin_name }}.sh
+source "${root}/lib/{{ bin_name }}.sh"
+
+usage() {
+    printf 'Usage: %s [name]\n' "$(basename "$0")"
+}
+
+main() {
+    if [[ "${1:-}" == "-h" || "${1:-}" == "--help" ]]; then
+        usage
+        return 0
+    fi
+    greet "${1:-world}"
+}
+
+main "$@"
@@ -0,0 +1,25 @@
+#!/usr/bin/env bats
+
+setup() {
+    # shellcheck source=lib/{{ bin_name }}.sh
+    source "${BATS_TEST_DIRNAME}/../lib/{{ bin_name }}.sh"
+}
+
+@test "greet defaults to world" {
+    run greet
+    [ "$status" -eq 0 ]
+    [ "$output" = "Hello, world!" ]
+}
+
+@test "greet takes a name" {
+    run greet {{ bin_name }}
+    [ "$output" = "Hello, {{ bin_name }}!" ]
+}
+{{#if (eq kind "Executable")}}
+
+@test "{{ bin_name }} greets" {
+    run "${BATS_TEST_DIRNAME}/../bin/{{ bin_name }}"
+    [ "$status" -eq 0 ]
+    [ "$output" = "Hello, world!" ]
+}
+{{/if}}
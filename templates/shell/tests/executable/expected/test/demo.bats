@@ -0,0 +1,23 @@
+#!/usr/bin/env bats
+
+setup() {
+    # shellcheck source=lib/demo.sh
+    source "${BATS_TEST_DIRNAME}/../lib/demo.sh"
+}
+
+@test "greet defaults to world" {
+    run greet
+    [ "$status" -eq 0 ]
+    [ "$output" = "Hello, world!" ]
+}
+
+@test "greet takes a name" {
+    run greet demo
+    [ "$output" = "Hello, demo!" ]
+}
+
+@test "demo greets" {
+    run "${BATS_TEST_DIRNAME}/../bin/demo"
+    [ "$status" -eq 0 ]
+    [ "$output" = "Hello, world!" ]
+}
@@ -0,0 +1,12 @@
+module "{{ package_name }}" {
+  source = "../.."
+
+  name = "example"
+  tags = {
+    Environment = "dev"
+  }
+}
+
+output "tags" {
+  value = module.{{ package_name }}.tags
+}
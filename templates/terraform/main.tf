@@ -0,0 +1,6 @@
+locals {
+  tags = merge(var.tags, {
+    Module = "{{ package_name }}"
+    Name   = var.name
+  })
+}
@@ -0,0 +1,9 @@
+output "name" {
+  description = "Name used to identify the resources created by this module."
+  value       = var.name
+}
+
+output "tags" {
+  description = "Tags applied to every resource created by this module."
+  value       = local.tags
+}
@@ -0,0 +1,12 @@
+module "demo" {
+  source = "../.."
+
+  name = "example"
+  tags = {
+    Environment = "dev"
+  }
+}
+
+output "tags" {
+  value = module.demo.tags
+}
@@ -0,0 +1,6 @@
+locals {
+  tags = merge(var.tags, {
+    Module = "demo"
+    Name   = var.name
+  })
+}
@@ -0,0 +1,10 @@
+variable "name" {
+  description = "Name used to identify the resources created by this module."
+  type        = string
+}
+
+variable "tags" {
+  description = "Tags to apply to every resource created by this module."
+  type        = map(string)
+  default     = {}
+}
@@ -0,0 +1,46 @@
+#![cfg(unix)]
+
+use gen::project::{Lang, ProjectBuilder};
+use std::fs;
+use std::os::unix::fs::PermissionsExt;
+use std::path::{Path, PathBuf};
+use tempfile::TempDir;
+
+fn templates_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("templates")
+}
+
+fn fake_clang_format(dir: &Path, script: &str) -> PathBuf {
+    let path = dir.join("clang-format");
+    fs::write(&path, format!("#!/bin/sh\n{}\n", script)).unwrap();
+    fs::set_permissions(&path, fs::Permissions::from_mode(0o755)).unwrap();
+    path
+}
+
+fn generate(output_dir: &Path, clang_format: &Path) -> anyhow::Result<()> {
+    let mut project = ProjectBuilder::new("demo")
+        .lang(Lang::C)
+        .templates_root(templates_root())
+        .output_dir(output_dir)
+        .build()?;
+    project.set_clang_format(clang_format);
+    project.create_language_project()
+}
+
+#[test]
+fn clang_format_success_writes_its_config() {
+    let scratch_dir = TempDir::new().unwrap();
+    let clang_format = fake_clang_format(scratch_dir.path(), "echo 'BasedOnStyle: Google'");
+
+    generate(scratch_dir.path(), &clang_format).unwrap();
+    let config = fs::read_to_string(scratch_dir.path().join("demo").join(".clang-format"));
+    assert_eq!(config.unwrap(), "BasedOnStyle: Google\n");
+}
+
+#[test]
+fn clang_format_failure_fails_generation() {
+    let scratch_dir = TempDir::new().unwrap();
+    let clang_format = fake_clang_format(scratch_dir.path(), "echo 'unknown style' >&2\nexit 1");
+
+    assert!(generate(scratch_dir.path(), &clang_format).is_err());
+}
@@ -0,0 +1,62 @@
+use gen::project::Project;
+use gen::template::{sets, test};
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use tempfile::TempDir;
+
+#[test]
+fn shipped_fixtures_pass() {
+    let templates_root = Path::new(env!("CARGO_MANIFEST_DIR")).join("templates");
+    for set in sets(&templates_root).unwrap() {
+        let template_dir = templates_root.join(&set);
+        if template_dir.join("tests").is_dir() {
+            assert_eq!(test(&template_dir).unwrap(), 0, "{} fixtures failed", set);
+        }
+    }
+}
+
+// The generated CI runs `cargo fmt --check`, so the Rust sources the fixtures render must already
+// be formatted for the edition `cargo new` picks
+#[test]
+fn rust_fixtures_are_rustfmt_clean() {
+    if Command::new("rustfmt").arg("--version").output().is_err() {
+        eprintln!("skipping rustfmt check: rustfmt is not installed");
+        return;
+    }
+
+    let template_dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("templates")
+        .join("rust");
+    for fixture in fs::read_dir(template_dir.join("tests")).unwrap() {
+        let fixture = fixture.unwrap().path();
+        let variables: toml::Table =
+            toml::from_str(&fs::read_to_string(fixture.join("fixture.toml")).unwrap()).unwrap();
+        let name = variables["name"].as_str().unwrap().to_string();
+        let project =
+            Project::from_variables(variables, template_dir.clone(), name.into()).unwrap();
+
+        // Written out so rustfmt picks up the rendered rustfmt.toml
+        let output_dir = TempDir::new().unwrap();
+        let mut sources = Vec::new();
+        for (path, contents) in project.render_in_memory().unwrap() {
+            let path = output_dir.path().join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(&path, contents).unwrap();
+            if path.extension().is_some_and(|extension| extension == "rs") {
+                sources.push(path);
+            }
+        }
+
+        let status = Command::new("rustfmt")
+            .args(["--check", "--edition", "2024"])
+            .args(&sources)
+            .status()
+            .unwrap();
+        assert!(
+            status.success(),
+            "fixture {} is not rustfmt clean",
+            fixture.display()
+        );
+    }
+}
@@ -0,0 +1,210 @@
+use gen::languages::check_tools;
+use gen::project::{Lang, Project, ProjectBuilder, ProjectKind};
+use gen::template::from_dir;
+use gen::walk::{collect_files, VCS_DIRS};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tempfile::TempDir;
+
+const NAME: &str = "demo";
+const DOMAIN: &str = "com.example";
+
+fn templates_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("templates")
+}
+
+fn generate(
+    lang: Lang,
+    kind: ProjectKind,
+    templates_root: &Path,
+    template_set: Option<&str>,
+    output_dir: &Path,
+    configure: &dyn Fn(&mut Project),
+) -> PathBuf {
+    let mut builder = ProjectBuilder::new(NAME)
+        .lang(lang)
+        .kind(kind)
+        .domain(DOMAIN)
+        .templates_root(templates_root)
+        .output_dir(output_dir);
+    if let Some(template_set) = template_set {
+        builder = builder.template_set(template_set);
+    }
+    let mut project = builder.build().unwrap();
+    configure(&mut project);
+    project.create_language_project().unwrap();
+    project.create_files().unwrap();
+    output_dir.join(NAME)
+}
+
+fn files(project_dir: &Path) -> Vec<(PathBuf, Vec<u8>, bool)> {
+    let mut files = Vec::new();
+    collect_files(project_dir, VCS_DIRS, &mut files).unwrap();
+    files.sort();
+    files
+        .into_iter()
+        .map(|file| {
+            let contents = fs::read(&file).unwrap();
+            let executable = executable(&file);
+            (
+                file.strip_prefix(project_dir).unwrap().to_path_buf(),
+                contents,
+                executable,
+            )
+        })
+        .collect()
+}
+
+#[cfg(unix)]
+fn executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    fs::metadata(path).unwrap().permissions().mode() & 0o111 != 0
+}
+
+#[cfg(not(unix))]
+fn executable(_path: &Path) -> bool {
+    false
+}
+
+fn round_trip(lang: Lang, kind: ProjectKind) {
+    round_trip_with(lang, kind, &|_| {});
+}
+
+fn round_trip_with(lang: Lang, kind: ProjectKind, configure: &dyn Fn(&mut Project)) {
+    if let Err(error) = check_tools(lang, &[]) {
+        eprintln!("skipping {:?} round trip: {}", lang, error);
+        return;
+    }
+
+    let scratch_dir = TempDir::new().unwrap();
+    let original = generate(
+        lang,
+        kind,
+        &templates_root(),
+        None,
+        &scratch_dir.path().join("original"),
+        configure,
+    );
+
+    let snapshot_root = scratch_dir.path().join("templates");
+    from_dir(
+        &original,
+        &snapshot_root.join("snapshot"),
+        Some(NAME),
+        Some(DOMAIN),
+    )
+    .unwrap();
+    let regenerated = generate(
+        lang,
+        kind,
+        &snapshot_root,
+        Some("snapshot"),
+        &scratch_dir.path().join("regenerated"),
+        configure,
+    );
+
+    let expected = files(&original);
+    let actual = files(&regenerated);
+
+    let paths = |files: &[(PathBuf, Vec<u8>, bool)]| {
+        files
+            .iter()
+            .map(|(path, _, _)| path.clone())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(paths(&expected), paths(&actual));
+    for (expected, actual) in expected.iter().zip(&actual) {
+        assert!(
+            expected == actual,
+            "{} changed in the round trip",
+            expected.0.display()
+        );
+    }
+}
+
+#[test]
+fn rust_round_trip() {
+    round_trip(Lang::Rust, ProjectKind::Executable);
+}
+
+#[test]
+fn rust_library_round_trip() {
+    round_trip(Lang::Rust, ProjectKind::Library);
+}
+
+#[test]
+fn rust_proptest_round_trip() {
+    round_trip_with(Lang::Rust, ProjectKind::Library, &|project| {
+        project.set_proptest(true)
+    });
+}
+
+#[test]
+fn go_round_trip() {
+    round_trip(Lang::Go, ProjectKind::Executable);
+}
+
+#[test]
+fn java_round_trip() {
+    round_trip(Lang::Java, ProjectKind::Executable);
+}
+
+#[test]
+fn c_round_trip() {
+    round_trip(Lang::C, ProjectKind::Executable);
+}
+
+#[test]
+fn cpp_round_trip() {
+    round_trip(Lang::Cpp, ProjectKind::Executable);
+}
+
+#[test]
+fn asm_round_trip() {
+    round_trip(Lang::Asm, ProjectKind::Executable);
+}
+
+#[test]
+fn shell_round_trip() {
+    round_trip(Lang::Shell, ProjectKind::Executable);
+}
+
+#[test]
+fn python_round_trip() {
+    round_trip(Lang::Python, ProjectKind::Executable);
+}
+
+#[test]
+fn elixir_round_trip() {
+    round_trip(Lang::Elixir, ProjectKind::Executable);
+}
+
+#[test]
+fn ruby_round_trip() {
+    round_trip(Lang::Ruby, ProjectKind::Executable);
+}
+
+#[test]
+fn scala_round_trip() {
+    round_trip(Lang::Scala, ProjectKind::Executable);
+}
+
+#[test]
+fn lua_round_trip() {
+    round_trip(Lang::Lua, ProjectKind::Executable);
+}
+
+#[test]
+fn php_round_trip() {
+    round_trip(Lang::Php, ProjectKind::Executable);
+}
+
+#[test]
+fn latex_round_trip() {
+    round_trip(Lang::Latex, ProjectKind::Executable);
+}
+
+#[test]
+fn terraform_round_trip() {
+    round_trip(Lang::Terraform, ProjectKind::Library);
+}
@@ -0,0 +1,69 @@
+use gen::config::FormatConfig;
+use gen::diff::changes;
+use gen::languages::check_tools;
+use gen::project::{Lang, Project, ProjectBuilder};
+use gen::record::ProjectRecord;
+use gen::upgrade::render;
+use std::path::{Path, PathBuf};
+use tempfile::TempDir;
+
+fn templates_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("templates")
+}
+
+fn generate(output_dir: &Path, configure: &dyn Fn(&mut Project)) -> PathBuf {
+    let mut project = ProjectBuilder::new("demo")
+        .lang(Lang::Rust)
+        .templates_root(templates_root())
+        .output_dir(output_dir)
+        .build()
+        .unwrap();
+    configure(&mut project);
+    project.create_language_project().unwrap();
+    project.create_files().unwrap();
+    project.write_record().unwrap();
+    output_dir.join("demo")
+}
+
+fn assert_up_to_date(project_dir: &Path) {
+    let record = ProjectRecord::load(project_dir).unwrap();
+    let rendered = render(&record, &templates_root(), project_dir).unwrap();
+    let paths: Vec<PathBuf> = changes(project_dir, rendered)
+        .into_iter()
+        .map(|change| change.path)
+        .collect();
+    assert!(
+        paths.is_empty(),
+        "{:?} differ from the recorded render",
+        paths
+    );
+}
+
+#[test]
+fn fresh_project_is_up_to_date() {
+    if let Err(error) = check_tools(Lang::Rust, &[]) {
+        eprintln!("skipping record round trip: {}", error);
+        return;
+    }
+
+    let scratch_dir = TempDir::new().unwrap();
+    assert_up_to_date(&generate(scratch_dir.path(), &|_| {}));
+}
+
+#[test]
+fn recorded_format_settings_are_rendered_again() {
+    if let Err(error) = check_tools(Lang::Rust, &[]) {
+        eprintln!("skipping record round trip: {}", error);
+        return;
+    }
+
+    let scratch_dir = TempDir::new().unwrap();
+    let project_dir = generate(scratch_dir.path(), &|project| {
+        project.set_format(FormatConfig {
+            enabled: true,
+            indent_width: 2,
+            max_width: 120,
+        })
+    });
+    assert_up_to_date(&project_dir);
+}